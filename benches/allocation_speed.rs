@@ -1,5 +1,6 @@
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use fastalloc::{FixedPool, GrowingPool, PoolConfig};
+use std::hint::black_box;
 
 fn bench_fixed_pool_allocation(c: &mut Criterion) {
     let mut group = c.benchmark_group("fixed_pool_allocation");
@@ -11,7 +12,7 @@ fn bench_fixed_pool_allocation(c: &mut Criterion) {
             let pool = FixedPool::<i32>::new(size).unwrap();
             b.iter(|| {
                 let handle = pool.allocate(black_box(42)).unwrap();
-                black_box(handle);
+                drop(black_box(handle));
             });
         });
     }
@@ -35,7 +36,36 @@ fn bench_growing_pool_allocation(c: &mut Criterion) {
 
             b.iter(|| {
                 let handle = pool.allocate(black_box(42)).unwrap();
-                black_box(handle);
+                drop(black_box(handle));
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_growing_pool_many_chunks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("growing_pool_many_chunks");
+
+    for chunks in [16, 256, 4096].iter() {
+        group.bench_with_input(BenchmarkId::from_parameter(chunks), chunks, |b, &chunks| {
+            let config = PoolConfig::builder()
+                .capacity(16)
+                .growth_strategy(fastalloc::GrowthStrategy::Linear { amount: 16 })
+                .build()
+                .unwrap();
+            let pool = GrowingPool::with_config(config).unwrap();
+
+            // Fill every chunk, then free one slot in the last chunk so each
+            // allocation below writes there
+            let mut handles: Vec<_> = (0..chunks * 16)
+                .map(|i| pool.allocate(i).unwrap())
+                .collect();
+            drop(handles.pop());
+
+            b.iter(|| {
+                let handle = pool.allocate(black_box(42)).unwrap();
+                drop(black_box(handle));
             });
         });
     }
@@ -86,7 +116,7 @@ fn bench_different_sizes(c: &mut Criterion) {
         let pool = FixedPool::<u8>::new(1000).unwrap();
         b.iter(|| {
             let handle = pool.allocate(black_box(42u8)).unwrap();
-            black_box(handle);
+            drop(black_box(handle));
         });
     });
 
@@ -95,12 +125,13 @@ fn bench_different_sizes(c: &mut Criterion) {
         let pool = FixedPool::<u128>::new(1000).unwrap();
         b.iter(|| {
             let handle = pool.allocate(black_box(42u128)).unwrap();
-            black_box(handle);
+            drop(black_box(handle));
         });
     });
 
     // Large type
     #[derive(Clone)]
+    #[allow(dead_code)]
     struct LargeStruct([u64; 32]);
     impl fastalloc::Poolable for LargeStruct {}
 
@@ -108,7 +139,65 @@ fn bench_different_sizes(c: &mut Criterion) {
         let pool = FixedPool::<LargeStruct>::new(1000).unwrap();
         b.iter(|| {
             let handle = pool.allocate(black_box(LargeStruct([0; 32]))).unwrap();
-            black_box(handle);
+            drop(black_box(handle));
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_recycled_buffers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("buffer_recycling");
+
+    group.bench_function("allocate_fresh_vec", |b| {
+        let pool = FixedPool::<Vec<u8>>::new(100).unwrap();
+        b.iter(|| {
+            let mut handle = pool.allocate(Vec::with_capacity(4096)).unwrap();
+            handle.extend_from_slice(black_box(&[1u8; 64]));
+            black_box(&handle);
+        });
+    });
+
+    group.bench_function("allocate_recycled_vec", |b| {
+        let config = PoolConfig::builder()
+            .capacity(100)
+            .reset_fn(|| Vec::with_capacity(4096), |v: &mut Vec<u8>| v.clear())
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+        b.iter(|| {
+            let mut handle = pool.allocate_recycled().unwrap();
+            handle.extend_from_slice(black_box(&[1u8; 64]));
+            black_box(&handle);
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_uninit_allocation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("uninit_allocation");
+
+    struct Block([u8; 4096]);
+    impl fastalloc::Poolable for Block {}
+
+    group.bench_function("allocate_4k_block", |b| {
+        let pool = FixedPool::<Block>::new(100).unwrap();
+        b.iter(|| {
+            let handle = pool.allocate(Block(black_box([7u8; 4096]))).unwrap();
+            black_box(&handle.0);
+        });
+    });
+
+    group.bench_function("allocate_uninit_4k_block", |b| {
+        let pool = FixedPool::<Block>::new(100).unwrap();
+        b.iter(|| {
+            let mut slot = pool.allocate_uninit().unwrap();
+            // Fill the slot in place instead of moving a value into it
+            let block = slot.as_uninit_mut().as_mut_ptr();
+            unsafe { block.cast::<u8>().write_bytes(black_box(7), 4096) };
+            let handle = unsafe { slot.assume_init() };
+            black_box(&handle.0);
         });
     });
 
@@ -119,8 +208,11 @@ criterion_group!(
     benches,
     bench_fixed_pool_allocation,
     bench_growing_pool_allocation,
+    bench_growing_pool_many_chunks,
     bench_box_allocation,
     bench_allocation_reuse,
-    bench_different_sizes
+    bench_different_sizes,
+    bench_recycled_buffers,
+    bench_uninit_allocation
 );
 criterion_main!(benches);
@@ -1,5 +1,6 @@
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use fastalloc::FixedPool;
+use std::hint::black_box;
 
 fn bench_pool_vs_box(c: &mut Criterion) {
     let mut group = c.benchmark_group("pool_vs_box");
@@ -57,6 +58,7 @@ fn bench_pool_vs_vec(c: &mut Criterion) {
 
 fn bench_struct_allocation(c: &mut Criterion) {
     #[derive(Clone)]
+    #[allow(dead_code)]
     struct GameObject {
         position: (f32, f32, f32),
         velocity: (f32, f32, f32),
@@ -132,7 +134,7 @@ fn bench_cache_performance(c: &mut Criterion) {
 
     // Boxed values (potentially poor cache locality)
     group.bench_function("box_vector_sequential", |b| {
-        let boxes: Vec<Box<i32>> = (0..1000).map(|i| Box::new(i)).collect();
+        let boxes: Vec<Box<i32>> = (0..1000).map(Box::new).collect();
 
         b.iter(|| {
             let mut sum = 0;
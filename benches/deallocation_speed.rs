@@ -1,5 +1,6 @@
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use fastalloc::FixedPool;
+use std::hint::black_box;
 
 fn bench_deallocation(c: &mut Criterion) {
     let mut group = c.benchmark_group("deallocation");
@@ -60,6 +61,40 @@ fn bench_bulk_deallocation(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_batch_free(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_free");
+
+    for &size in &[100, 1000, 10000] {
+        group.bench_with_input(
+            BenchmarkId::new("individual_drop", size),
+            &size,
+            |b, &size| {
+                let pool = FixedPool::<i32>::new(size).unwrap();
+
+                b.iter(|| {
+                    let handles: Vec<_> = (0..size as i32)
+                        .map(|i| pool.allocate(i).unwrap())
+                        .collect();
+                    drop(black_box(handles));
+                });
+            },
+        );
+
+        group.bench_with_input(BenchmarkId::new("free_batch", size), &size, |b, &size| {
+            let pool = FixedPool::<i32>::new(size).unwrap();
+
+            b.iter(|| {
+                let handles: Vec<_> = (0..size as i32)
+                    .map(|i| pool.allocate(i).unwrap())
+                    .collect();
+                pool.free_batch(black_box(handles));
+            });
+        });
+    }
+
+    group.finish();
+}
+
 fn bench_mixed_allocation_deallocation(c: &mut Criterion) {
     let mut group = c.benchmark_group("mixed_alloc_dealloc");
 
@@ -98,6 +133,7 @@ criterion_group!(
     benches,
     bench_deallocation,
     bench_bulk_deallocation,
+    bench_batch_free,
     bench_mixed_allocation_deallocation
 );
 criterion_main!(benches);
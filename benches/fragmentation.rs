@@ -1,6 +1,7 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, Criterion};
 use fastalloc::{FixedPool, GrowingPool, GrowthStrategy, PoolConfig};
 use std::collections::VecDeque;
+use std::hint::black_box;
 
 fn bench_fragmentation_pattern(c: &mut Criterion) {
     let mut group = c.benchmark_group("fragmentation");
@@ -60,7 +61,7 @@ fn bench_fragmentation_pattern(c: &mut Criterion) {
                 // Randomly drop some handles
                 if i % 3 == 0 && !handles.is_empty() {
                     let idx = i % handles.len();
-                    handles.remove(idx);
+                    drop(handles.remove(idx));
                 }
             }
 
@@ -94,7 +95,7 @@ fn bench_growing_pool_fragmentation(c: &mut Criterion) {
 
                 // Deallocate every 5th
                 if i % 5 == 0 && !handles.is_empty() {
-                    handles.remove(0);
+                    drop(handles.remove(0));
                 }
             }
 
@@ -1,5 +1,6 @@
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use fastalloc::{PoolConfig, ThreadSafePool};
+use std::hint::black_box;
 use std::sync::Arc;
 use std::thread;
 
@@ -24,7 +25,7 @@ fn bench_thread_safe_pool(c: &mut Criterion) {
                         handles.push(thread::spawn(move || {
                             for i in 0..100 {
                                 if let Ok(handle) = pool_clone.allocate(black_box(i)) {
-                                    black_box(handle);
+                                    drop(black_box(handle));
                                 }
                             }
                         }));
@@ -110,7 +111,7 @@ fn bench_thread_local_pool(c: &mut Criterion) {
         b.iter(|| {
             for i in 0..100 {
                 if let Ok(handle) = pool.allocate(black_box(i)) {
-                    black_box(handle);
+                    drop(black_box(handle));
                 }
             }
         });
@@ -1,8 +1,10 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use fastalloc::{FixedPool, PoolConfig};
+use criterion::{criterion_group, criterion_main, Criterion};
+use fastalloc::FixedPool;
+use std::hint::black_box;
 
 // Simulated game entity
 #[derive(Clone)]
+#[allow(dead_code)]
 struct GameEntity {
     id: u64,
     position: (f32, f32, f32),
@@ -89,6 +91,7 @@ fn bench_game_entity_spawning(c: &mut Criterion) {
 
 // Simulated connection object
 #[derive(Clone)]
+#[allow(dead_code)]
 struct Connection {
     id: u64,
     addr: [u8; 4],
@@ -140,6 +143,7 @@ fn bench_server_connections(c: &mut Criterion) {
 
 // Simulated particle for particle system
 #[derive(Clone, Copy)]
+#[allow(dead_code)]
 struct Particle {
     position: (f32, f32, f32),
     velocity: (f32, f32, f32),
@@ -197,6 +201,7 @@ fn bench_particle_system(c: &mut Criterion) {
 // Data processing pipeline simulation
 fn bench_data_pipeline(c: &mut Criterion) {
     #[derive(Clone)]
+    #[allow(dead_code)]
     struct DataChunk {
         id: u64,
         data: Vec<f64>,
@@ -1,6 +1,6 @@
 //! Async runtime integration example using tokio.
 
-use fastalloc::{FixedPool, PoolConfig, ThreadSafePool};
+use fastalloc::{PoolConfig, ThreadSafePool};
 use std::sync::Arc;
 
 #[tokio::main]
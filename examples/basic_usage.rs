@@ -87,7 +87,7 @@ fn main() {
 
     {
         let h1 = reuse_pool.allocate(String::from("First")).unwrap();
-        let h2 = reuse_pool.allocate(String::from("Second")).unwrap();
+        let _h2 = reuse_pool.allocate(String::from("Second")).unwrap();
         println!(
             "   Allocated 2 strings, available: {}",
             reuse_pool.available()
@@ -100,7 +100,7 @@ fn main() {
         reuse_pool.available()
     );
 
-    let h3 = reuse_pool.allocate(String::from("Third")).unwrap();
+    let _h3 = reuse_pool.allocate(String::from("Third")).unwrap();
     println!(
         "   Allocated new string (reused slot), available: {}",
         reuse_pool.available()
@@ -1,6 +1,6 @@
 //! Custom initialization and configuration example.
 
-use fastalloc::{GrowingPool, GrowthStrategy, PoolConfig};
+use fastalloc::{FixedPool, GrowingPool, GrowthStrategy, PoolConfig};
 
 fn main() {
     println!("=== Custom Initialization Example ===\n");
@@ -57,7 +57,7 @@ fn main() {
         .build()
         .expect("Invalid configuration");
 
-    let buffer_pool = GrowingPool::with_config(config).expect("Failed to create pool");
+    let buffer_pool = FixedPool::with_config(config).expect("Failed to create pool");
 
     {
         let mut buf = buffer_pool
@@ -72,6 +72,10 @@ fn main() {
         println!("Buffer modified: {:?}", *buf);
     } // Buffer returned to pool and reset function is called
 
+    // The reset buffer stays in its slot and is handed out again
+    let buf = buffer_pool.allocate_recycled().unwrap();
+    println!("Buffer recycled: {:?}", *buf);
+
     println!();
 
     // Example 3: Different growth strategies
@@ -3,6 +3,7 @@
 use fastalloc::FixedPool;
 
 #[derive(Debug, Clone, Copy)]
+#[allow(dead_code)]
 struct Particle {
     position: (f32, f32, f32),
     velocity: (f32, f32, f32),
@@ -4,6 +4,7 @@ use fastalloc::FixedPool;
 use std::time::SystemTime;
 
 #[derive(Debug)]
+#[allow(dead_code)]
 struct Connection {
     id: u64,
     client_addr: String,
@@ -23,10 +23,21 @@ pub(crate) struct BitmapAllocator {
 }
 
 impl BitmapAllocator {
-    const BITS_PER_WORD: usize = 64;
+    pub(crate) const BITS_PER_WORD: usize = 64;
+
+    /// Rounds `additional` up so that `capacity + additional` fills whole words.
+    #[inline]
+    pub(crate) fn word_aligned_growth(capacity: usize, additional: usize) -> usize {
+        let target = capacity.saturating_add(additional);
+        let remainder = target % Self::BITS_PER_WORD;
+        if remainder == 0 {
+            additional
+        } else {
+            additional.saturating_add(Self::BITS_PER_WORD - remainder)
+        }
+    }
 
     /// Creates a new bitmap allocator with the given capacity.
-    #[allow(dead_code)]
     pub fn new(capacity: usize) -> Self {
         let num_words = (capacity + Self::BITS_PER_WORD - 1) / Self::BITS_PER_WORD;
         let bitmap = alloc::vec![0u64; num_words];
@@ -47,13 +58,6 @@ impl BitmapAllocator {
         (word_idx, bit_pos)
     }
 
-    /// Checks if a slot is allocated.
-    #[inline]
-    fn is_allocated(&self, index: usize) -> bool {
-        let (word_idx, bit_pos) = Self::word_and_bit(index);
-        (self.bitmap[word_idx] & (1u64 << bit_pos)) != 0
-    }
-
     /// Marks a slot as allocated.
     #[inline]
     fn mark_allocated(&mut self, index: usize) {
@@ -119,11 +123,25 @@ impl BitmapAllocator {
     }
 
     /// Extends the allocator with additional capacity.
+    ///
+    /// The search hint moves to the first word holding new slots, so the
+    /// next allocation finds them without rescanning the full words before.
+    /// Use [`word_aligned_growth`](Self::word_aligned_growth) to size
+    /// `additional` so that no partial word is left at the end.
     #[allow(dead_code)]
     pub fn extend(&mut self, additional: usize) {
-        self.capacity += additional;
+        if additional == 0 {
+            return;
+        }
+
+        self.next_free_hint = self.capacity / Self::BITS_PER_WORD;
+        self.capacity = self
+            .capacity
+            .checked_add(additional)
+            .expect("bitmap allocator capacity overflow");
 
-        let new_num_words = (self.capacity + Self::BITS_PER_WORD - 1) / Self::BITS_PER_WORD;
+        let new_num_words = self.capacity / Self::BITS_PER_WORD
+            + usize::from(self.capacity % Self::BITS_PER_WORD != 0);
         let old_num_words = self.bitmap.len();
 
         // Add more words if needed
@@ -133,6 +151,122 @@ impl BitmapAllocator {
     }
 }
 
+/// Finds the first run of `count` zero bits among the first `capacity` bits.
+///
+/// Whole words are skipped or consumed at once, and runs inside a word are
+/// measured with `trailing_zeros`/`trailing_ones`, so the scan costs a few
+/// instructions per run of set or clear bits rather than per slot.
+pub(crate) fn find_zero_run(bitmap: &[u64], capacity: usize, count: usize) -> Option<usize> {
+    if count == 0 || count > capacity {
+        return None;
+    }
+
+    let mut run_start = 0;
+    let mut run_len = 0;
+
+    for (word_idx, &word) in bitmap.iter().enumerate() {
+        let base = word_idx * 64;
+        if base >= capacity {
+            break;
+        }
+
+        // Free bits are set; bits past the capacity are treated as allocated
+        let mut free = !word;
+        if capacity - base < 64 {
+            free &= (1u64 << (capacity - base)) - 1;
+        }
+
+        if free == u64::MAX {
+            if run_len == 0 {
+                run_start = base;
+            }
+            run_len += 64;
+            if run_len >= count {
+                return Some(run_start);
+            }
+            continue;
+        }
+
+        let mut bit = 0;
+        while bit < 64 {
+            let rest = free >> bit;
+            if rest == 0 {
+                // Everything above `bit` is allocated
+                run_len = 0;
+                break;
+            }
+
+            let ones = rest.trailing_ones() as usize;
+            if ones == 0 {
+                run_len = 0;
+                bit += rest.trailing_zeros() as usize;
+                continue;
+            }
+
+            if run_len == 0 {
+                run_start = base + bit;
+            }
+            run_len += ones;
+            if run_len >= count {
+                return Some(run_start);
+            }
+            bit += ones;
+        }
+    }
+
+    None
+}
+
+/// Returns the length of the longest run of zero bits among the first `capacity` bits.
+///
+/// Uses the same word-at-a-time scan as [`find_zero_run`], so runs spanning
+/// word boundaries are measured in full.
+pub(crate) fn longest_zero_run(bitmap: &[u64], capacity: usize) -> usize {
+    let mut longest = 0;
+    let mut run_len = 0;
+
+    for (word_idx, &word) in bitmap.iter().enumerate() {
+        let base = word_idx * 64;
+        if base >= capacity {
+            break;
+        }
+
+        // Free bits are set; bits past the capacity are treated as allocated
+        let mut free = !word;
+        if capacity - base < 64 {
+            free &= (1u64 << (capacity - base)) - 1;
+        }
+
+        if free == u64::MAX {
+            run_len += 64;
+            continue;
+        }
+
+        let mut bit = 0;
+        while bit < 64 {
+            let rest = free >> bit;
+            if rest == 0 {
+                longest = longest.max(run_len);
+                run_len = 0;
+                break;
+            }
+
+            let ones = rest.trailing_ones() as usize;
+            if ones == 0 {
+                longest = longest.max(run_len);
+                run_len = 0;
+                bit += rest.trailing_zeros() as usize;
+                continue;
+            }
+
+            run_len += ones;
+            bit += ones;
+        }
+    }
+
+    longest.max(run_len)
+}
+
 impl Allocator for BitmapAllocator {
     #[inline]
     fn allocate(&mut self) -> Option<usize> {
@@ -169,6 +303,12 @@ impl Allocator for BitmapAllocator {
     fn capacity(&self) -> usize {
         self.capacity
     }
+
+    #[inline]
+    fn is_allocated(&self, index: usize) -> bool {
+        let (word_idx, bit_pos) = Self::word_and_bit(index);
+        (self.bitmap[word_idx] & (1u64 << bit_pos)) != 0
+    }
 }
 
 #[cfg(test)]
@@ -230,6 +370,29 @@ mod tests {
         assert!(allocator.is_full());
     }
 
+    #[test]
+    fn word_aligned_growth_fills_last_word() {
+        assert_eq!(BitmapAllocator::word_aligned_growth(64, 10), 64);
+        assert_eq!(BitmapAllocator::word_aligned_growth(100, 10), 28);
+        assert_eq!(BitmapAllocator::word_aligned_growth(100, 28), 28);
+
+        let mut allocator = BitmapAllocator::new(100);
+        allocator.extend(BitmapAllocator::word_aligned_growth(100, 1));
+        assert_eq!(allocator.capacity(), 128);
+        assert_eq!(allocator.bitmap.len(), 2);
+    }
+
+    #[test]
+    fn extend_moves_hint_to_new_slots() {
+        let mut allocator = BitmapAllocator::new(128);
+        for _ in 0..128 {
+            allocator.allocate();
+        }
+
+        allocator.extend(64);
+        assert_eq!(allocator.allocate(), Some(128));
+    }
+
     #[test]
     fn large_capacity() {
         let mut allocator = BitmapAllocator::new(1000);
@@ -260,4 +423,83 @@ mod tests {
         let reused_idx = allocator.allocate().unwrap();
         assert_eq!(reused_idx, idx);
     }
+
+    fn find_run(allocator: &BitmapAllocator, count: usize) -> Option<usize> {
+        find_zero_run(&allocator.bitmap, allocator.capacity, count)
+    }
+
+    fn longest_run(allocator: &BitmapAllocator) -> usize {
+        longest_zero_run(&allocator.bitmap, allocator.capacity)
+    }
+
+    #[test]
+    fn contiguous_run_straddles_word_boundary() {
+        let mut allocator = BitmapAllocator::new(200);
+        for _ in 0..200 {
+            allocator.allocate();
+        }
+
+        // Free slots 60..70, which cross from the first word into the second
+        for index in 60..70 {
+            allocator.free(index);
+        }
+        assert_eq!(find_run(&allocator, 10), Some(60));
+        assert_eq!(find_run(&allocator, 11), None);
+
+        // A longer run spanning two boundaries (bits 120..200)
+        for index in 120..200 {
+            allocator.free(index);
+        }
+        assert_eq!(find_run(&allocator, 11), Some(120));
+        assert_eq!(find_run(&allocator, 80), Some(120));
+        assert_eq!(find_run(&allocator, 81), None);
+    }
+
+    #[test]
+    fn contiguous_run_skips_fragmented_slots() {
+        let mut allocator = BitmapAllocator::new(128);
+        for _ in 0..128 {
+            allocator.allocate();
+        }
+
+        // Every other slot free: plenty of space, but no run of two
+        for index in (0..128).step_by(2) {
+            allocator.free(index);
+        }
+        assert_eq!(find_run(&allocator, 1), Some(0));
+        assert_eq!(find_run(&allocator, 2), None);
+    }
+
+    #[test]
+    fn zero_run_respects_capacity() {
+        // Bits past the capacity never count as free
+        let bitmap = [0u64; 2];
+        assert_eq!(find_zero_run(&bitmap, 70, 70), Some(0));
+        assert_eq!(find_zero_run(&bitmap, 70, 71), None);
+        assert_eq!(find_zero_run(&bitmap, 70, 0), None);
+    }
+
+    #[test]
+    fn longest_zero_run_spans_words() {
+        let mut allocator = BitmapAllocator::new(200);
+        assert_eq!(longest_run(&allocator), 200);
+
+        for _ in 0..200 {
+            allocator.allocate();
+        }
+        assert_eq!(longest_run(&allocator), 0);
+
+        // Runs of 3 (10..13), 80 across the first word boundary (40..120)
+        // and 5 at the very end (195..200)
+        for index in (10..13).chain(40..120).chain(195..200) {
+            allocator.free(index);
+        }
+        assert_eq!(longest_run(&allocator), 80);
+        assert_eq!(find_run(&allocator, 80), Some(40));
+        assert_eq!(find_run(&allocator, 81), None);
+
+        allocator.mark_allocated(60);
+        allocator.allocated += 1;
+        assert_eq!(longest_run(&allocator), 59);
+    }
 }
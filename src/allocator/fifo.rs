@@ -0,0 +1,134 @@
+//! Queue-based (FIFO) allocator implementation.
+
+use super::Allocator;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// A queue-based allocator that reuses the oldest freed slot first.
+///
+/// Where [`StackAllocator`](super::StackAllocator) favors cache locality by
+/// handing back the most recently freed slot, this allocator cycles through
+/// all free slots in the order they were released. That spreads reuse evenly
+/// across the pool, which suits schedulers that want fair index distribution
+/// or want a freed slot to stay untouched for as long as possible.
+///
+/// Time complexity: O(1) for both allocation and deallocation.
+/// Space complexity: O(capacity) for storing free indices.
+#[derive(Clone)]
+pub(crate) struct FifoAllocator {
+    /// Queue of available indices; allocation pops from the front
+    free_queue: VecDeque<usize>,
+    /// Total capacity
+    capacity: usize,
+    /// Occupancy bitmap (1 = allocated), also used for double-free detection
+    allocated_bitmap: Vec<u64>,
+}
+
+impl FifoAllocator {
+    /// Creates a new FIFO allocator with the given capacity.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            free_queue: (0..capacity).collect(),
+            capacity,
+            allocated_bitmap: alloc::vec![0u64; (capacity + 63) / 64],
+        }
+    }
+
+    /// Extends the allocator with additional capacity.
+    ///
+    /// New slots are queued behind the slots that are already free.
+    #[allow(dead_code)]
+    pub fn extend(&mut self, additional: usize) {
+        let old_capacity = self.capacity;
+        self.capacity = self
+            .capacity
+            .checked_add(additional)
+            .expect("fifo allocator capacity overflow");
+
+        let new_num_words = self.capacity / 64 + usize::from(self.capacity % 64 != 0);
+        self.allocated_bitmap.resize(new_num_words, 0);
+        self.free_queue.extend(old_capacity..self.capacity);
+    }
+}
+
+impl Allocator for FifoAllocator {
+    #[inline]
+    fn allocate(&mut self) -> Option<usize> {
+        let index = self.free_queue.pop_front()?;
+        self.allocated_bitmap[index / 64] |= 1u64 << (index % 64);
+        Some(index)
+    }
+
+    #[inline]
+    fn free(&mut self, index: usize) {
+        debug_assert!(index < self.capacity, "index out of bounds");
+        debug_assert!(
+            self.is_allocated(index),
+            "double free detected for index {}",
+            index
+        );
+
+        self.allocated_bitmap[index / 64] &= !(1u64 << (index % 64));
+        self.free_queue.push_back(index);
+    }
+
+    #[inline]
+    fn available(&self) -> usize {
+        self.free_queue.len()
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[inline]
+    fn is_allocated(&self, index: usize) -> bool {
+        (self.allocated_bitmap[index / 64] & (1u64 << (index % 64))) != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocates_in_index_order() {
+        let mut allocator = FifoAllocator::new(3);
+        assert_eq!(allocator.allocate(), Some(0));
+        assert_eq!(allocator.allocate(), Some(1));
+        assert_eq!(allocator.allocate(), Some(2));
+        assert!(allocator.allocate().is_none());
+    }
+
+    #[test]
+    fn reuses_oldest_freed_slot_first() {
+        let mut allocator = FifoAllocator::new(3);
+        for _ in 0..3 {
+            allocator.allocate();
+        }
+
+        allocator.free(0);
+        allocator.free(1);
+        allocator.free(2);
+
+        assert_eq!(allocator.allocate(), Some(0));
+        assert_eq!(allocator.allocate(), Some(1));
+        assert_eq!(allocator.allocate(), Some(2));
+    }
+
+    #[test]
+    fn extend_queues_new_slots_last() {
+        let mut allocator = FifoAllocator::new(2);
+        allocator.allocate();
+        allocator.allocate();
+        allocator.free(1);
+
+        allocator.extend(2);
+        assert_eq!(allocator.capacity(), 4);
+        assert_eq!(allocator.available(), 3);
+        assert_eq!(allocator.allocate(), Some(1));
+        assert_eq!(allocator.allocate(), Some(2));
+        assert_eq!(allocator.allocate(), Some(3));
+    }
+}
@@ -15,8 +15,7 @@ pub(crate) struct FreeListAllocator {
     free_list: Vec<usize>,
     /// Total capacity
     capacity: usize,
-    /// Debug-mode tracking for double-free detection
-    #[cfg(debug_assertions)]
+    /// Occupancy bitmap (1 = allocated), also used for double-free detection
     allocated_bitmap: alloc::vec::Vec<u64>,
 }
 
@@ -29,7 +28,6 @@ impl FreeListAllocator {
         Self {
             free_list,
             capacity,
-            #[cfg(debug_assertions)]
             allocated_bitmap: {
                 let num_words = (capacity + 63) / 64;
                 alloc::vec![0u64; num_words]
@@ -37,20 +35,55 @@ impl FreeListAllocator {
         }
     }
 
+    /// Returns the number of heap bytes held by the free list and occupancy bitmap.
+    pub fn heap_bytes(&self) -> usize {
+        self.free_list.capacity() * core::mem::size_of::<usize>()
+            + self.allocated_bitmap.capacity() * core::mem::size_of::<u64>()
+    }
+
+    /// Creates an allocator in which the slots selected by `occupied` are already allocated.
+    pub fn with_occupied(capacity: usize, occupied: impl Fn(usize) -> bool) -> Self {
+        let mut allocator = Self::new(capacity);
+        allocator.free_list.retain(|&index| !occupied(index));
+
+        for index in (0..capacity).filter(|&index| occupied(index)) {
+            allocator.allocated_bitmap[index / 64] |= 1u64 << (index % 64);
+        }
+
+        allocator
+    }
+
     /// Extends the allocator with additional capacity.
     pub fn extend(&mut self, additional: usize) {
         let old_capacity = self.capacity;
-        self.capacity += additional;
+        self.capacity = self
+            .capacity
+            .checked_add(additional)
+            .expect("free list allocator capacity overflow");
 
-        #[cfg(debug_assertions)]
         {
-            let new_num_words = (self.capacity + 63) / 64;
+            let new_num_words = self.capacity / 64 + usize::from(self.capacity % 64 != 0);
             self.allocated_bitmap.resize(new_num_words, 0);
         }
 
         // Add new indices to the free list
         self.free_list.extend(old_capacity..self.capacity);
     }
+
+    /// Shrinks the allocator to `new_capacity` slots, dropping the free slots above it.
+    ///
+    /// Every slot at or above `new_capacity` must be free.
+    pub fn truncate(&mut self, new_capacity: usize) {
+        debug_assert!(
+            (new_capacity..self.capacity).all(|index| !self.is_allocated(index)),
+            "truncating allocated slots"
+        );
+
+        self.free_list.retain(|&index| index < new_capacity);
+        self.capacity = new_capacity;
+        self.allocated_bitmap
+            .truncate(new_capacity / 64 + usize::from(new_capacity % 64 != 0));
+    }
 }
 
 impl Allocator for FreeListAllocator {
@@ -58,7 +91,6 @@ impl Allocator for FreeListAllocator {
     fn allocate(&mut self) -> Option<usize> {
         let index = self.free_list.pop()?;
 
-        #[cfg(debug_assertions)]
         {
             let word_idx = index / 64;
             let bit_pos = index % 64;
@@ -78,7 +110,6 @@ impl Allocator for FreeListAllocator {
     fn free(&mut self, index: usize) {
         debug_assert!(index < self.capacity, "index out of bounds");
 
-        #[cfg(debug_assertions)]
         {
             let word_idx = index / 64;
             let bit_pos = index % 64;
@@ -103,6 +134,11 @@ impl Allocator for FreeListAllocator {
     fn capacity(&self) -> usize {
         self.capacity
     }
+
+    #[inline]
+    fn is_allocated(&self, index: usize) -> bool {
+        (self.allocated_bitmap[index / 64] & (1u64 << (index % 64))) != 0
+    }
 }
 
 #[cfg(test)]
@@ -176,4 +212,37 @@ mod tests {
         // Should reuse the freed slot
         assert_eq!(idx2, idx0);
     }
+
+    #[test]
+    fn truncate_drops_free_slots_above_capacity() {
+        let mut allocator = FreeListAllocator::new(4);
+        allocator.extend(100);
+        let used: Vec<_> = (0..10).map(|_| allocator.allocate().unwrap()).collect();
+        for index in used {
+            allocator.free(index);
+        }
+
+        allocator.truncate(4);
+        assert_eq!(allocator.capacity(), 4);
+        assert_eq!(allocator.available(), 4);
+        let mut indices: Vec<_> = (0..4).map(|_| allocator.allocate().unwrap()).collect();
+        indices.sort_unstable();
+        assert_eq!(indices, [0, 1, 2, 3]);
+        assert!(allocator.allocate().is_none());
+    }
+
+    #[test]
+    fn with_occupied_skips_taken_slots() {
+        let mut allocator = FreeListAllocator::with_occupied(70, |index| index % 2 == 1);
+        assert_eq!(allocator.available(), 35);
+        assert!(allocator.is_allocated(1));
+        assert!(allocator.is_allocated(69));
+        assert!(!allocator.is_allocated(68));
+
+        while let Some(index) = allocator.allocate() {
+            assert_eq!(index % 2, 0);
+        }
+        allocator.free(69);
+        assert_eq!(allocator.allocate(), Some(69));
+    }
 }
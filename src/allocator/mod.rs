@@ -1,12 +1,24 @@
-//! Internal allocation strategies for managing pool memory.
+//! Slot allocation strategies for managing pool memory.
+//!
+//! Every pool tracks which of its slots are free with a slot allocator. The
+//! built-in strategies are internal, but [`SlotAllocator`] exposes the same
+//! interface so a [`FixedPool`](crate::FixedPool) can be driven by a custom
+//! strategy through [`FixedPool::with_allocator`](crate::FixedPool::with_allocator).
+//! The functions in this module box the built-in strategies for the same
+//! constructor.
 
 mod bitmap;
+mod fifo;
 mod freelist;
+mod pool_allocator;
 mod stack;
 
-#[allow(unused)]
+use alloc::boxed::Box;
+
 pub(crate) use bitmap::BitmapAllocator;
+pub(crate) use fifo::FifoAllocator;
 pub(crate) use freelist::FreeListAllocator;
+pub(crate) use pool_allocator::PoolAllocator;
 pub(crate) use stack::StackAllocator;
 
 /// Trait for internal allocation strategies.
@@ -26,6 +38,9 @@ pub(crate) trait Allocator {
     /// Returns the total capacity.
     fn capacity(&self) -> usize;
 
+    /// Returns whether the slot at `index` is currently allocated.
+    fn is_allocated(&self, index: usize) -> bool;
+
     /// Returns whether the allocator is full.
     #[inline]
     fn is_full(&self) -> bool {
@@ -39,6 +54,146 @@ pub(crate) trait Allocator {
     }
 }
 
+/// Decides which slot of a pool each allocation uses.
+///
+/// An allocator manages the indices `0..capacity()`: [`allocate`](Self::allocate)
+/// hands out a free index and marks it allocated, [`free`](Self::free) returns
+/// it. The pool only ever frees indices the allocator handed out, and never
+/// frees one twice. Implementations are free to choose any order, e.g. to
+/// keep allocations packed at the front of the pool.
+///
+/// # Safety
+///
+/// The pool writes to and drops slots based solely on what the allocator
+/// reports, so an implementation must uphold:
+///
+/// - [`allocate`](Self::allocate) only returns indices below
+///   [`capacity`](Self::capacity), which never changes.
+/// - An index returned by `allocate` is not returned again until it has been
+///   passed to [`free`](Self::free).
+/// - [`is_allocated`](Self::is_allocated) is `true` exactly for the indices
+///   handed out and not yet freed, and [`available`](Self::available) counts
+///   the rest.
+///
+/// Violating any of these lets two handles alias one slot or makes the pool
+/// read or drop an uninitialized slot.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::allocator::SlotAllocator;
+/// use std::collections::BTreeSet;
+///
+/// /// Always hands out the lowest free index.
+/// struct LowestFirst {
+///     free: BTreeSet<usize>,
+///     capacity: usize,
+/// }
+///
+/// // SAFETY: indices come from a set of `0..capacity`, so each is handed
+/// // out once until it is inserted back by `free`.
+/// unsafe impl SlotAllocator for LowestFirst {
+///     fn allocate(&mut self) -> Option<usize> {
+///         self.free.pop_first()
+///     }
+///
+///     fn free(&mut self, index: usize) {
+///         self.free.insert(index);
+///     }
+///
+///     fn available(&self) -> usize {
+///         self.free.len()
+///     }
+///
+///     fn capacity(&self) -> usize {
+///         self.capacity
+///     }
+///
+///     fn is_allocated(&self, index: usize) -> bool {
+///         !self.free.contains(&index)
+///     }
+/// }
+/// ```
+pub unsafe trait SlotAllocator: Send {
+    /// Allocates a free slot, returning its index, or `None` if every slot is taken.
+    fn allocate(&mut self) -> Option<usize>;
+
+    /// Frees a slot previously returned by [`allocate`](Self::allocate).
+    fn free(&mut self, index: usize);
+
+    /// Returns the number of free slots.
+    fn available(&self) -> usize;
+
+    /// Returns the number of slots managed by this allocator.
+    fn capacity(&self) -> usize;
+
+    /// Returns whether the slot at `index` is currently allocated.
+    fn is_allocated(&self, index: usize) -> bool;
+
+    /// Returns whether every slot is allocated.
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.available() == 0
+    }
+
+    /// Returns whether every slot is free.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.available() == self.capacity()
+    }
+}
+
+// SAFETY: the built-in allocators hand out each index in `0..capacity` at
+// most once until it is freed, and report allocation state accurately.
+unsafe impl<A: Allocator + Send> SlotAllocator for A {
+    #[inline]
+    fn allocate(&mut self) -> Option<usize> {
+        Allocator::allocate(self)
+    }
+
+    #[inline]
+    fn free(&mut self, index: usize) {
+        Allocator::free(self, index)
+    }
+
+    #[inline]
+    fn available(&self) -> usize {
+        Allocator::available(self)
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        Allocator::capacity(self)
+    }
+
+    #[inline]
+    fn is_allocated(&self, index: usize) -> bool {
+        Allocator::is_allocated(self, index)
+    }
+}
+
+/// Returns the built-in LIFO allocator, which reuses the most recently freed slot first.
+///
+/// This is the strategy `FixedPool` uses by default.
+pub fn stack(capacity: usize) -> Box<dyn SlotAllocator> {
+    Box::new(StackAllocator::new(capacity))
+}
+
+/// Returns the built-in free-list allocator used by `GrowingPool`.
+pub fn free_list(capacity: usize) -> Box<dyn SlotAllocator> {
+    Box::new(FreeListAllocator::new(capacity))
+}
+
+/// Returns the built-in bitmap allocator, which uses one bit of metadata per slot.
+pub fn bitmap(capacity: usize) -> Box<dyn SlotAllocator> {
+    Box::new(BitmapAllocator::new(capacity))
+}
+
+/// Returns the built-in FIFO allocator, which reuses the oldest freed slot first.
+pub fn fifo(capacity: usize) -> Box<dyn SlotAllocator> {
+    Box::new(FifoAllocator::new(capacity))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,6 +209,7 @@ mod tests {
         let mut indices = Vec::new();
         for _ in 0..capacity {
             let idx = allocator.allocate().expect("should allocate");
+            assert!(allocator.is_allocated(idx));
             indices.push(idx);
         }
 
@@ -67,6 +223,7 @@ mod tests {
         // Free all slots
         for idx in indices {
             allocator.free(idx);
+            assert!(!allocator.is_allocated(idx));
         }
 
         assert_eq!(allocator.available(), capacity);
@@ -88,4 +245,27 @@ mod tests {
     fn test_bitmap_allocator() {
         test_allocator(BitmapAllocator::new(100));
     }
+
+    #[test]
+    fn test_fifo_allocator() {
+        test_allocator(FifoAllocator::new(100));
+    }
+
+    #[test]
+    fn boxed_builtin_allocators_follow_the_contract() {
+        for mut allocator in [stack(10), free_list(10), bitmap(10), fifo(10)] {
+            assert_eq!(allocator.capacity(), 10);
+            assert!(allocator.is_empty());
+
+            let indices: Vec<_> = (0..10).map(|_| allocator.allocate().unwrap()).collect();
+            assert!(allocator.is_full());
+            assert!(allocator.allocate().is_none());
+
+            for index in indices {
+                assert!(allocator.is_allocated(index));
+                allocator.free(index);
+            }
+            assert_eq!(allocator.available(), 10);
+        }
+    }
 }
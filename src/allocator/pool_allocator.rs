@@ -0,0 +1,240 @@
+//! Slot allocator used by `FixedPool`, either built-in or user-provided.
+
+use super::{Allocator, SlotAllocator, StackAllocator};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+/// The slot allocator of a `FixedPool`.
+///
+/// The built-in [`StackAllocator`] supports batch and run allocation
+/// directly. A custom [`SlotAllocator`] only provides single-slot
+/// operations, so the extended operations fall back to building on those,
+/// or report that they are unsupported.
+pub(crate) enum PoolAllocator {
+    /// The default LIFO allocator
+    Stack(StackAllocator),
+    /// An allocator provided through `FixedPool::with_allocator`
+    Custom(Box<dyn SlotAllocator>),
+}
+
+impl PoolAllocator {
+    /// Switches lowest-index-first allocation on or off.
+    ///
+    /// Custom allocators decide their own order, so this only affects the
+    /// built-in allocator.
+    pub fn set_sorted(&mut self, sorted: bool) {
+        if let PoolAllocator::Stack(stack) = self {
+            stack.set_sorted(sorted);
+        }
+    }
+
+    /// Returns the number of heap bytes held by the allocator.
+    ///
+    /// Only the boxed value itself is known for custom allocators; anything
+    /// they allocate internally is not counted.
+    pub fn heap_bytes(&self) -> usize {
+        match self {
+            PoolAllocator::Stack(stack) => stack.heap_bytes(),
+            PoolAllocator::Custom(custom) => core::mem::size_of_val(&**custom),
+        }
+    }
+
+    /// Returns the index the next call to `allocate` would hand out.
+    ///
+    /// Custom allocators cannot be queried without allocating, so they always report `None`.
+    #[inline]
+    pub fn peek(&self) -> Option<usize> {
+        match self {
+            PoolAllocator::Stack(stack) => stack.peek(),
+            PoolAllocator::Custom(_) => None,
+        }
+    }
+
+    /// Returns the occupancy bits of slots `64 * word .. 64 * word + 64`.
+    ///
+    /// Bit `n` is set when slot `64 * word + n` is allocated.
+    #[inline]
+    pub fn occupancy_word(&self, word: usize) -> u64 {
+        match self {
+            PoolAllocator::Stack(stack) => stack.occupancy_word(word),
+            PoolAllocator::Custom(custom) => {
+                let end = custom.capacity().min(word * 64 + 64);
+                (word * 64..end)
+                    .filter(|&index| custom.is_allocated(index))
+                    .fold(0, |bits, index| bits | 1u64 << (index % 64))
+            }
+        }
+    }
+
+    /// Returns the length of the longest run of consecutive free slots.
+    pub fn longest_free_run(&self) -> usize {
+        match self {
+            PoolAllocator::Stack(stack) => stack.longest_free_run(),
+            PoolAllocator::Custom(custom) => {
+                let (mut longest, mut current) = (0, 0);
+                for index in 0..custom.capacity() {
+                    current = if custom.is_allocated(index) {
+                        0
+                    } else {
+                        current + 1
+                    };
+                    longest = longest.max(current);
+                }
+                longest
+            }
+        }
+    }
+
+    /// Allocates `count` consecutive slots, returning the first index.
+    ///
+    /// Custom allocators cannot be asked for specific slots, so they always return `None`.
+    pub fn allocate_run(&mut self, count: usize) -> Option<usize> {
+        match self {
+            PoolAllocator::Stack(stack) => stack.allocate_run(count),
+            PoolAllocator::Custom(_) => None,
+        }
+    }
+
+    /// Allocates `count` slots in one operation, in the order `allocate` would return them.
+    ///
+    /// Returns `None` without allocating anything if fewer than `count`
+    /// slots are free.
+    pub fn allocate_many(&mut self, count: usize) -> Option<Vec<usize>> {
+        match self {
+            PoolAllocator::Stack(stack) => stack.allocate_many(count),
+            PoolAllocator::Custom(custom) => {
+                if custom.available() < count {
+                    return None;
+                }
+                (0..count).map(|_| custom.allocate()).collect()
+            }
+        }
+    }
+
+    /// Allocates a free slot that satisfies `accept`.
+    ///
+    /// Returns `None` without allocating anything if no free slot matches.
+    pub fn allocate_matching(&mut self, accept: impl Fn(usize) -> bool) -> Option<usize> {
+        match self {
+            PoolAllocator::Stack(stack) => stack.allocate_matching(accept),
+            PoolAllocator::Custom(custom) => {
+                // Take slots until one matches, then give the rejected ones back
+                let mut rejected = Vec::new();
+                let found = loop {
+                    match custom.allocate() {
+                        Some(index) if accept(index) => break Some(index),
+                        Some(index) => rejected.push(index),
+                        None => break None,
+                    }
+                };
+                for index in rejected {
+                    custom.free(index);
+                }
+                found
+            }
+        }
+    }
+
+    /// Frees a batch of previously allocated slots in one operation.
+    pub fn free_many(&mut self, indices: &[usize]) {
+        match self {
+            PoolAllocator::Stack(stack) => stack.free_many(indices),
+            PoolAllocator::Custom(custom) => {
+                for &index in indices {
+                    custom.free(index);
+                }
+            }
+        }
+    }
+}
+
+impl From<StackAllocator> for PoolAllocator {
+    fn from(stack: StackAllocator) -> Self {
+        PoolAllocator::Stack(stack)
+    }
+}
+
+impl Clone for PoolAllocator {
+    /// Clones the allocator state; a custom allocator becomes a built-in one
+    /// with the same slots allocated, since it cannot be cloned itself.
+    fn clone(&self) -> Self {
+        match self {
+            PoolAllocator::Stack(stack) => PoolAllocator::Stack(stack.clone()),
+            PoolAllocator::Custom(custom) => {
+                PoolAllocator::Stack(StackAllocator::with_occupied(custom.capacity(), |index| {
+                    custom.is_allocated(index)
+                }))
+            }
+        }
+    }
+}
+
+impl Allocator for PoolAllocator {
+    #[inline]
+    fn allocate(&mut self) -> Option<usize> {
+        match self {
+            PoolAllocator::Stack(stack) => Allocator::allocate(stack),
+            PoolAllocator::Custom(custom) => custom.allocate(),
+        }
+    }
+
+    #[inline]
+    fn free(&mut self, index: usize) {
+        match self {
+            PoolAllocator::Stack(stack) => Allocator::free(stack, index),
+            PoolAllocator::Custom(custom) => custom.free(index),
+        }
+    }
+
+    #[inline]
+    fn available(&self) -> usize {
+        match self {
+            PoolAllocator::Stack(stack) => Allocator::available(stack),
+            PoolAllocator::Custom(custom) => custom.available(),
+        }
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        match self {
+            PoolAllocator::Stack(stack) => Allocator::capacity(stack),
+            PoolAllocator::Custom(custom) => custom.capacity(),
+        }
+    }
+
+    #[inline]
+    fn is_allocated(&self, index: usize) -> bool {
+        match self {
+            PoolAllocator::Stack(stack) => Allocator::is_allocated(stack, index),
+            PoolAllocator::Custom(custom) => custom.is_allocated(index),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::allocator::FifoAllocator;
+
+    #[test]
+    fn custom_allocator_supports_batch_operations() {
+        let mut allocator = PoolAllocator::Custom(Box::new(FifoAllocator::new(70)));
+
+        let batch = allocator.allocate_many(3).unwrap();
+        assert_eq!(batch, [0, 1, 2]);
+        assert!(allocator.allocate_many(68).is_none());
+        assert_eq!(Allocator::available(&allocator), 67);
+
+        assert_eq!(allocator.allocate_matching(|index| index == 5), Some(5));
+        assert_eq!(allocator.occupancy_word(0), 0b10_0111);
+        assert_eq!(allocator.longest_free_run(), 64);
+        assert_eq!(allocator.allocate_run(2), None);
+        assert_eq!(allocator.peek(), None);
+
+        allocator.free_many(&batch);
+        let copy = allocator.clone();
+        assert!(matches!(copy, PoolAllocator::Stack(_)));
+        assert!(Allocator::is_allocated(&copy, 5));
+        assert_eq!(Allocator::available(&copy), 69);
+    }
+}
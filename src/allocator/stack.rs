@@ -1,6 +1,8 @@
 //! Stack-based (LIFO) allocator implementation.
 
+use super::bitmap::{find_zero_run, longest_zero_run};
 use super::Allocator;
+use alloc::collections::TryReserveError;
 use alloc::vec::Vec;
 
 /// A stack-based allocator that uses LIFO (Last-In-First-Out) allocation.
@@ -8,16 +10,22 @@ use alloc::vec::Vec;
 /// This allocator provides excellent cache locality since recently freed
 /// objects are likely to still be in cache when reallocated.
 ///
-/// Time complexity: O(1) for both allocation and deallocation.
+/// In sorted mode the free stack is kept in descending order instead, so the
+/// lowest free index is always handed out first regardless of free history.
+///
+/// Time complexity: O(1) for both allocation and deallocation, or O(n)
+/// deallocation in sorted mode.
 /// Space complexity: O(capacity) for storing free indices.
+#[derive(Clone)]
 pub(crate) struct StackAllocator {
     /// Stack of available indices (LIFO)
     free_stack: Vec<usize>,
     /// Total capacity
     capacity: usize,
-    /// Debug-mode tracking for double-free detection
-    #[cfg(debug_assertions)]
+    /// Occupancy bitmap (1 = allocated), also used for double-free detection
     allocated_bitmap: alloc::vec::Vec<u64>,
+    /// Keep `free_stack` sorted so the lowest index is always on top
+    sorted: bool,
 }
 
 impl StackAllocator {
@@ -33,29 +41,198 @@ impl StackAllocator {
         Self {
             free_stack,
             capacity,
-            #[cfg(debug_assertions)]
             allocated_bitmap: {
                 let num_words = (capacity + 63) / 64;
                 alloc::vec![0u64; num_words]
             },
+            sorted: false,
+        }
+    }
+
+    /// Returns the number of heap bytes held by the free list and occupancy bitmap.
+    pub fn heap_bytes(&self) -> usize {
+        self.free_stack.capacity() * core::mem::size_of::<usize>()
+            + self.allocated_bitmap.capacity() * core::mem::size_of::<u64>()
+    }
+
+    /// Creates an allocator in which the slots selected by `occupied` are already allocated.
+    pub fn with_occupied(capacity: usize, occupied: impl Fn(usize) -> bool) -> Self {
+        let mut allocator = Self::new(capacity);
+        allocator.occupy(occupied);
+        allocator
+    }
+
+    /// Like [`with_occupied`](Self::with_occupied), reporting allocation
+    /// failure instead of aborting.
+    #[cfg(feature = "serde")]
+    pub fn try_with_occupied(
+        capacity: usize,
+        occupied: impl Fn(usize) -> bool,
+    ) -> Result<Self, TryReserveError> {
+        let mut allocator = Self::try_new(capacity)?;
+        allocator.occupy(occupied);
+        Ok(allocator)
+    }
+
+    /// Marks the free slots selected by `occupied` as allocated.
+    fn occupy(&mut self, occupied: impl Fn(usize) -> bool) {
+        self.free_stack.retain(|&index| !occupied(index));
+
+        for index in (0..self.capacity).filter(|&index| occupied(index)) {
+            self.allocated_bitmap[index / 64] |= 1u64 << (index % 64);
         }
     }
 
+    /// Creates a new stack allocator, reporting allocation failure instead of aborting.
+    pub fn try_new(capacity: usize) -> Result<Self, TryReserveError> {
+        let mut free_stack = Vec::new();
+        free_stack.try_reserve_exact(capacity)?;
+        free_stack.extend((0..capacity).rev());
+
+        let mut allocated_bitmap = Vec::new();
+        allocated_bitmap.try_reserve_exact((capacity + 63) / 64)?;
+        allocated_bitmap.resize((capacity + 63) / 64, 0);
+
+        Ok(Self {
+            free_stack,
+            capacity,
+            allocated_bitmap,
+            sorted: false,
+        })
+    }
+
     /// Creates a new stack allocator with additional capacity.
     #[allow(dead_code)]
     pub fn with_additional_capacity(&mut self, additional: usize) {
         let old_capacity = self.capacity;
         self.capacity += additional;
 
-        #[cfg(debug_assertions)]
         {
             let new_num_words = (self.capacity + 63) / 64;
             self.allocated_bitmap.resize(new_num_words, 0);
         }
 
-        // Add new indices to the stack
-        for i in (old_capacity..self.capacity).rev() {
-            self.free_stack.push(i);
+        // Add new indices to the stack; in sorted mode they are the largest
+        // free indices, so they go underneath the existing ones
+        if self.sorted {
+            self.free_stack
+                .splice(0..0, (old_capacity..self.capacity).rev());
+        } else {
+            for i in (old_capacity..self.capacity).rev() {
+                self.free_stack.push(i);
+            }
+        }
+    }
+
+    /// Switches lowest-index-first allocation on or off.
+    ///
+    /// Turning it on sorts the current free stack once; afterwards every free
+    /// inserts in place so the smallest free index stays on top.
+    pub fn set_sorted(&mut self, sorted: bool) {
+        if sorted && !self.sorted {
+            self.free_stack.sort_unstable_by(|a, b| b.cmp(a));
+        }
+        self.sorted = sorted;
+    }
+
+    /// Pushes a freed index, keeping the stack ordered in sorted mode.
+    #[inline]
+    fn push_free(&mut self, index: usize) {
+        if self.sorted {
+            // Descending order: find the first position holding a smaller index
+            let position = self.free_stack.partition_point(|&free| free > index);
+            self.free_stack.insert(position, index);
+        } else {
+            self.free_stack.push(index);
+        }
+    }
+}
+
+impl StackAllocator {
+    /// Returns the index the next call to `allocate` would hand out.
+    #[inline]
+    pub fn peek(&self) -> Option<usize> {
+        self.free_stack.last().copied()
+    }
+
+    /// Returns the occupancy bits of slots `64 * word .. 64 * word + 64`.
+    ///
+    /// Bit `n` is set when slot `64 * word + n` is allocated.
+    #[inline]
+    pub fn occupancy_word(&self, word: usize) -> u64 {
+        self.allocated_bitmap[word]
+    }
+
+    /// Returns the length of the longest run of consecutive free slots.
+    #[inline]
+    pub fn longest_free_run(&self) -> usize {
+        longest_zero_run(&self.allocated_bitmap, self.capacity)
+    }
+
+    /// Allocates `count` consecutive slots, returning the first index.
+    ///
+    /// Returns `None` if no run of that length is free, even when enough
+    /// individual slots are available.
+    pub fn allocate_run(&mut self, count: usize) -> Option<usize> {
+        let start = find_zero_run(&self.allocated_bitmap, self.capacity, count)?;
+        let end = start + count;
+
+        for index in start..end {
+            self.allocated_bitmap[index / 64] |= 1u64 << (index % 64);
+        }
+        self.free_stack
+            .retain(|&index| index < start || index >= end);
+
+        Some(start)
+    }
+
+    /// Allocates `count` slots in one operation, in the order `allocate` would return them.
+    ///
+    /// Returns `None` without allocating anything if fewer than `count`
+    /// slots are free.
+    pub fn allocate_many(&mut self, count: usize) -> Option<Vec<usize>> {
+        let split = self.free_stack.len().checked_sub(count)?;
+        let mut indices = self.free_stack.split_off(split);
+        indices.reverse();
+
+        for &index in &indices {
+            self.allocated_bitmap[index / 64] |= 1u64 << (index % 64);
+        }
+
+        Some(indices)
+    }
+
+    /// Allocates the free slot closest to the top of the stack that satisfies `accept`.
+    ///
+    /// Returns `None` without allocating anything if no free slot matches.
+    pub fn allocate_matching(&mut self, accept: impl Fn(usize) -> bool) -> Option<usize> {
+        let position = self.free_stack.iter().rposition(|&index| accept(index))?;
+        // `remove` keeps the remaining stack order, including in sorted mode
+        let index = self.free_stack.remove(position);
+        self.allocated_bitmap[index / 64] |= 1u64 << (index % 64);
+
+        Some(index)
+    }
+
+    /// Frees a batch of previously allocated slots in one operation.
+    pub fn free_many(&mut self, indices: &[usize]) {
+        for &index in indices {
+            debug_assert!(index < self.capacity, "index out of bounds");
+
+            let word_idx = index / 64;
+            let bit_pos = index % 64;
+            debug_assert_ne!(
+                self.allocated_bitmap[word_idx] & (1u64 << bit_pos),
+                0,
+                "double free detected for index {}",
+                index
+            );
+            self.allocated_bitmap[word_idx] &= !(1u64 << bit_pos);
+        }
+
+        self.free_stack.extend_from_slice(indices);
+        if self.sorted {
+            self.free_stack.sort_unstable_by(|a, b| b.cmp(a));
         }
     }
 }
@@ -65,7 +242,6 @@ impl Allocator for StackAllocator {
     fn allocate(&mut self) -> Option<usize> {
         let index = self.free_stack.pop()?;
 
-        #[cfg(debug_assertions)]
         {
             let word_idx = index / 64;
             let bit_pos = index % 64;
@@ -85,7 +261,6 @@ impl Allocator for StackAllocator {
     fn free(&mut self, index: usize) {
         debug_assert!(index < self.capacity, "index out of bounds");
 
-        #[cfg(debug_assertions)]
         {
             let word_idx = index / 64;
             let bit_pos = index % 64;
@@ -98,7 +273,7 @@ impl Allocator for StackAllocator {
             self.allocated_bitmap[word_idx] &= !(1u64 << bit_pos);
         }
 
-        self.free_stack.push(index);
+        self.push_free(index);
     }
 
     #[inline]
@@ -110,6 +285,11 @@ impl Allocator for StackAllocator {
     fn capacity(&self) -> usize {
         self.capacity
     }
+
+    #[inline]
+    fn is_allocated(&self, index: usize) -> bool {
+        (self.allocated_bitmap[index / 64] & (1u64 << (index % 64))) != 0
+    }
 }
 
 #[cfg(test)]
@@ -157,6 +337,20 @@ mod tests {
         assert_eq!(allocator.allocate(), Some(idx0));
     }
 
+    #[test]
+    fn free_many_restores_slots() {
+        let mut allocator = StackAllocator::new(4);
+
+        let indices: Vec<usize> = (0..4).map(|_| allocator.allocate().unwrap()).collect();
+        assert!(allocator.is_full());
+
+        allocator.free_many(&indices);
+        assert!(allocator.is_empty());
+
+        // Last freed index is reused first
+        assert_eq!(allocator.allocate(), Some(3));
+    }
+
     #[test]
     fn with_additional_capacity() {
         let mut allocator = StackAllocator::new(2);
@@ -175,4 +369,108 @@ mod tests {
         assert_eq!(allocator.allocate(), Some(4));
         assert!(allocator.is_full());
     }
+
+    #[test]
+    fn try_new_matches_new() {
+        let mut allocator = StackAllocator::try_new(3).unwrap();
+        assert_eq!(allocator.capacity(), 3);
+        assert_eq!(allocator.allocate(), Some(0));
+        assert_eq!(allocator.available(), 2);
+
+        assert!(StackAllocator::try_new(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn allocate_run_claims_consecutive_slots() {
+        let mut allocator = StackAllocator::new(130);
+        for _ in 0..60 {
+            allocator.allocate();
+        }
+
+        assert_eq!(allocator.allocate_run(10), Some(60));
+        assert_eq!(allocator.available(), 60);
+        assert!((60..70).all(|index| allocator.is_allocated(index)));
+
+        // Single allocations skip the claimed run
+        assert_eq!(allocator.allocate(), Some(70));
+
+        let run: Vec<usize> = (60..70).collect();
+        allocator.free_many(&run);
+        assert_eq!(allocator.allocate_run(70), None);
+        assert_eq!(allocator.allocate_run(59), Some(71));
+    }
+
+    #[test]
+    fn sorted_mode_hands_out_lowest_index() {
+        let mut allocator = StackAllocator::new(6);
+        for _ in 0..6 {
+            allocator.allocate();
+        }
+
+        allocator.free(4);
+        allocator.free(1);
+        allocator.free_many(&[5, 0]);
+        allocator.set_sorted(true);
+        allocator.free(3);
+
+        assert_eq!(allocator.peek(), Some(0));
+        assert_eq!(allocator.allocate(), Some(0));
+        assert_eq!(allocator.allocate(), Some(1));
+        assert_eq!(allocator.allocate(), Some(3));
+
+        allocator.with_additional_capacity(2);
+        assert_eq!(allocator.allocate(), Some(4));
+        assert_eq!(allocator.allocate(), Some(5));
+        assert_eq!(allocator.allocate(), Some(6));
+        assert_eq!(allocator.allocate(), Some(7));
+        assert_eq!(allocator.peek(), None);
+    }
+
+    #[test]
+    fn allocate_many_matches_single_allocations() {
+        let mut allocator = StackAllocator::new(5);
+        allocator.allocate();
+        allocator.free(0);
+
+        assert_eq!(allocator.allocate_many(6), None);
+        assert_eq!(allocator.available(), 5);
+
+        assert_eq!(allocator.allocate_many(3), Some(alloc::vec![0, 1, 2]));
+        assert!((0..3).all(|index| allocator.is_allocated(index)));
+        assert_eq!(allocator.allocate(), Some(3));
+    }
+
+    #[test]
+    fn allocate_matching_skips_rejected_slots() {
+        let mut allocator = StackAllocator::new(8);
+
+        assert_eq!(allocator.allocate_matching(|index| index % 4 == 3), Some(3));
+        assert_eq!(allocator.allocate_matching(|index| index % 4 == 3), Some(7));
+        assert_eq!(allocator.allocate_matching(|index| index % 4 == 3), None);
+        assert!(allocator.is_allocated(3));
+        assert_eq!(allocator.available(), 6);
+
+        // Untouched slots are still handed out in order
+        assert_eq!(allocator.allocate(), Some(0));
+        assert_eq!(allocator.allocate(), Some(1));
+        assert_eq!(allocator.allocate(), Some(2));
+        assert_eq!(allocator.allocate(), Some(4));
+    }
+
+    #[test]
+    fn with_occupied_skips_taken_slots() {
+        let mut allocator = StackAllocator::with_occupied(70, |index| index % 3 == 0);
+        assert_eq!(allocator.available(), 46);
+        assert!(allocator.is_allocated(0));
+        assert!(allocator.is_allocated(69));
+        assert!(!allocator.is_allocated(68));
+
+        // Free slots are still handed out lowest index first
+        assert_eq!(allocator.allocate(), Some(1));
+        while let Some(index) = allocator.allocate() {
+            assert_ne!(index % 3, 0);
+        }
+        allocator.free(69);
+        assert_eq!(allocator.allocate(), Some(69));
+    }
 }
@@ -1,9 +1,13 @@
 //! Builder for pool configuration.
 
-use super::{GrowthStrategy, InitializationStrategy, PoolConfig};
+use super::{
+    GrowHook, GrowthStrategy, InitializationStrategy, Observer, PoolConfig, PoolEvent,
+    ShrinkPolicy, Watermark,
+};
 use crate::error::{Error, Result};
 use crate::utils::validate_alignment;
 use core::mem;
+use core::num::NonZeroUsize;
 
 /// Builder for constructing a `PoolConfig` with validation.
 ///
@@ -29,6 +33,17 @@ pub struct PoolConfigBuilder<T> {
     pre_initialize: bool,
     initialization_strategy: InitializationStrategy<T>,
     thread_local: bool,
+    zero_on_release: bool,
+    contiguous: bool,
+    reset_on_acquire: bool,
+    align_growth_to_words: bool,
+    deterministic: bool,
+    shrink_policy: Option<ShrinkPolicy>,
+    observer: Option<Observer>,
+    on_grow: Option<GrowHook>,
+    high_watermark: Option<Watermark>,
+    low_watermark: Option<Watermark>,
+    name: Option<&'static str>,
 }
 
 impl<T> PoolConfigBuilder<T> {
@@ -42,6 +57,44 @@ impl<T> PoolConfigBuilder<T> {
             pre_initialize: false,
             initialization_strategy: InitializationStrategy::Lazy,
             thread_local: false,
+            zero_on_release: false,
+            contiguous: false,
+            reset_on_acquire: false,
+            align_growth_to_words: false,
+            deterministic: false,
+            shrink_policy: None,
+            observer: None,
+            on_grow: None,
+            high_watermark: None,
+            low_watermark: None,
+            name: None,
+        }
+    }
+
+    /// Creates a builder carrying over every closure-free setting of `config`.
+    pub(crate) fn from_settings(config: &PoolConfig<T>) -> Self {
+        Self {
+            capacity: Some(config.capacity),
+            max_capacity: config.max_capacity,
+            growth_strategy: config
+                .growth_strategy
+                .try_clone()
+                .unwrap_or(GrowthStrategy::None),
+            alignment: config.alignment,
+            pre_initialize: config.pre_initialize,
+            initialization_strategy: InitializationStrategy::Lazy,
+            thread_local: config.thread_local,
+            zero_on_release: config.zero_on_release,
+            contiguous: config.contiguous,
+            reset_on_acquire: config.reset_on_acquire,
+            align_growth_to_words: config.align_growth_to_words,
+            deterministic: config.deterministic,
+            shrink_policy: config.shrink_policy,
+            observer: None,
+            on_grow: None,
+            high_watermark: None,
+            low_watermark: None,
+            name: config.name,
         }
     }
 
@@ -53,9 +106,42 @@ impl<T> PoolConfigBuilder<T> {
         self
     }
 
+    /// Sets the initial capacity of the pool from a value known to be non-zero.
+    ///
+    /// Equivalent to [`capacity`](Self::capacity), but rules out the
+    /// zero-capacity error at compile time.
+    pub fn capacity_nonzero(mut self, capacity: NonZeroUsize) -> Self {
+        self.capacity = Some(capacity.get());
+        self
+    }
+
+    /// Sets a name that identifies the pool in error messages and statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{FixedPool, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(1)
+    ///     .name("connection_pool")
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = FixedPool::with_config(config).unwrap();
+    ///
+    /// let _connection = pool.allocate(1).unwrap();
+    /// let error = pool.allocate(2).unwrap_err();
+    /// assert!(error.to_string().starts_with("Pool 'connection_pool' exhausted"));
+    /// ```
+    pub fn name(mut self, name: &'static str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
     /// Sets the maximum capacity of the pool.
     ///
-    /// If set, the pool will not grow beyond this size.
+    /// If set, the pool will not grow beyond this size; a growth step that
+    /// would overshoot it grows the pool to exactly this size instead.
     /// If `None`, the pool can grow indefinitely (subject to memory availability).
     pub fn max_capacity(mut self, max_capacity: Option<usize>) -> Self {
         self.max_capacity = max_capacity;
@@ -68,9 +154,36 @@ impl<T> PoolConfigBuilder<T> {
         self
     }
 
+    /// Sets a custom growth strategy from a closure.
+    ///
+    /// Shorthand for `growth_strategy(GrowthStrategy::Custom { compute: Box::new(compute) })`.
+    /// The closure receives the current capacity and returns the number of
+    /// slots to add.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::PoolConfig;
+    ///
+    /// let config = PoolConfig::<i32>::builder()
+    ///     .capacity(16)
+    ///     .growth_fn(|current| current / 2)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(config.growth_strategy().compute_growth(16), 8);
+    /// ```
+    pub fn growth_fn(self, compute: impl Fn(usize) -> usize + Send + Sync + 'static) -> Self {
+        self.growth_strategy(GrowthStrategy::Custom {
+            compute: alloc::boxed::Box::new(compute),
+        })
+    }
+
     /// Sets the memory alignment for pool objects.
     ///
     /// Must be a power of two. Defaults to the natural alignment of `T`.
+    /// A `FixedPool` aligns the start of its storage to this value, which
+    /// [`FixedPool::allocate_aligned`](crate::FixedPool::allocate_aligned)
+    /// relies on.
     pub fn alignment(mut self, alignment: usize) -> Self {
         self.alignment = alignment;
         self
@@ -80,6 +193,10 @@ impl<T> PoolConfigBuilder<T> {
     ///
     /// If `true`, all initial capacity will be allocated and initialized eagerly.
     /// If `false`, objects are initialized on first use.
+    ///
+    /// A `FixedPool` with an initializer constructs an object in every slot
+    /// when it is created. Without an initializer there is nothing to
+    /// construct and the pool stays lazy.
     pub fn pre_initialize(mut self, pre_initialize: bool) -> Self {
         self.pre_initialize = pre_initialize;
         self
@@ -92,6 +209,11 @@ impl<T> PoolConfigBuilder<T> {
     }
 
     /// Sets a custom reset function to be called when objects are returned to the pool.
+    ///
+    /// A `FixedPool` with a reset function runs it every time a handle is
+    /// dropped and keeps the reset object initialized for reuse via
+    /// `FixedPool::allocate_recycled` instead of dropping it. `GrowingPool`
+    /// does not recycle and drops released objects without calling it.
     pub fn reset_fn(
         mut self,
         initializer: impl Fn() -> T + Send + Sync + 'static,
@@ -117,6 +239,257 @@ impl<T> PoolConfigBuilder<T> {
         self
     }
 
+    /// Sets whether a slot's memory is wiped when its object is returned to the pool.
+    ///
+    /// When enabled, the pool overwrites the slot's bytes with zeros after calling
+    /// `on_release` and dropping the value. The wipe uses volatile writes so the
+    /// compiler cannot elide it, which makes this suitable for pooling buffers that
+    /// hold secrets such as keys or tokens.
+    ///
+    /// A `FixedPool` with a reset function keeps released objects alive for
+    /// reuse, so there is nothing to wipe on release and the reset function
+    /// must clear any secrets itself. The slot is wiped once the recycled
+    /// object is finally dropped, when a new value replaces it or the pool is
+    /// dropped.
+    pub fn zero_on_release(mut self, zero_on_release: bool) -> Self {
+        self.zero_on_release = zero_on_release;
+        self
+    }
+
+    /// Sets whether a `GrowingPool` keeps all slots in one contiguous region.
+    ///
+    /// By default a growing pool adds a separate storage chunk on each growth,
+    /// so objects never move. With contiguous storage the pool instead
+    /// reallocates its single backing buffer (like `Vec::reserve`) and moves
+    /// the existing objects, keeping every slot adjacent for better cache
+    /// locality. This option is independent of the growth strategy.
+    ///
+    /// `ThreadSafePool` caches object pointers in its handles and rejects
+    /// contiguous configurations.
+    ///
+    /// # Safety
+    ///
+    /// Growth moves every object in the pool. Handles stay valid because they
+    /// refer to slots by index, but references obtained by dereferencing a
+    /// handle, and `MappedHandle`s, point at the old location. The caller must
+    /// not keep such references or mapped handles alive across an allocation
+    /// that may grow the pool.
+    pub unsafe fn contiguous(mut self, contiguous: bool) -> Self {
+        self.contiguous = contiguous;
+        self
+    }
+
+    /// Sets whether the reset function also runs when an object is acquired.
+    ///
+    /// Normally the reset function set with [`reset_fn`](Self::reset_fn)
+    /// only runs when an object is released. With this flag every object is
+    /// also reset right before it is handed out (before its `on_acquire`
+    /// hook), whether it was passed to `allocate` or recycled, guaranteeing a
+    /// clean state even if a release path was skipped. Has no effect without
+    /// a reset function.
+    pub fn reset_on_acquire(mut self, reset_on_acquire: bool) -> Self {
+        self.reset_on_acquire = reset_on_acquire;
+        self
+    }
+
+    /// Sets whether a `GrowingPool` rounds each growth up to a multiple of 64 slots.
+    ///
+    /// Occupancy is tracked in 64-bit bitmap words. When the grown capacity is
+    /// not a multiple of 64, the last word is only partly usable and free-slot
+    /// scans have to bounds-check it. With this flag the growth amount is
+    /// rounded up so the capacity after growing is word-aligned, unless that
+    /// would exceed `max_capacity`, in which case growth stops at the maximum.
+    pub fn align_growth_to_words(mut self, align: bool) -> Self {
+        self.align_growth_to_words = align;
+        self
+    }
+
+    /// Sets whether a `FixedPool` always hands out the lowest free index.
+    ///
+    /// By default freed slots are reused last-in-first-out, so the index an
+    /// allocation receives depends on the order of earlier frees. With this
+    /// flag the smallest free index is always chosen, which makes slot
+    /// assignment reproducible across replays. Frees become O(n) in the
+    /// number of free slots.
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// Sets when a `GrowingPool` frees chunks it grew but no longer needs.
+    ///
+    /// See [`ShrinkPolicy`] for how the policy avoids growing and shrinking
+    /// back and forth. Contiguous pools and other pool types do not shrink.
+    pub fn auto_shrink(mut self, policy: ShrinkPolicy) -> Self {
+        self.shrink_policy = Some(policy);
+        self
+    }
+
+    /// Sets a callback invoked whenever the pool allocates, frees or grows.
+    ///
+    /// The observer receives a [`PoolEvent`] with the slot index and the
+    /// number of allocated slots, which makes it suitable for tracing and
+    /// leak detection. It is called after the pool has finished updating its
+    /// state, so it may query the pool. Thread-safe pools call it while
+    /// holding their lock, so it must not allocate from or drop handles of
+    /// the same thread-safe pool.
+    pub fn observer(mut self, observer: impl Fn(PoolEvent) + Send + Sync + 'static) -> Self {
+        self.observer = Some(alloc::boxed::Box::new(observer));
+        self
+    }
+
+    /// Sets a callback invoked after each successful growth of a `GrowingPool`.
+    ///
+    /// The callback receives `(old_capacity, new_capacity)`, which is handy
+    /// for resizing side tables indexed by slot. It runs once the new
+    /// capacity is visible, so it may query the pool.
+    pub fn on_grow(mut self, on_grow: impl Fn(usize, usize) + Send + Sync + 'static) -> Self {
+        self.on_grow = Some(alloc::boxed::Box::new(on_grow));
+        self
+    }
+
+    /// Sets a callback invoked when utilization rises above `ratio`.
+    ///
+    /// Utilization is `allocated / capacity`, checked on every allocation
+    /// and release of a [`FixedPool`](crate::FixedPool); other pools ignore
+    /// watermarks. The callback receives the number of allocated slots and
+    /// fires once per crossing: it fires again only after utilization has
+    /// fallen below the low watermark (or below `ratio`, if no low
+    /// watermark is set). Setting both gives hysteresis, so a pool hovering
+    /// around one threshold does not fire repeatedly. `ratio` must be
+    /// between 0.0 and 1.0.
+    ///
+    /// Like the [`observer`](Self::observer), the callback runs after the
+    /// pool has updated its state and may query the pool.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{FixedPool, PoolConfig};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let scale_ups = Arc::new(AtomicUsize::new(0));
+    /// let counter = Arc::clone(&scale_ups);
+    /// let config = PoolConfig::builder()
+    ///     .capacity(10)
+    ///     .on_high_watermark(0.8, move |_usage| {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///     })
+    ///     .on_low_watermark(0.2, |_usage| {})
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = FixedPool::with_config(config).unwrap();
+    ///
+    /// let handles: Vec<_> = (0..10).map(|i| pool.allocate(i).unwrap()).collect();
+    /// assert_eq!(scale_ups.load(Ordering::Relaxed), 1);
+    /// # drop(handles);
+    /// ```
+    pub fn on_high_watermark(
+        mut self,
+        ratio: f64,
+        callback: impl Fn(usize) + Send + Sync + 'static,
+    ) -> Self {
+        self.high_watermark = Some(Watermark {
+            ratio,
+            callback: alloc::boxed::Box::new(callback),
+        });
+        self
+    }
+
+    /// Sets a callback invoked when utilization falls below `ratio`.
+    ///
+    /// The counterpart of [`on_high_watermark`](Self::on_high_watermark):
+    /// it fires once the pool drains below `ratio` after having risen above
+    /// the high watermark (or above `ratio`, if no high watermark is set).
+    /// `ratio` must be between 0.0 and 1.0, and below the high watermark's.
+    pub fn on_low_watermark(
+        mut self,
+        ratio: f64,
+        callback: impl Fn(usize) + Send + Sync + 'static,
+    ) -> Self {
+        self.low_watermark = Some(Watermark {
+            ratio,
+            callback: alloc::boxed::Box::new(callback),
+        });
+        self
+    }
+
+    /// Overrides builder fields from environment variables.
+    ///
+    /// Reads `{prefix}_CAPACITY`, `{prefix}_MAX_CAPACITY`, `{prefix}_ALIGNMENT`
+    /// and `{prefix}_GROWTH`. Variables that are not set leave the current
+    /// value alone, so defaults can be set in code and tuned per deployment.
+    /// `MAX_CAPACITY` also accepts `none` to remove the limit, and `GROWTH`
+    /// takes `none`, `linear:<amount>` or `exp:<factor>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::PoolConfig;
+    ///
+    /// // With MYPOOL_CAPACITY=4096 in the environment, that value wins
+    /// let config = PoolConfig::<u64>::builder()
+    ///     .capacity(1024)
+    ///     .apply_env("MYPOOL")
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// # let _ = config;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` naming the variable if a set
+    /// value cannot be parsed. Range checks still happen in
+    /// [`build`](Self::build).
+    #[cfg(feature = "std")]
+    pub fn apply_env(mut self, prefix: &str) -> Result<Self> {
+        let read = |suffix: &str| -> Result<Option<String>> {
+            match std::env::var(format!("{}_{}", prefix, suffix)) {
+                Ok(value) => Ok(Some(value.trim().to_owned())),
+                Err(std::env::VarError::NotPresent) => Ok(None),
+                Err(std::env::VarError::NotUnicode(_)) => Err(Error::invalid_config(
+                    "pool environment variable is not valid unicode",
+                )),
+            }
+        };
+
+        if let Some(value) = read("CAPACITY")? {
+            self.capacity = Some(value.parse().map_err(|_| {
+                Error::invalid_config("<PREFIX>_CAPACITY must be an unsigned integer")
+            })?);
+        }
+
+        if let Some(value) = read("MAX_CAPACITY")? {
+            self.max_capacity = if value.eq_ignore_ascii_case("none") {
+                None
+            } else {
+                Some(value.parse().map_err(|_| {
+                    Error::invalid_config(
+                        "<PREFIX>_MAX_CAPACITY must be an unsigned integer or `none`",
+                    )
+                })?)
+            };
+        }
+
+        if let Some(value) = read("ALIGNMENT")? {
+            self.alignment = value.parse().map_err(|_| {
+                Error::invalid_config("<PREFIX>_ALIGNMENT must be an unsigned integer")
+            })?;
+        }
+
+        if let Some(value) = read("GROWTH")? {
+            self.growth_strategy = value.parse().map_err(|_| {
+                Error::invalid_config(
+                    "<PREFIX>_GROWTH must be `none`, `linear:<amount>` or `exp:<factor>`",
+                )
+            })?;
+        }
+
+        Ok(self)
+    }
+
     /// Builds the configuration, validating all parameters.
     ///
     /// # Errors
@@ -145,14 +518,33 @@ impl<T> PoolConfigBuilder<T> {
         // Validate alignment
         validate_alignment(self.alignment)?;
 
-        // Ensure pre_initialize and initialization strategy are consistent
-        let initialization_strategy =
-            if self.pre_initialize && self.initialization_strategy.is_lazy() {
-                // If pre_initialize is true but strategy is lazy, upgrade to eager with default
-                InitializationStrategy::Lazy // Will be handled by pool implementation
-            } else {
-                self.initialization_strategy
-            };
+        // Validate watermarks
+        let ratios = [&self.high_watermark, &self.low_watermark];
+        if ratios
+            .iter()
+            .flat_map(|watermark| watermark.as_ref())
+            .any(|watermark| !(0.0..=1.0).contains(&watermark.ratio))
+        {
+            return Err(Error::invalid_config(
+                "watermark ratios must be between 0.0 and 1.0",
+            ));
+        }
+        if let (Some(high), Some(low)) = (&self.high_watermark, &self.low_watermark) {
+            if low.ratio >= high.ratio {
+                return Err(Error::invalid_config(
+                    "low watermark must be below the high watermark",
+                ));
+            }
+        }
+
+        // Validate shrink policy
+        if let Some(policy) = &self.shrink_policy {
+            if !(0.0..=1.0).contains(&policy.min_free_ratio) {
+                return Err(Error::invalid_config(
+                    "shrink policy min_free_ratio must be between 0.0 and 1.0",
+                ));
+            }
+        }
 
         Ok(PoolConfig {
             capacity,
@@ -160,10 +552,111 @@ impl<T> PoolConfigBuilder<T> {
             growth_strategy: self.growth_strategy,
             alignment: self.alignment,
             pre_initialize: self.pre_initialize,
-            initialization_strategy,
+            initialization_strategy: self.initialization_strategy,
             thread_local: self.thread_local,
+            zero_on_release: self.zero_on_release,
+            contiguous: self.contiguous,
+            reset_on_acquire: self.reset_on_acquire,
+            align_growth_to_words: self.align_growth_to_words,
+            deterministic: self.deterministic,
+            shrink_policy: self.shrink_policy,
+            observer: self.observer,
+            on_grow: self.on_grow,
+            high_watermark: self.high_watermark,
+            low_watermark: self.low_watermark,
+            name: self.name,
         })
     }
+
+    /// Builds the configuration and checks that it can replace `old` on a live pool.
+    ///
+    /// This is meant for reloading configuration at runtime. Changes that a
+    /// pool can absorb, such as raising `max_capacity` or switching the growth
+    /// strategy, are accepted. Changes that would invalidate slots or handles
+    /// already handed out are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{GrowthStrategy, PoolConfig};
+    ///
+    /// let old = PoolConfig::<u64>::builder().capacity(100).build().unwrap();
+    ///
+    /// let reloaded = PoolConfig::builder()
+    ///     .capacity(100)
+    ///     .max_capacity(Some(1000))
+    ///     .growth_strategy(GrowthStrategy::Linear { amount: 50 })
+    ///     .build_compatible_with(&old);
+    /// assert!(reloaded.is_ok());
+    ///
+    /// let shrunk = PoolConfig::builder().capacity(50).build_compatible_with(&old);
+    /// assert!(shrunk.is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`build`](Self::build), or
+    /// `Error::InvalidConfiguration` if:
+    /// - Alignment differs from the old configuration
+    /// - Capacity is lower than the old capacity
+    /// - Contiguous storage is switched on or off
+    pub fn build_compatible_with(self, old: &PoolConfig<T>) -> Result<PoolConfig<T>> {
+        let config = self.build()?;
+
+        if config.alignment != old.alignment {
+            return Err(Error::invalid_config(
+                "alignment cannot change on a live pool",
+            ));
+        }
+
+        if config.capacity < old.capacity {
+            return Err(Error::invalid_config(
+                "capacity cannot be decreased on a live pool",
+            ));
+        }
+
+        if config.contiguous != old.contiguous {
+            return Err(Error::invalid_config(
+                "contiguous storage cannot change on a live pool",
+            ));
+        }
+
+        Ok(config)
+    }
+}
+
+impl<T: Default + 'static> PoolConfigBuilder<T> {
+    /// Sets whether released objects are reset to `T::default()` and kept for reuse.
+    ///
+    /// This is shorthand for [`reset_fn`](Self::reset_fn) with
+    /// `T::default` as both the initializer and the reset. A `FixedPool`
+    /// calls `on_release` first and then overwrites the object with a fresh
+    /// default, so the slot stays initialized and
+    /// `FixedPool::allocate_recycled` hands it out again. Passing `false`
+    /// leaves the current initialization strategy unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{FixedPool, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(4)
+    ///     .reset_to_default(true)
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = FixedPool::<Vec<u8>>::with_config(config).unwrap();
+    ///
+    /// drop(pool.allocate(vec![1, 2, 3]).unwrap());
+    /// assert!(pool.allocate_recycled().unwrap().is_empty());
+    /// ```
+    pub fn reset_to_default(self, reset_to_default: bool) -> Self {
+        if reset_to_default {
+            self.reset_fn(T::default, |value| *value = T::default())
+        } else {
+            self
+        }
+    }
 }
 
 impl<T> Default for PoolConfigBuilder<T> {
@@ -188,6 +681,15 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn builder_accepts_nonzero_capacity() {
+        let config = PoolConfig::<i32>::builder()
+            .capacity_nonzero(NonZeroUsize::new(8).unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(config.capacity(), 8);
+    }
+
     #[test]
     fn builder_validates_alignment() {
         let result = PoolConfig::<i32>::builder()
@@ -206,6 +708,22 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn builder_validates_watermarks() {
+        let out_of_range = PoolConfig::<i32>::builder()
+            .capacity(10)
+            .on_high_watermark(1.5, |_| {})
+            .build();
+        assert!(out_of_range.is_err());
+
+        let inverted = PoolConfig::<i32>::builder()
+            .capacity(10)
+            .on_high_watermark(0.2, |_| {})
+            .on_low_watermark(0.8, |_| {})
+            .build();
+        assert!(inverted.is_err());
+    }
+
     #[test]
     fn builder_creates_valid_config() {
         let config = PoolConfig::<i32>::builder()
@@ -232,4 +750,193 @@ mod tests {
 
         assert!(config.growth_strategy().allows_growth());
     }
+
+    #[test]
+    fn builder_with_zero_on_release() {
+        let config = PoolConfig::<i32>::builder().capacity(10).build().unwrap();
+        assert!(!config.zero_on_release());
+
+        let config = PoolConfig::<i32>::builder()
+            .capacity(10)
+            .zero_on_release(true)
+            .build()
+            .unwrap();
+        assert!(config.zero_on_release());
+    }
+
+    fn live_config() -> PoolConfig<u32> {
+        PoolConfig::builder()
+            .capacity(100)
+            .alignment(16)
+            .build()
+            .unwrap()
+    }
+
+    fn expect_message(result: Result<PoolConfig<u32>>, expected: &str) {
+        match result {
+            Err(Error::InvalidConfiguration { message }) => assert_eq!(message, expected),
+            other => panic!("expected InvalidConfiguration, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn compatible_allows_safe_changes() {
+        let old = live_config();
+        let config = PoolConfig::builder()
+            .capacity(200)
+            .alignment(16)
+            .max_capacity(Some(10_000))
+            .growth_strategy(GrowthStrategy::Linear { amount: 10 })
+            .build_compatible_with(&old)
+            .unwrap();
+
+        assert_eq!(config.capacity(), 200);
+        assert_eq!(config.max_capacity(), Some(10_000));
+    }
+
+    #[test]
+    fn compatible_rejects_alignment_change() {
+        let result = PoolConfig::builder()
+            .capacity(100)
+            .alignment(64)
+            .build_compatible_with(&live_config());
+        expect_message(result, "alignment cannot change on a live pool");
+    }
+
+    #[test]
+    fn compatible_rejects_capacity_decrease() {
+        let result = PoolConfig::builder()
+            .capacity(99)
+            .alignment(16)
+            .build_compatible_with(&live_config());
+        expect_message(result, "capacity cannot be decreased on a live pool");
+    }
+
+    #[test]
+    fn compatible_rejects_contiguous_change() {
+        let builder = PoolConfig::builder().capacity(100).alignment(16);
+        // Safety: the configuration is only validated, never used for a pool
+        let result = unsafe { builder.contiguous(true) }.build_compatible_with(&live_config());
+        expect_message(result, "contiguous storage cannot change on a live pool");
+    }
+
+    #[test]
+    fn compatible_propagates_build_errors() {
+        let result = PoolConfig::builder()
+            .alignment(16)
+            .build_compatible_with(&live_config());
+        expect_message(result, "capacity must be specified");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn apply_env_overrides_set_fields() {
+        // Each test uses its own prefix so parallel tests never share variables
+        std::env::set_var("FASTALLOC_ENV_OK_CAPACITY", "256");
+        std::env::set_var("FASTALLOC_ENV_OK_MAX_CAPACITY", "1024");
+        std::env::set_var("FASTALLOC_ENV_OK_GROWTH", "linear:100");
+
+        let config = PoolConfig::<u64>::builder()
+            .capacity(8)
+            .alignment(16)
+            .apply_env("FASTALLOC_ENV_OK")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(config.capacity(), 256);
+        assert_eq!(config.max_capacity(), Some(1024));
+        assert_eq!(config.alignment(), 16); // unset, left alone
+        assert!(matches!(
+            config.growth_strategy(),
+            GrowthStrategy::Linear { amount: 100 }
+        ));
+
+        std::env::set_var("FASTALLOC_ENV_OK_GROWTH", "exp:2.0");
+        std::env::set_var("FASTALLOC_ENV_OK_MAX_CAPACITY", "none");
+        let config = PoolConfig::<u64>::builder()
+            .apply_env("FASTALLOC_ENV_OK")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(config.max_capacity(), None);
+        assert!(matches!(
+            config.growth_strategy(),
+            GrowthStrategy::Exponential { factor } if *factor == 2.0
+        ));
+
+        for suffix in ["CAPACITY", "MAX_CAPACITY", "GROWTH"] {
+            std::env::remove_var(format!("FASTALLOC_ENV_OK_{}", suffix));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn apply_env_rejects_malformed_growth() {
+        std::env::set_var("FASTALLOC_ENV_BAD_GROWTH", "quadratic:3");
+
+        let err = PoolConfig::<u64>::builder()
+            .apply_env("FASTALLOC_ENV_BAD")
+            .err()
+            .unwrap();
+        std::env::remove_var("FASTALLOC_ENV_BAD_GROWTH");
+
+        assert!(matches!(err, Error::InvalidConfiguration { .. }));
+        assert!(err.to_string().contains("_GROWTH"));
+    }
+
+    #[test]
+    fn growth_fn_drives_pool_growth() {
+        let config = PoolConfig::<i32>::builder()
+            .capacity(4)
+            .growth_fn(|current| current + 7)
+            .build()
+            .unwrap();
+        assert!(matches!(
+            config.growth_strategy(),
+            GrowthStrategy::Custom { .. }
+        ));
+
+        let pool = crate::GrowingPool::with_config(config).unwrap();
+        let handles: alloc::vec::Vec<_> = (0..5).map(|i| pool.allocate(i).unwrap()).collect();
+        assert_eq!(pool.capacity(), 4 + (4 + 7));
+        assert_eq!(handles.len(), 5);
+    }
+
+    #[test]
+    fn reset_to_default_recycles_default_values() {
+        use crate::traits::Poolable;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static RELEASED_LEN: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Default)]
+        struct Scratch {
+            items: alloc::vec::Vec<u32>,
+        }
+
+        impl Poolable for Scratch {
+            fn on_release(&mut self) {
+                RELEASED_LEN.store(self.items.len(), Ordering::Relaxed);
+            }
+        }
+
+        let config = PoolConfig::builder()
+            .capacity(1)
+            .reset_to_default(true)
+            .build()
+            .unwrap();
+        let pool = crate::FixedPool::<Scratch>::with_config(config).unwrap();
+
+        drop(pool.allocate(Scratch {
+            items: alloc::vec![1, 2, 3],
+        }));
+        // `on_release` ran before the reset and saw the old contents
+        assert_eq!(RELEASED_LEN.load(Ordering::Relaxed), 3);
+
+        let recycled = pool.allocate_recycled().unwrap();
+        assert!(recycled.items.is_empty());
+        assert_eq!(recycled.items.capacity(), 0);
+        assert_eq!(recycled.index(), 0);
+    }
 }
@@ -1,6 +1,9 @@
 //! Growth strategies for dynamic memory pools.
 
+use crate::error::Error;
 use alloc::boxed::Box;
+use core::fmt;
+use core::str::FromStr;
 
 /// Strategy for growing a memory pool when it runs out of capacity.
 ///
@@ -62,6 +65,18 @@ impl GrowthStrategy {
         }
     }
 
+    /// Copies the strategy, or returns `None` for `Custom`, whose closure cannot be cloned.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        match self {
+            GrowthStrategy::None => Some(GrowthStrategy::None),
+            GrowthStrategy::Linear { amount } => Some(GrowthStrategy::Linear { amount: *amount }),
+            GrowthStrategy::Exponential { factor } => {
+                Some(GrowthStrategy::Exponential { factor: *factor })
+            }
+            GrowthStrategy::Custom { .. } => None,
+        }
+    }
+
     /// Returns whether this strategy allows growth.
     #[inline]
     pub fn allows_growth(&self) -> bool {
@@ -69,8 +84,8 @@ impl GrowthStrategy {
     }
 }
 
-impl core::fmt::Debug for GrowthStrategy {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl fmt::Debug for GrowthStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             GrowthStrategy::None => write!(f, "GrowthStrategy::None"),
             GrowthStrategy::Linear { amount } => f
@@ -88,6 +103,76 @@ impl core::fmt::Debug for GrowthStrategy {
     }
 }
 
+/// Formats the strategy in the form accepted by [`FromStr`]: `none`,
+/// `linear:<amount>` or `exp:<factor>`.
+///
+/// `Custom` strategies are written as `custom`, which does not parse back.
+impl fmt::Display for GrowthStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GrowthStrategy::None => f.write_str("none"),
+            GrowthStrategy::Linear { amount } => write!(f, "linear:{}", amount),
+            GrowthStrategy::Exponential { factor } => write!(f, "exp:{}", factor),
+            GrowthStrategy::Custom { .. } => f.write_str("custom"),
+        }
+    }
+}
+
+/// Parses `none`, `linear:<amount>` or `exp:<factor>`, ignoring ASCII case
+/// and whitespace around the amount or factor.
+///
+/// The amount must be a positive integer and the factor a finite number
+/// greater than 1. `Custom` strategies cannot be parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::GrowthStrategy;
+///
+/// let strategy: GrowthStrategy = "linear:128".parse().unwrap();
+/// assert_eq!(strategy.compute_growth(1000), 128);
+/// assert_eq!(strategy.to_string(), "linear:128");
+///
+/// assert!("exp:abc".parse::<GrowthStrategy>().is_err());
+/// ```
+impl FromStr for GrowthStrategy {
+    type Err = Error;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let spec = spec.trim();
+        if spec.eq_ignore_ascii_case("none") {
+            return Ok(GrowthStrategy::None);
+        }
+
+        let malformed = || {
+            Error::invalid_config(
+                "growth strategy must be `none`, `linear:<amount>` or `exp:<factor>`",
+            )
+        };
+        let (kind, arg) = spec.split_once(':').ok_or_else(malformed)?;
+
+        if kind.trim().eq_ignore_ascii_case("linear") {
+            let amount = arg.trim().parse().ok().filter(|&amount| amount > 0);
+            let amount = amount.ok_or_else(|| {
+                Error::invalid_config("linear growth amount must be a positive integer")
+            })?;
+            Ok(GrowthStrategy::Linear { amount })
+        } else if kind.trim().eq_ignore_ascii_case("exp") {
+            let factor = arg.trim().parse::<f64>().ok();
+            let factor = factor
+                .filter(|factor| factor.is_finite() && *factor > 1.0)
+                .ok_or_else(|| {
+                    Error::invalid_config(
+                        "exponential growth factor must be a finite number greater than 1",
+                    )
+                })?;
+            Ok(GrowthStrategy::Exponential { factor })
+        } else {
+            Err(malformed())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,4 +216,36 @@ mod tests {
         // Even with capacity 0, should grow by at least 1
         assert_eq!(strategy.compute_growth(0), 1);
     }
+
+    #[test]
+    fn growth_strategy_round_trips_through_display() {
+        use alloc::string::ToString;
+
+        for text in ["none", "linear:1", "linear:128", "exp:1.5", "exp:2"] {
+            let strategy: GrowthStrategy = text.parse().unwrap();
+            assert_eq!(strategy.to_string(), text);
+        }
+
+        let strategy: GrowthStrategy = " Linear: 64 ".parse().unwrap();
+        assert!(matches!(strategy, GrowthStrategy::Linear { amount: 64 }));
+        let strategy = GrowthStrategy::Exponential { factor: 2.0 };
+        let parsed: GrowthStrategy = strategy.to_string().parse().unwrap();
+        assert!(matches!(parsed, GrowthStrategy::Exponential { factor } if factor == 2.0));
+    }
+
+    #[test]
+    fn growth_strategy_parse_errors() {
+        use alloc::string::ToString;
+
+        let message = |text: &str| text.parse::<GrowthStrategy>().unwrap_err().to_string();
+
+        assert!(message("exp:abc").contains("exponential growth factor"));
+        assert!(message("exp:0.5").contains("exponential growth factor"));
+        assert!(message("exp:inf").contains("exponential growth factor"));
+        assert!(message("linear:-5").contains("linear growth amount"));
+        assert!(message("linear:0").contains("linear growth amount"));
+        assert!(message("quadratic:3").contains("must be `none`"));
+        assert!(message("linear").contains("must be `none`"));
+        assert!(message("custom").contains("must be `none`"));
+    }
 }
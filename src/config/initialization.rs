@@ -82,6 +82,14 @@ impl<T> InitializationStrategy<T> {
         matches!(self, InitializationStrategy::Eager { .. })
     }
 
+    /// Returns whether this strategy has a reset function.
+    ///
+    /// Pools with a reset function recycle released objects instead of dropping them.
+    #[inline]
+    pub fn has_reset(&self) -> bool {
+        matches!(self, InitializationStrategy::Custom { reset: Some(_), .. })
+    }
+
     /// Creates an initial value if an initializer is available.
     pub fn initialize(&self) -> Option<T> {
         match self {
@@ -103,6 +111,8 @@ impl<T> InitializationStrategy<T> {
     }
 }
 
+// Written by hand: `#[derive(Default)]` would require `T: Default`
+#[allow(clippy::derivable_impls)]
 impl<T> Default for InitializationStrategy<T> {
     fn default() -> Self {
         Self::Lazy
@@ -138,6 +148,14 @@ mod tests {
         assert!(strategy.initialize().is_none());
     }
 
+    #[test]
+    fn default_is_lazy_for_any_type() {
+        struct NoDefault;
+
+        let strategy = InitializationStrategy::<NoDefault>::default();
+        assert!(strategy.is_lazy());
+    }
+
     #[test]
     fn eager_strategy() {
         let strategy = InitializationStrategy::eager(|| 42);
@@ -150,6 +168,8 @@ mod tests {
     fn custom_strategy_with_reset() {
         let strategy = InitializationStrategy::custom(|| vec![1, 2, 3], |v| v.clear());
 
+        assert!(strategy.has_reset());
+
         let mut value = strategy.initialize().unwrap();
         assert_eq!(value, vec![1, 2, 3]);
 
@@ -161,6 +181,7 @@ mod tests {
     #[test]
     fn custom_strategy_without_reset() {
         let strategy = InitializationStrategy::custom_init_only(|| 100);
+        assert!(!strategy.has_reset());
 
         let value = strategy.initialize().unwrap();
         assert_eq!(value, 100);
@@ -3,10 +3,20 @@
 mod builder;
 mod growth_strategy;
 mod initialization;
+mod observer;
+mod shrink_policy;
+mod strict;
+mod watermark;
 
 pub use builder::PoolConfigBuilder;
 pub use growth_strategy::GrowthStrategy;
 pub use initialization::InitializationStrategy;
+pub use observer::PoolEvent;
+pub use shrink_policy::ShrinkPolicy;
+pub use strict::{CapacitySet, NoCapacity, StrictPoolConfigBuilder};
+
+pub(crate) use observer::{GrowHook, Observer};
+pub(crate) use watermark::{Watermark, WatermarkState};
 
 use core::mem;
 
@@ -50,6 +60,39 @@ pub struct PoolConfig<T> {
 
     /// Whether this is a thread-local pool
     pub(crate) thread_local: bool,
+
+    /// Whether slot memory is zeroed when an object is released
+    pub(crate) zero_on_release: bool,
+
+    /// Whether a growing pool reallocates one contiguous storage region
+    pub(crate) contiguous: bool,
+
+    /// Whether the reset function also runs when an object is acquired
+    pub(crate) reset_on_acquire: bool,
+
+    /// Whether growth is rounded up to whole 64-slot bitmap words
+    pub(crate) align_growth_to_words: bool,
+
+    /// Whether the lowest free index is always allocated first
+    pub(crate) deterministic: bool,
+
+    /// When a growing pool frees trailing empty chunks, if ever
+    pub(crate) shrink_policy: Option<ShrinkPolicy>,
+
+    /// Callback invoked on allocation, release and growth
+    pub(crate) observer: Option<Observer>,
+
+    /// Callback invoked after each growth with the old and new capacity
+    pub(crate) on_grow: Option<GrowHook>,
+
+    /// Callback invoked when utilization rises above a ratio
+    pub(crate) high_watermark: Option<Watermark>,
+
+    /// Callback invoked when utilization falls back below a ratio
+    pub(crate) low_watermark: Option<Watermark>,
+
+    /// Name shown in error messages and statistics reports
+    pub(crate) name: Option<&'static str>,
 }
 
 impl<T> PoolConfig<T> {
@@ -69,6 +112,45 @@ impl<T> PoolConfig<T> {
         PoolConfigBuilder::new()
     }
 
+    /// Creates a builder that rejects a missing capacity at compile time.
+    ///
+    /// See [`StrictPoolConfigBuilder`].
+    pub fn strict_builder() -> StrictPoolConfigBuilder<T> {
+        StrictPoolConfigBuilder::new()
+    }
+
+    /// Returns a builder preloaded with this configuration's settings.
+    ///
+    /// Configurations cannot be cloned because they may own closures. This
+    /// copies every plain setting into a fresh builder so the same settings
+    /// can be used to build further pools. Closures are not carried over: a
+    /// `Custom` growth strategy becomes `GrowthStrategy::None`, the
+    /// initialization strategy becomes `Lazy`, and observer, `on_grow` and
+    /// watermark callbacks are dropped; set them again on the returned builder.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{FixedPool, PoolConfig};
+    ///
+    /// let config = PoolConfig::<i32>::builder()
+    ///     .capacity(64)
+    ///     .alignment(16)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let copy = config.clone_settings().build().unwrap();
+    /// assert_eq!(copy.capacity(), 64);
+    /// assert_eq!(copy.alignment(), 16);
+    ///
+    /// let first = FixedPool::with_config(config).unwrap();
+    /// let second = FixedPool::with_config(copy).unwrap();
+    /// # drop((first, second));
+    /// ```
+    pub fn clone_settings(&self) -> PoolConfigBuilder<T> {
+        PoolConfigBuilder::from_settings(self)
+    }
+
     /// Returns the initial capacity.
     #[inline]
     pub fn capacity(&self) -> usize {
@@ -87,6 +169,12 @@ impl<T> PoolConfig<T> {
         &self.growth_strategy
     }
 
+    /// Returns the pool's name, if one was set.
+    #[inline]
+    pub fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
     /// Returns the alignment requirement.
     #[inline]
     pub fn alignment(&self) -> usize {
@@ -104,6 +192,121 @@ impl<T> PoolConfig<T> {
     pub fn thread_local(&self) -> bool {
         self.thread_local
     }
+
+    /// Returns whether slot memory is zeroed when an object is released.
+    #[inline]
+    pub fn zero_on_release(&self) -> bool {
+        self.zero_on_release
+    }
+
+    /// Returns whether a growing pool keeps its storage contiguous.
+    #[inline]
+    pub fn contiguous(&self) -> bool {
+        self.contiguous
+    }
+
+    /// Returns the policy for freeing unused chunks of a growing pool, if any.
+    #[inline]
+    pub fn shrink_policy(&self) -> Option<ShrinkPolicy> {
+        self.shrink_policy
+    }
+
+    /// Returns whether the reset function also runs when an object is acquired.
+    #[inline]
+    pub fn reset_on_acquire(&self) -> bool {
+        self.reset_on_acquire
+    }
+
+    /// Returns whether growth is rounded up to whole bitmap words.
+    #[inline]
+    pub fn align_growth_to_words(&self) -> bool {
+        self.align_growth_to_words
+    }
+
+    /// Returns whether the lowest free index is always allocated first.
+    #[inline]
+    pub fn deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Returns whether an observer callback is registered.
+    #[inline]
+    pub fn has_observer(&self) -> bool {
+        self.observer.is_some()
+    }
+
+    /// Reports an event to the observer, if any.
+    ///
+    /// Pools must not hold any `RefCell` borrow while calling this, so the
+    /// observer may freely query the pool.
+    #[inline]
+    pub(crate) fn notify(&self, event: PoolEvent) {
+        if let Some(observer) = &self.observer {
+            observer(event);
+        }
+    }
+
+    /// Returns whether a high or low watermark callback is registered.
+    #[inline]
+    pub(crate) fn has_watermarks(&self) -> bool {
+        self.high_watermark.is_some() || self.low_watermark.is_some()
+    }
+
+    /// Reports a completed growth to the `on_grow` callback, if any.
+    ///
+    /// Like [`notify`](Self::notify), this must be called with no `RefCell`
+    /// borrow held.
+    #[inline]
+    pub(crate) fn notify_grow(&self, old_capacity: usize, new_capacity: usize) {
+        if let Some(on_grow) = &self.on_grow {
+            on_grow(old_capacity, new_capacity);
+        }
+    }
+}
+
+impl<T: crate::traits::Poolable> PoolConfig<T> {
+    /// Prepares an object that is about to be handed out.
+    ///
+    /// Runs the reset function first when `reset_on_acquire` is set, then
+    /// the object's `on_acquire` hook.
+    #[inline]
+    pub(crate) fn acquire(&self, value: &mut T) {
+        if self.reset_on_acquire {
+            self.initialization_strategy.reset(value);
+        }
+        value.on_acquire();
+    }
+}
+
+impl<T> core::fmt::Debug for PoolConfig<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PoolConfig")
+            .field("capacity", &self.capacity)
+            .field("max_capacity", &self.max_capacity)
+            .field("growth_strategy", &self.growth_strategy)
+            .field("alignment", &self.alignment)
+            .field("pre_initialize", &self.pre_initialize)
+            .field("initialization_strategy", &self.initialization_strategy)
+            .field("thread_local", &self.thread_local)
+            .field("zero_on_release", &self.zero_on_release)
+            .field("contiguous", &self.contiguous)
+            .field("reset_on_acquire", &self.reset_on_acquire)
+            .field("align_growth_to_words", &self.align_growth_to_words)
+            .field("deterministic", &self.deterministic)
+            .field("shrink_policy", &self.shrink_policy)
+            .field("has_observer", &self.observer.is_some())
+            .field("has_on_grow", &self.on_grow.is_some())
+            .field(
+                "high_watermark",
+                &self.high_watermark.as_ref().map(|w| w.ratio),
+            )
+            .field(
+                "low_watermark",
+                &self.low_watermark.as_ref().map(|w| w.ratio),
+            )
+            .field("name", &self.name)
+            .finish()
+    }
 }
 
 impl<T> Default for PoolConfig<T> {
@@ -116,6 +319,17 @@ impl<T> Default for PoolConfig<T> {
             pre_initialize: false,
             initialization_strategy: InitializationStrategy::Lazy,
             thread_local: false,
+            zero_on_release: false,
+            contiguous: false,
+            reset_on_acquire: false,
+            align_growth_to_words: false,
+            deterministic: false,
+            shrink_policy: None,
+            observer: None,
+            on_grow: None,
+            high_watermark: None,
+            low_watermark: None,
+            name: None,
         }
     }
 }
@@ -132,6 +346,7 @@ mod tests {
         assert_eq!(config.alignment(), mem::align_of::<i32>());
         assert!(!config.pre_initialize());
         assert!(!config.thread_local());
+        assert!(!config.zero_on_release());
     }
 
     #[test]
@@ -140,4 +355,59 @@ mod tests {
 
         assert_eq!(config.capacity(), 500);
     }
+
+    #[test]
+    fn debug_lists_settings() {
+        use alloc::format;
+
+        let config = PoolConfig::<i32>::builder()
+            .capacity(32)
+            .max_capacity(Some(128))
+            .growth_strategy(GrowthStrategy::Linear { amount: 16 })
+            .alignment(64)
+            .build()
+            .unwrap();
+
+        let debug = format!("{:?}", config);
+        assert!(debug.contains("capacity: 32"));
+        assert!(debug.contains("max_capacity: Some(128)"));
+        assert!(debug.contains("GrowthStrategy::Linear"));
+        assert!(debug.contains("alignment: 64"));
+        assert!(debug.contains("InitializationStrategy::Lazy"));
+        assert!(debug.contains("thread_local: false"));
+    }
+
+    #[test]
+    fn clone_settings_rebuilds_config() {
+        let config = PoolConfig::<i32>::builder()
+            .capacity(32)
+            .max_capacity(Some(128))
+            .growth_strategy(GrowthStrategy::Exponential { factor: 1.5 })
+            .alignment(64)
+            .zero_on_release(true)
+            .reset_fn(|| 0, |v| *v = 0)
+            .build()
+            .unwrap();
+
+        let copy = config.clone_settings().build().unwrap();
+        assert_eq!(copy.capacity(), 32);
+        assert_eq!(copy.max_capacity(), Some(128));
+        assert!(matches!(
+            copy.growth_strategy(),
+            GrowthStrategy::Exponential { factor } if *factor == 1.5
+        ));
+        assert_eq!(copy.alignment(), 64);
+        assert!(copy.zero_on_release());
+        assert!(copy.initialization_strategy.is_lazy());
+
+        let custom = PoolConfig::<i32>::builder()
+            .capacity(4)
+            .growth_strategy(GrowthStrategy::Custom {
+                compute: alloc::boxed::Box::new(|c| c),
+            })
+            .build()
+            .unwrap();
+        let copy = custom.clone_settings().build().unwrap();
+        assert!(!copy.growth_strategy().allows_growth());
+    }
 }
@@ -0,0 +1,84 @@
+//! Pool lifecycle events delivered to user observers.
+
+use alloc::boxed::Box;
+use core::panic::Location;
+
+/// An event reported to a pool observer.
+///
+/// Register an observer with
+/// [`PoolConfigBuilder::observer`](crate::config::PoolConfigBuilder::observer)
+/// to trace allocations or detect leaks. `usage` is the number of allocated
+/// slots right after the event.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::{FixedPool, PoolConfig, PoolEvent};
+/// use std::sync::{Arc, Mutex};
+///
+/// let events = Arc::new(Mutex::new(Vec::new()));
+/// let sink = Arc::clone(&events);
+///
+/// let config = PoolConfig::builder()
+///     .capacity(4)
+///     .observer(move |event| sink.lock().unwrap().push(event))
+///     .build()
+///     .unwrap();
+/// let pool = FixedPool::with_config(config).unwrap();
+///
+/// drop(pool.allocate(1).unwrap());
+///
+/// assert_eq!(
+///     *events.lock().unwrap(),
+///     [
+///         PoolEvent::Allocated { index: 0, usage: 1 },
+///         PoolEvent::Freed { index: 0, usage: 0 },
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PoolEvent {
+    /// A slot was handed out.
+    Allocated {
+        /// Index of the allocated slot
+        index: usize,
+        /// Number of allocated slots after the allocation
+        usage: usize,
+    },
+
+    /// A slot was returned to the pool.
+    Freed {
+        /// Index of the freed slot
+        index: usize,
+        /// Number of allocated slots after the release
+        usage: usize,
+    },
+
+    /// The pool grew.
+    Grew {
+        /// Capacity after growth
+        new_capacity: usize,
+    },
+
+    /// The pool freed unused chunks under its shrink policy.
+    Shrunk {
+        /// Capacity after shrinking
+        new_capacity: usize,
+    },
+
+    /// The pool was dropped while a slot was still allocated, because its
+    /// handle was leaked.
+    Leaked {
+        /// Index of the leaked slot
+        index: usize,
+        /// Where the slot was allocated; only recorded in debug builds
+        location: Option<&'static Location<'static>>,
+    },
+}
+
+/// Boxed observer callback stored in a pool configuration.
+pub(crate) type Observer = Box<dyn Fn(PoolEvent) + Send + Sync>;
+
+/// Boxed growth callback receiving `(old_capacity, new_capacity)`.
+pub(crate) type GrowHook = Box<dyn Fn(usize, usize) + Send + Sync>;
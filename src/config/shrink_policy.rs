@@ -0,0 +1,39 @@
+//! Policy for returning unused growth to the system allocator.
+
+/// When a [`GrowingPool`](crate::GrowingPool) gives back chunks it grew but no
+/// longer needs.
+///
+/// Shrinking as soon as a chunk empties makes a pool whose load hovers
+/// around a chunk boundary grow and shrink over and over. With this policy
+/// the pool waits until more than `min_free_ratio` of its capacity has
+/// been free for `idle_threshold` consecutive releases before it frees its
+/// trailing empty chunks, so only sustained low occupancy shrinks it. The
+/// initial chunk is never freed.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::{GrowingPool, GrowthStrategy, PoolConfig, ShrinkPolicy};
+///
+/// let config = PoolConfig::builder()
+///     .capacity(16)
+///     .growth_strategy(GrowthStrategy::Exponential { factor: 2.0 })
+///     .auto_shrink(ShrinkPolicy {
+///         idle_threshold: 64,
+///         min_free_ratio: 0.75,
+///     })
+///     .build()
+///     .unwrap();
+/// let pool = GrowingPool::<u64>::with_config(config).unwrap();
+/// # drop(pool);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShrinkPolicy {
+    /// Number of consecutive releases that must leave the pool above
+    /// `min_free_ratio` before it shrinks
+    pub idle_threshold: usize,
+
+    /// Fraction of capacity, between 0.0 and 1.0, that must be free for a
+    /// release to count towards `idle_threshold`
+    pub min_free_ratio: f64,
+}
@@ -0,0 +1,198 @@
+//! Builder that requires a capacity at compile time.
+
+use super::{
+    GrowthStrategy, InitializationStrategy, PoolConfig, PoolConfigBuilder, PoolEvent, ShrinkPolicy,
+};
+use crate::error::Result;
+use core::marker::PhantomData;
+use core::num::NonZeroUsize;
+
+/// Builder state before a capacity has been set.
+#[derive(Debug)]
+pub enum NoCapacity {}
+
+/// Builder state once a capacity has been set.
+#[derive(Debug)]
+pub enum CapacitySet {}
+
+/// Sets a plain option on the wrapped builder, keeping the current state.
+macro_rules! forward_setters {
+    ($($(#[$attr:meta])* fn $name:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            #[doc = concat!("See [`PoolConfigBuilder::", stringify!($name), "`].")]
+            $(#[$attr])*
+            pub fn $name(self, $($arg: $ty),*) -> Self {
+                Self {
+                    inner: self.inner.$name($($arg),*),
+                    state: PhantomData,
+                }
+            }
+        )*
+    };
+}
+
+/// A [`PoolConfigBuilder`] that only offers `build` once a capacity is set.
+///
+/// The regular builder reports a missing capacity when `build` runs. This
+/// builder tracks it in its type instead: [`capacity`](Self::capacity)
+/// moves it from the [`NoCapacity`] to the [`CapacitySet`] state, and
+/// `build` only exists in the latter, so forgetting the capacity is a
+/// compile error. Every other setting, and the remaining checks in
+/// `build`, are the same as for [`PoolConfigBuilder`].
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::PoolConfig;
+///
+/// let config = PoolConfig::<i32>::strict_builder()
+///     .max_capacity(Some(512))
+///     .capacity(64)
+///     .build()
+///     .unwrap();
+/// assert_eq!(config.capacity(), 64);
+/// ```
+///
+/// Building without a capacity does not compile:
+///
+/// ```compile_fail
+/// use fastalloc::PoolConfig;
+///
+/// let config = PoolConfig::<i32>::strict_builder().build();
+/// ```
+pub struct StrictPoolConfigBuilder<T, S = NoCapacity> {
+    inner: PoolConfigBuilder<T>,
+    state: PhantomData<S>,
+}
+
+impl<T> StrictPoolConfigBuilder<T, NoCapacity> {
+    /// Creates a new builder with default values and no capacity.
+    pub fn new() -> Self {
+        Self {
+            inner: PoolConfigBuilder::new(),
+            state: PhantomData,
+        }
+    }
+
+    /// Sets the initial capacity of the pool.
+    ///
+    /// Must be at least 1; a zero capacity is still rejected by `build`.
+    pub fn capacity(self, capacity: usize) -> StrictPoolConfigBuilder<T, CapacitySet> {
+        StrictPoolConfigBuilder {
+            inner: self.inner.capacity(capacity),
+            state: PhantomData,
+        }
+    }
+
+    /// Sets the initial capacity of the pool from a value that cannot be zero.
+    pub fn capacity_nonzero(
+        self,
+        capacity: NonZeroUsize,
+    ) -> StrictPoolConfigBuilder<T, CapacitySet> {
+        self.capacity(capacity.get())
+    }
+}
+
+impl<T, S> StrictPoolConfigBuilder<T, S> {
+    forward_setters! {
+        fn name(name: &'static str);
+        fn max_capacity(max_capacity: Option<usize>);
+        fn growth_strategy(strategy: GrowthStrategy);
+        fn growth_fn(compute: impl Fn(usize) -> usize + Send + Sync + 'static);
+        fn alignment(alignment: usize);
+        fn pre_initialize(pre_initialize: bool);
+        fn initializer(initializer: impl Fn() -> T + Send + Sync + 'static);
+        fn reset_fn(
+            initializer: impl Fn() -> T + Send + Sync + 'static,
+            reset: impl Fn(&mut T) + Send + Sync + 'static
+        );
+        fn initialization_strategy(strategy: InitializationStrategy<T>);
+        #[cfg(feature = "std")]
+        fn thread_local(thread_local: bool);
+        fn zero_on_release(zero_on_release: bool);
+        fn reset_on_acquire(reset_on_acquire: bool);
+        fn align_growth_to_words(align: bool);
+        fn deterministic(deterministic: bool);
+        fn auto_shrink(policy: ShrinkPolicy);
+        fn observer(observer: impl Fn(PoolEvent) + Send + Sync + 'static);
+        fn on_grow(on_grow: impl Fn(usize, usize) + Send + Sync + 'static);
+        fn on_high_watermark(ratio: f64, callback: impl Fn(usize) + Send + Sync + 'static);
+        fn on_low_watermark(ratio: f64, callback: impl Fn(usize) + Send + Sync + 'static);
+    }
+
+    /// See [`PoolConfigBuilder::contiguous`].
+    ///
+    /// # Safety
+    ///
+    /// The same requirements apply as for [`PoolConfigBuilder::contiguous`].
+    pub unsafe fn contiguous(self, contiguous: bool) -> Self {
+        Self {
+            inner: self.inner.contiguous(contiguous),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<T: Default + 'static, S> StrictPoolConfigBuilder<T, S> {
+    forward_setters! {
+        fn reset_to_default(reset_to_default: bool);
+    }
+}
+
+impl<T> StrictPoolConfigBuilder<T, CapacitySet> {
+    /// Builds the configuration, validating all settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error [`PoolConfigBuilder::build`] would return for the
+    /// same settings.
+    pub fn build(self) -> Result<PoolConfig<T>> {
+        self.inner.build()
+    }
+}
+
+impl<T> Default for StrictPoolConfigBuilder<T, NoCapacity> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn strict_builder_carries_settings() {
+        let config = PoolConfig::<u32>::strict_builder()
+            .name("strict")
+            .growth_strategy(GrowthStrategy::Linear { amount: 8 })
+            .capacity(16)
+            .max_capacity(Some(64))
+            .build()
+            .unwrap();
+
+        assert_eq!(config.capacity(), 16);
+        assert_eq!(config.max_capacity(), Some(64));
+        assert_eq!(config.name(), Some("strict"));
+        assert!(matches!(
+            config.growth_strategy(),
+            GrowthStrategy::Linear { amount: 8 }
+        ));
+    }
+
+    #[test]
+    fn strict_builder_still_validates_values() {
+        let result = PoolConfig::<u32>::strict_builder().capacity(0).build();
+        assert!(matches!(result, Err(Error::InvalidConfiguration { .. })));
+
+        let result = PoolConfig::<u32>::strict_builder()
+            .capacity(4)
+            .alignment(3)
+            .build();
+        assert!(matches!(
+            result,
+            Err(Error::InvalidAlignment { alignment: 3 })
+        ));
+    }
+}
@@ -0,0 +1,60 @@
+//! Utilization thresholds that notify when a pool fills up or drains.
+
+use super::PoolConfig;
+use alloc::boxed::Box;
+use core::cell::Cell;
+
+/// Boxed watermark callback receiving the number of allocated slots.
+pub(crate) type WatermarkHook = Box<dyn Fn(usize) + Send + Sync>;
+
+/// A utilization ratio and the callback to run when it is crossed.
+pub(crate) struct Watermark {
+    pub(crate) ratio: f64,
+    pub(crate) callback: WatermarkHook,
+}
+
+/// Which side of the watermarks a pool was last seen on.
+///
+/// A pool starts out low. It turns high, firing the high watermark, once
+/// utilization rises above the high ratio, and turns low again, firing the
+/// low watermark, once utilization falls below the low ratio. Between the
+/// two ratios nothing changes, so a pool hovering around one threshold
+/// does not fire repeatedly. A missing watermark borrows the other one's
+/// ratio.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct WatermarkState {
+    high: Cell<bool>,
+}
+
+impl WatermarkState {
+    /// Creates the state of an empty pool.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fires a watermark if `usage` out of `capacity` slots crosses one.
+    ///
+    /// Like [`PoolConfig::notify`], this must be called with no `RefCell`
+    /// borrow held, so the callbacks may query the pool.
+    pub(crate) fn update<T>(&self, config: &PoolConfig<T>, usage: usize, capacity: usize) {
+        let (high, low) = match (&config.high_watermark, &config.low_watermark) {
+            (None, None) => return,
+            (Some(high), None) => (high, high),
+            (None, Some(low)) => (low, low),
+            (Some(high), Some(low)) => (high, low),
+        };
+        let utilization = usage as f64 / capacity as f64;
+
+        if !self.high.get() && utilization > high.ratio {
+            self.high.set(true);
+            if let Some(watermark) = &config.high_watermark {
+                (watermark.callback)(usage);
+            }
+        } else if self.high.get() && utilization < low.ratio {
+            self.high.set(false);
+            if let Some(watermark) = &config.low_watermark {
+                (watermark.callback)(usage);
+            }
+        }
+    }
+}
@@ -1,20 +1,40 @@
 //! Error types for the fastalloc crate.
 
+use alloc::string::String;
 use core::fmt;
 
 /// Result type alias using the fastalloc error type.
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// How a failed operation may be retried, as reported by [`Error::retry_hint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RetryHint {
+    /// The pool could still grow, so retrying right away may succeed.
+    Immediately,
+    /// The pool cannot grow any further; retrying only helps once other
+    /// handles have been dropped.
+    AfterRelease,
+    /// Retrying will not help.
+    Never,
+}
+
 /// Errors that can occur when working with memory pools.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum Error {
     /// The pool has reached its capacity and cannot allocate more objects.
+    #[non_exhaustive]
     PoolExhausted {
         /// Current capacity of the pool
         capacity: usize,
         /// Number of objects currently allocated
         allocated: usize,
+        /// Whether the pool could still grow (has a growth strategy and headroom
+        /// under its maximum capacity)
+        can_grow: bool,
+        /// Name of the pool, if one was configured
+        name: Option<&'static str>,
     },
 
     /// Invalid configuration was provided when building a pool.
@@ -42,6 +62,14 @@ pub enum Error {
         max: usize,
     },
 
+    /// Growing the pool would overflow `usize`.
+    CapacityOverflow {
+        /// Current capacity
+        current: usize,
+        /// Requested additional slots
+        additional: usize,
+    },
+
     /// A handle reference was invalid or expired.
     InvalidHandle,
 
@@ -49,13 +77,26 @@ pub enum Error {
     DoubleFree,
 
     /// Memory allocation from the system allocator failed.
-    AllocationFailed,
+    #[non_exhaustive]
+    AllocationFailed {
+        /// Number of bytes that were requested
+        bytes: usize,
+    },
 
     /// Custom error with a message (for extensibility).
     Custom {
         /// Error message
         message: &'static str,
     },
+
+    /// Custom error with a message built at runtime.
+    ///
+    /// Use this over `Custom` when the message needs runtime detail, such as
+    /// the offending value.
+    CustomOwned {
+        /// Error message
+        message: String,
+    },
 }
 
 impl fmt::Display for Error {
@@ -64,10 +105,16 @@ impl fmt::Display for Error {
             Error::PoolExhausted {
                 capacity,
                 allocated,
+                name,
+                ..
             } => {
+                match name {
+                    Some(name) => write!(f, "Pool '{}' exhausted", name)?,
+                    None => write!(f, "Pool exhausted")?,
+                }
                 write!(
                     f,
-                    "Pool exhausted: allocated {}/{} objects. Consider using a growing pool or increasing capacity.",
+                    ": allocated {}/{} objects. Consider using a growing pool or increasing capacity.",
                     allocated, capacity
                 )
             }
@@ -95,18 +142,31 @@ impl fmt::Display for Error {
                     current, requested, max
                 )
             }
+            Error::CapacityOverflow {
+                current,
+                additional,
+            } => {
+                write!(
+                    f,
+                    "Capacity overflow: cannot grow from {} by {} slots",
+                    current, additional
+                )
+            }
             Error::InvalidHandle => {
                 write!(f, "Invalid or expired handle")
             }
             Error::DoubleFree => {
                 write!(f, "Attempted to free an already freed object (double-free)")
             }
-            Error::AllocationFailed => {
-                write!(f, "System memory allocation failed")
+            Error::AllocationFailed { bytes } => {
+                write!(f, "System memory allocation of {} bytes failed", bytes)
             }
             Error::Custom { message } => {
                 write!(f, "Error: {}", message)
             }
+            Error::CustomOwned { message } => {
+                write!(f, "Error: {}", message)
+            }
         }
     }
 }
@@ -114,6 +174,43 @@ impl fmt::Display for Error {
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
+/// Converts pool errors for use with `?` in functions returning `io::Result`.
+///
+/// `PoolExhausted` maps to `WouldBlock`, since the allocation can succeed
+/// once slots are returned, `AllocationFailed` maps to `OutOfMemory`, and
+/// everything else to `Other`. The original error is kept as the inner
+/// error, so the message is preserved and it can be recovered with
+/// `io::Error::into_inner`.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::FixedPool;
+/// use std::io;
+///
+/// fn fill(pool: &FixedPool<u8>) -> io::Result<()> {
+///     let _a = pool.allocate(1)?;
+///     let _b = pool.allocate(2)?;
+///     Ok(())
+/// }
+///
+/// let pool = FixedPool::new(1).unwrap();
+/// assert_eq!(fill(&pool).unwrap_err().kind(), io::ErrorKind::WouldBlock);
+/// ```
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> Self {
+        use std::io::ErrorKind;
+
+        let kind = match err {
+            Error::PoolExhausted { .. } => ErrorKind::WouldBlock,
+            Error::AllocationFailed { .. } => ErrorKind::OutOfMemory,
+            _ => ErrorKind::Other,
+        };
+        std::io::Error::new(kind, err)
+    }
+}
+
 impl Error {
     /// Creates a new invalid configuration error.
     #[inline]
@@ -126,6 +223,83 @@ impl Error {
     pub fn custom(message: &'static str) -> Self {
         Error::Custom { message }
     }
+
+    /// Creates a new custom error from a message built at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::Error;
+    ///
+    /// let value = "lots";
+    /// let err = Error::custom_owned(format!("invalid POOL_SIZE: {:?}", value));
+    /// assert_eq!(err.to_string(), "Error: invalid POOL_SIZE: \"lots\"");
+    /// ```
+    #[inline]
+    pub fn custom_owned(message: impl Into<String>) -> Self {
+        Error::CustomOwned {
+            message: message.into(),
+        }
+    }
+
+    /// Returns how the failed operation may be retried.
+    ///
+    /// `Error::PoolExhausted` from a pool that `can_grow` is worth retrying
+    /// right away. A fixed pool, or a growing pool at its `max_capacity`,
+    /// only has room again once other handles are dropped, so a retry loop
+    /// should back off until then. Configuration and allocation-failure
+    /// errors are permanent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{GrowingPool, GrowthStrategy, PoolConfig, RetryHint};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(1)
+    ///     .growth_strategy(GrowthStrategy::Linear { amount: 1 })
+    ///     .max_capacity(Some(2))
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = GrowingPool::with_config(config).unwrap();
+    /// let _held = (pool.allocate(1).unwrap(), pool.allocate(2).unwrap());
+    ///
+    /// let err = pool.allocate(3).unwrap_err();
+    /// assert_eq!(err.retry_hint(), RetryHint::AfterRelease);
+    /// ```
+    pub fn retry_hint(&self) -> RetryHint {
+        match self {
+            Error::PoolExhausted { can_grow: true, .. } => RetryHint::Immediately,
+            Error::PoolExhausted { .. } | Error::MaxCapacityExceeded { .. } => {
+                RetryHint::AfterRelease
+            }
+            _ => RetryHint::Never,
+        }
+    }
+
+    /// Returns whether retrying the operation may succeed later.
+    ///
+    /// Exhaustion errors are transient: once other handles are dropped and
+    /// their slots return to the pool, the same allocation can succeed.
+    /// Configuration and allocation-failure errors are permanent. Use
+    /// [`retry_hint`](Self::retry_hint) to also tell whether the retry has
+    /// to wait for a release.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(1).unwrap();
+    /// let _held = pool.allocate(1).unwrap();
+    ///
+    /// let err = pool.allocate(2).unwrap_err();
+    /// assert!(err.is_transient());
+    /// ```
+    #[inline]
+    pub fn is_transient(&self) -> bool {
+        self.retry_hint() != RetryHint::Never
+    }
 }
 
 #[cfg(test)]
@@ -138,9 +312,21 @@ mod tests {
         let err = Error::PoolExhausted {
             capacity: 100,
             allocated: 100,
+            can_grow: false,
+            name: None,
         };
         assert!(err.to_string().contains("exhausted"));
 
+        let err = Error::PoolExhausted {
+            capacity: 8,
+            allocated: 8,
+            can_grow: false,
+            name: Some("connection_pool"),
+        };
+        assert!(err
+            .to_string()
+            .starts_with("Pool 'connection_pool' exhausted: allocated 8/8 objects."));
+
         let err = Error::InvalidConfiguration {
             message: "capacity must be positive",
         };
@@ -158,4 +344,106 @@ mod tests {
         let err = Error::custom("custom message");
         assert!(matches!(err, Error::Custom { .. }));
     }
+
+    #[test]
+    fn owned_custom_error_includes_runtime_detail() {
+        let capacity = 4096;
+        let err = Error::custom_owned(alloc::format!("capacity {} is too large", capacity));
+
+        assert!(matches!(err, Error::CustomOwned { .. }));
+        assert_eq!(err.to_string(), "Error: capacity 4096 is too large");
+        assert!(!err.is_transient());
+        assert_eq!(err.clone(), err);
+    }
+
+    #[test]
+    fn error_is_transient() {
+        let exhausted = Error::PoolExhausted {
+            capacity: 10,
+            allocated: 10,
+            can_grow: false,
+            name: None,
+        };
+        assert!(exhausted.is_transient());
+        assert_eq!(exhausted.retry_hint(), RetryHint::AfterRelease);
+
+        let growable = Error::PoolExhausted {
+            capacity: 10,
+            allocated: 10,
+            can_grow: true,
+            name: None,
+        };
+        assert_eq!(growable.retry_hint(), RetryHint::Immediately);
+
+        let capped = Error::MaxCapacityExceeded {
+            current: 10,
+            requested: 20,
+            max: 10,
+        };
+        assert!(capped.is_transient());
+
+        assert!(!Error::invalid_config("bad").is_transient());
+        assert_eq!(Error::invalid_config("bad").retry_hint(), RetryHint::Never);
+        assert!(!Error::InvalidAlignment { alignment: 3 }.is_transient());
+        assert!(!Error::AllocationFailed { bytes: 64 }.is_transient());
+        assert!(!Error::CapacityOverflow {
+            current: usize::MAX,
+            additional: 1,
+        }
+        .is_transient());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn converts_to_io_error() {
+        use std::io::ErrorKind;
+
+        let cases = [
+            (
+                Error::PoolExhausted {
+                    capacity: 4,
+                    allocated: 4,
+                    can_grow: false,
+                    name: None,
+                },
+                ErrorKind::WouldBlock,
+            ),
+            (
+                Error::AllocationFailed { bytes: 64 },
+                ErrorKind::OutOfMemory,
+            ),
+            (Error::invalid_config("bad"), ErrorKind::Other),
+            (Error::UninitializedPool, ErrorKind::Other),
+            (Error::InvalidAlignment { alignment: 3 }, ErrorKind::Other),
+            (
+                Error::MaxCapacityExceeded {
+                    current: 1,
+                    requested: 2,
+                    max: 1,
+                },
+                ErrorKind::Other,
+            ),
+            (
+                Error::CapacityOverflow {
+                    current: usize::MAX,
+                    additional: 1,
+                },
+                ErrorKind::Other,
+            ),
+            (Error::InvalidHandle, ErrorKind::Other),
+            (Error::DoubleFree, ErrorKind::Other),
+            (Error::custom("custom"), ErrorKind::Other),
+            (Error::custom_owned("custom"), ErrorKind::Other),
+        ];
+
+        for (err, kind) in cases {
+            let message = err.to_string();
+            let io_err = std::io::Error::from(err.clone());
+            assert_eq!(io_err.kind(), kind, "{:?}", err);
+            assert_eq!(io_err.to_string(), message);
+
+            let inner = io_err.into_inner().unwrap().downcast::<Error>().unwrap();
+            assert_eq!(*inner, err);
+        }
+    }
 }
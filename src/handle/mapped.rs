@@ -0,0 +1,193 @@
+//! Handle projecting a pool-allocated object onto one of its parts.
+
+use super::OwnedHandle;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+/// A handle that dereferences to a part of a pool-allocated object.
+///
+/// Created by [`OwnedHandle::map`]. The mapped handle keeps the original
+/// handle alive, so the whole object is still returned to the pool when the
+/// mapped handle is dropped (similar to `RefMut::map`).
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::{FixedPool, Poolable};
+///
+/// struct Connection {
+///     id: u32,
+///     buffer: Vec<u8>,
+/// }
+///
+/// impl Poolable for Connection {}
+///
+/// let pool = FixedPool::new(4).unwrap();
+/// let conn = pool.allocate(Connection { id: 1, buffer: Vec::new() }).unwrap();
+///
+/// let mut buffer = conn.map(|c| &mut c.buffer);
+/// buffer.extend_from_slice(b"ping");
+/// assert_eq!(&buffer[..], b"ping");
+///
+/// // Dropping the mapped handle returns the whole connection
+/// drop(buffer);
+/// assert_eq!(pool.allocated(), 0);
+/// ```
+pub struct MappedHandle<'pool, T, U: ?Sized> {
+    handle: OwnedHandle<'pool, T>,
+    /// Pointer into the object owned by `handle`
+    value: *mut U,
+}
+
+impl<'pool, T> OwnedHandle<'pool, T> {
+    /// Projects this handle onto a part of the pooled object.
+    ///
+    /// The returned handle dereferences to `U` but still owns the slot, so
+    /// the whole `T` goes back to the pool when it is dropped.
+    #[inline]
+    pub fn map<U: ?Sized, F>(mut self, f: F) -> MappedHandle<'pool, T, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let value = f(&mut self) as *mut U;
+        MappedHandle {
+            handle: self,
+            value,
+        }
+    }
+}
+
+impl<'pool, T, U: ?Sized> MappedHandle<'pool, T, U> {
+    /// Projects this handle further onto a part of the mapped value.
+    #[inline]
+    pub fn map<V: ?Sized, F>(self, f: F) -> MappedHandle<'pool, T, V>
+    where
+        F: FnOnce(&mut U) -> &mut V,
+    {
+        // Safety: `value` points into the slot owned by `handle`, which is
+        // moved into the new mapped handle unchanged.
+        let value = f(unsafe { &mut *self.value }) as *mut V;
+        MappedHandle {
+            handle: self.handle,
+            value,
+        }
+    }
+
+    /// Returns the internal index of the underlying slot.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.handle.index()
+    }
+}
+
+impl<'pool, T, U: ?Sized> Deref for MappedHandle<'pool, T, U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // Safety: the slot is owned by `self.handle` and pool storage does not
+        // move while the handle is alive.
+        unsafe { &*self.value }
+    }
+}
+
+impl<'pool, T, U: ?Sized> DerefMut for MappedHandle<'pool, T, U> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safety: as in `deref`, and `&mut self` guarantees exclusive access.
+        unsafe { &mut *self.value }
+    }
+}
+
+impl<'pool, T, U: ?Sized + fmt::Debug> fmt::Debug for MappedHandle<'pool, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedHandle")
+            .field("index", &self.index())
+            .field("value", &&**self)
+            .finish()
+    }
+}
+
+impl<'pool, T, U: ?Sized + fmt::Display> fmt::Display for MappedHandle<'pool, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pool::FixedPool;
+    use crate::traits::Poolable;
+    use alloc::vec::Vec;
+
+    struct Connection {
+        id: u32,
+        buffer: Vec<u8>,
+    }
+
+    impl Poolable for Connection {}
+
+    #[test]
+    fn map_to_field() {
+        let pool = FixedPool::new(4).unwrap();
+        let conn = pool
+            .allocate(Connection {
+                id: 7,
+                buffer: Vec::new(),
+            })
+            .unwrap();
+        let index = conn.index();
+
+        let mut buffer = conn.map(|c| &mut c.buffer);
+        buffer.push(1);
+        buffer.push(2);
+        assert_eq!(&buffer[..], &[1, 2]);
+        assert_eq!(buffer.index(), index);
+        assert_eq!(pool.allocated(), 1);
+
+        drop(buffer);
+        assert_eq!(pool.allocated(), 0);
+    }
+
+    #[test]
+    fn map_preserves_other_fields() {
+        let pool = FixedPool::new(1).unwrap();
+        let conn = pool
+            .allocate(Connection {
+                id: 42,
+                buffer: Vec::new(),
+            })
+            .unwrap();
+
+        let id = conn.map(|c| &mut c.id);
+        assert_eq!(*id, 42);
+        drop(id);
+
+        // Slot is free again for a new allocation
+        let conn = pool
+            .allocate(Connection {
+                id: 1,
+                buffer: Vec::new(),
+            })
+            .unwrap();
+        assert_eq!(conn.id, 1);
+    }
+
+    #[test]
+    fn map_chained_to_slice() {
+        let pool = FixedPool::new(2).unwrap();
+        let conn = pool
+            .allocate(Connection {
+                id: 0,
+                buffer: alloc::vec![1, 2, 3, 4],
+            })
+            .unwrap();
+
+        let mut tail = conn.map(|c| &mut c.buffer).map(|b| &mut b[2..]);
+        tail[0] = 30;
+        assert_eq!(&*tail, &[30, 4]);
+
+        drop(tail);
+        assert!(pool.is_empty());
+    }
+}
@@ -1,9 +1,18 @@
 //! Smart handles for pool-allocated objects.
 
+mod mapped;
 mod owned;
+mod owned_rc;
 mod shared;
+mod slice;
+mod uninit;
 mod weak;
 
-pub use owned::{OwnedHandle, PoolInterface};
+pub use mapped::MappedHandle;
+pub(crate) use owned::PoolInterface;
+pub use owned::{OwnedHandle, PoolUsage};
+pub use owned_rc::OwnedRcHandle;
 pub use shared::SharedHandle;
+pub use slice::SliceHandle;
+pub use uninit::UninitHandle;
 pub use weak::WeakHandle;
@@ -1,7 +1,13 @@
 //! Owned handle that exclusively owns a pool-allocated object.
 
+use crate::traits::Poolable;
+use core::borrow::{Borrow, BorrowMut};
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::ops::{Deref, DerefMut};
+#[cfg(debug_assertions)]
+use core::panic::Location;
+use core::pin::Pin;
 
 /// An owned handle to a pool-allocated object.
 ///
@@ -25,13 +31,73 @@ use core::ops::{Deref, DerefMut};
 ///
 /// // Automatically returned to pool when dropped
 /// ```
+///
+/// # Equality
+///
+/// `PartialEq`, `Ord` and `Hash` look at the contained value, so distinct
+/// allocations holding equal values compare equal. Use
+/// [`ptr_eq`](Self::ptr_eq) or [`handle_id`](Self::handle_id) when the
+/// identity of the allocation matters.
+///
+/// # Dropping
+///
+/// Dropping the handle frees its slot, so a handle that is never bound,
+/// as in `pool.allocate(x)?;`, reserves nothing. The type is `#[must_use]`
+/// to catch this:
+///
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// use fastalloc::{FixedPool, Result};
+///
+/// fn reserve(pool: &FixedPool<u32>) -> Result<()> {
+///     pool.allocate(1)?;
+///     Ok(())
+/// }
+/// ```
+#[must_use = "dropping the handle immediately returns the object to the pool"]
 pub struct OwnedHandle<'pool, T> {
     pool: &'pool dyn PoolInterface<T>,
     index: usize,
     _marker: core::marker::PhantomData<T>,
 }
 
-/// Internal trait for pool operations needed by handles.
+/// Read-only usage queries shared by every pool.
+///
+/// Monitoring code can take any pool as `&dyn PoolUsage`, or reach a
+/// handle's pool through [`OwnedHandle::pool`], without being able to touch
+/// the pooled objects.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::handle::PoolUsage;
+/// use fastalloc::{FixedPool, GrowingPool, PoolConfig};
+///
+/// fn usage(pool: &dyn PoolUsage) -> (usize, usize) {
+///     (pool.allocated(), pool.capacity())
+/// }
+///
+/// let fixed = FixedPool::new(8).unwrap();
+/// let growing = GrowingPool::with_config(PoolConfig::builder().capacity(4).build().unwrap()).unwrap();
+/// let a = fixed.allocate(1).unwrap();
+/// let b = growing.allocate(2).unwrap();
+///
+/// assert_eq!(usage(a.pool()), (1, 8));
+/// assert_eq!(usage(&growing), (1, 4));
+/// # drop(b);
+/// ```
+pub trait PoolUsage {
+    /// Returns the current capacity of the pool.
+    fn capacity(&self) -> usize;
+    /// Returns the number of slots available for allocation.
+    fn available(&self) -> usize;
+    /// Returns the number of allocated slots.
+    fn allocated(&self) -> usize {
+        self.capacity() - self.available()
+    }
+}
+
+/// Trait for pool operations needed by handles.
 ///
 /// This trait is used internally to allow handles to work with different
 /// pool types without exposing implementation details.
@@ -42,21 +108,40 @@ pub struct OwnedHandle<'pool, T> {
 /// This is safe because pools use interior mutability (RefCell/UnsafeCell) and
 /// ensure exclusive access through allocator tracking.
 #[allow(clippy::mut_from_ref)]
-pub trait PoolInterface<T> {
-    #[doc(hidden)]
+pub(crate) trait PoolInterface<T>: PoolUsage {
     fn get(&self, index: usize) -> &T;
-    #[doc(hidden)]
     fn get_mut(&self, index: usize) -> &mut T;
-    #[doc(hidden)]
     fn return_to_pool(&self, index: usize);
+    /// Returns the pool as a [`PoolUsage`] trait object.
+    fn as_usage(&self) -> &dyn PoolUsage;
+    /// Returns whether `index` is in range and its slot is currently allocated.
+    fn is_valid_index(&self, index: usize) -> bool;
+    /// Returns whether live objects keep their address until they are dropped.
+    ///
+    /// Pools returning `true` let [`OwnedHandle::into_pinned`] pin their handles.
+    fn is_address_stable(&self) -> bool {
+        false
+    }
+    /// Records that the object in slot `index` has been pinned, so it is
+    /// dropped in place rather than moved or reused if its handle leaks.
+    fn mark_pinned(&self, _index: usize) {}
+    /// Records where the object in slot `index` was allocated, for leak reports.
+    #[cfg(debug_assertions)]
+    fn record_allocation_site(&self, _index: usize, _site: &'static Location<'static>) {}
 }
 
 impl<'pool, T> OwnedHandle<'pool, T> {
     /// Creates a new owned handle.
     ///
     /// This is internal and should only be called by pool implementations.
+    /// In debug builds the caller's location is passed on to the pool as the
+    /// allocation site of the slot.
     #[inline]
+    #[track_caller]
     pub(crate) fn new(pool: &'pool dyn PoolInterface<T>, index: usize) -> Self {
+        #[cfg(debug_assertions)]
+        pool.record_allocation_site(index, Location::caller());
+
         Self {
             pool,
             index,
@@ -72,6 +157,244 @@ impl<'pool, T> OwnedHandle<'pool, T> {
     pub fn index(&self) -> usize {
         self.index
     }
+
+    /// Returns the pool this handle's object was allocated from.
+    ///
+    /// Lets code that only holds a handle ask about the pool's usage,
+    /// whatever the pool type.
+    #[inline]
+    pub fn pool(&self) -> &'pool dyn PoolUsage {
+        self.pool.as_usage()
+    }
+
+    /// Consumes the handle and returns its slot index without freeing the slot.
+    ///
+    /// The index is a compact stand-in for the handle: the slot stays
+    /// allocated, and the object in it untouched, until the index is turned
+    /// back into a handle with [`FixedPool::from_raw_index`](crate::FixedPool::from_raw_index)
+    /// and that handle is dropped. An index that is never re-wrapped keeps its
+    /// slot occupied until the pool itself is dropped, which then drops the
+    /// object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(4).unwrap();
+    /// let index = pool.allocate(42).unwrap().into_raw();
+    /// assert_eq!(pool.allocated(), 1);
+    ///
+    /// // Safety: `index` came from `into_raw` on this pool and is re-wrapped once
+    /// let handle = unsafe { pool.from_raw_index(index) };
+    /// assert_eq!(*handle, 42);
+    /// ```
+    #[inline]
+    pub fn into_raw(self) -> usize {
+        let index = self.index;
+        core::mem::forget(self);
+        index
+    }
+
+    /// Pins the handle, so the object can be used through `Pin<&mut T>`.
+    ///
+    /// This works for pools whose objects never move while they are live:
+    /// a [`FixedPool`](crate::FixedPool) allocates its storage once and never
+    /// reallocates it, and a [`GrowingPool`](crate::GrowingPool) with chunked
+    /// storage only adds chunks. In both, a slot is reused only after the
+    /// handle has dropped its object in place. If a pinned handle is leaked,
+    /// dropping or consuming the pool drops its object in place rather than
+    /// moving it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the handle unchanged if its pool may move objects, such as a
+    /// growing pool with contiguous storage.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(4).unwrap();
+    /// let mut pinned = pool.allocate(1).unwrap().into_pinned().unwrap();
+    ///
+    /// *pinned.as_mut().get_mut() += 1;
+    /// assert_eq!(*pinned, 2);
+    /// ```
+    pub fn into_pinned(self) -> Result<Pin<Self>, Self> {
+        if !self.pool.is_address_stable() {
+            return Err(self);
+        }
+        self.pool.mark_pinned(self.index);
+        // Safety: the pool keeps the object at the same address until the
+        // handle drops it, and the slot is not reused before that
+        Ok(unsafe { Pin::new_unchecked(self) })
+    }
+
+    /// Replaces the pooled object with `value`, returning the previous object.
+    ///
+    /// The slot stays allocated, so this lets a caller temporarily move the
+    /// object out (for example into an API that takes `Box<T>`) and put a
+    /// value back without releasing and reacquiring the slot. Pool hooks such
+    /// as `on_acquire` and `on_release` are not called; use
+    /// [`replace`](Self::replace) when they should be.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(4).unwrap();
+    /// let mut handle = pool.allocate(String::from("old")).unwrap();
+    ///
+    /// let old = handle.swap_with(String::from("new"));
+    /// assert_eq!(old, "old");
+    /// assert_eq!(*handle, "new");
+    /// ```
+    #[inline]
+    pub fn swap_with(&mut self, value: T) -> T {
+        core::mem::replace(&mut **self, value)
+    }
+
+    /// Returns a clone of the pooled object.
+    ///
+    /// Equivalent to `(*handle).clone()`, which is easy to confuse with
+    /// cloning the handle itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(4).unwrap();
+    /// let handle = pool.allocate(String::from("value")).unwrap();
+    ///
+    /// let copy: String = handle.clone_value();
+    /// assert_eq!(copy, *handle);
+    /// ```
+    #[inline]
+    pub fn clone_value(&self) -> T
+    where
+        T: Clone,
+    {
+        (**self).clone()
+    }
+
+    /// Returns whether both handles refer to the same slot of the same pool.
+    ///
+    /// This compares allocation identity, unlike `==`, which compares the
+    /// contained values: two handles holding equal values are `==` but not
+    /// `ptr_eq`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(4).unwrap();
+    /// let a = pool.allocate(7).unwrap();
+    /// let b = pool.allocate(7).unwrap();
+    ///
+    /// assert!(a == b);
+    /// assert!(!a.ptr_eq(&b));
+    /// assert!(a.ptr_eq(&a));
+    /// ```
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        self.handle_id() == other.handle_id()
+    }
+
+    /// Returns an identity for this allocation as `(pool_address, slot_index)`.
+    ///
+    /// The pair is unique among live handles, so it can key sets or maps that
+    /// must tell allocations apart regardless of their values. It may be
+    /// reused once the handle is dropped and the slot reallocated.
+    #[inline]
+    pub fn handle_id(&self) -> (usize, usize) {
+        let pool = self.pool as *const dyn PoolInterface<T> as *const () as usize;
+        (pool, self.index)
+    }
+
+    /// Returns the current capacity of the pool this handle was allocated from.
+    ///
+    /// Lets code that only receives a handle inspect its pool without also
+    /// holding a reference to it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(8).unwrap();
+    /// let handle = pool.allocate(1).unwrap();
+    /// assert_eq!(handle.pool_capacity(), 8);
+    /// assert_eq!(handle.pool_available(), 7);
+    /// ```
+    #[inline]
+    pub fn pool_capacity(&self) -> usize {
+        self.pool.capacity()
+    }
+
+    /// Returns the number of free slots in the pool this handle was allocated from.
+    ///
+    /// For a growing pool this counts only slots free at the current
+    /// capacity, not room the pool could still grow into.
+    #[inline]
+    pub fn pool_available(&self) -> usize {
+        self.pool.available()
+    }
+
+    /// Returns whether this handle was allocated from the given pool.
+    #[inline]
+    pub(crate) fn is_from(&self, pool: &dyn PoolInterface<T>) -> bool {
+        core::ptr::eq(
+            self.pool as *const dyn PoolInterface<T> as *const (),
+            pool as *const dyn PoolInterface<T> as *const (),
+        )
+    }
+}
+
+impl<'pool, T: Poolable> OwnedHandle<'pool, T> {
+    /// Replaces the pooled object with `value`, returning the previous object.
+    ///
+    /// Unlike [`swap_with`](Self::swap_with), this treats the exchange as a
+    /// release followed by an acquire: `on_release` runs on the old object
+    /// before it is handed back and `on_acquire` runs on the new one before
+    /// it is stored, just as if the slot had been freed and reallocated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{FixedPool, Poolable};
+    ///
+    /// struct Conn {
+    ///     open: bool,
+    /// }
+    ///
+    /// impl Poolable for Conn {
+    ///     fn on_acquire(&mut self) {
+    ///         self.open = true;
+    ///     }
+    ///
+    ///     fn on_release(&mut self) {
+    ///         self.open = false;
+    ///     }
+    /// }
+    ///
+    /// let pool = FixedPool::new(2).unwrap();
+    /// let mut handle = pool.allocate(Conn { open: false }).unwrap();
+    ///
+    /// let old = handle.replace(Conn { open: false });
+    /// assert!(!old.open);
+    /// assert!(handle.open);
+    /// ```
+    pub fn replace(&mut self, mut value: T) -> T {
+        value.on_acquire();
+        let slot = &mut **self;
+        slot.on_release();
+        core::mem::replace(slot, value)
+    }
 }
 
 impl<'pool, T> Deref for OwnedHandle<'pool, T> {
@@ -90,6 +413,36 @@ impl<'pool, T> DerefMut for OwnedHandle<'pool, T> {
     }
 }
 
+impl<'pool, T> AsRef<T> for OwnedHandle<'pool, T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<'pool, T> AsMut<T> for OwnedHandle<'pool, T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+// Handles compare and hash by value, so borrowing as `T` keeps
+// `Borrow`'s requirement that `Eq` and `Hash` agree with the borrowed form
+impl<'pool, T> Borrow<T> for OwnedHandle<'pool, T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<'pool, T> BorrowMut<T> for OwnedHandle<'pool, T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
 impl<'pool, T> Drop for OwnedHandle<'pool, T> {
     fn drop(&mut self) {
         self.pool.return_to_pool(self.index);
@@ -111,7 +464,8 @@ impl<'pool, T: fmt::Display> fmt::Display for OwnedHandle<'pool, T> {
     }
 }
 
-// Implement PartialEq by comparing the contained values
+// Implement PartialEq by comparing the contained values; use `ptr_eq` or
+// `handle_id` to compare allocation identity instead
 impl<'pool, T: PartialEq> PartialEq for OwnedHandle<'pool, T> {
     fn eq(&self, other: &Self) -> bool {
         **self == **other
@@ -120,6 +474,12 @@ impl<'pool, T: PartialEq> PartialEq for OwnedHandle<'pool, T> {
 
 impl<'pool, T: Eq> Eq for OwnedHandle<'pool, T> {}
 
+impl<'pool, T: Hash> Hash for OwnedHandle<'pool, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
 impl<'pool, T: PartialOrd> PartialOrd for OwnedHandle<'pool, T> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         (**self).partial_cmp(&**other)
@@ -133,6 +493,18 @@ impl<'pool, T: Ord> Ord for OwnedHandle<'pool, T> {
 }
 
 // Implement common traits for FixedPool to satisfy PoolInterface
+impl<T: crate::traits::Poolable> PoolUsage for crate::pool::FixedPool<T> {
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[inline]
+    fn available(&self) -> usize {
+        self.available()
+    }
+}
+
 impl<T: crate::traits::Poolable> super::owned::PoolInterface<T> for crate::pool::FixedPool<T> {
     #[inline]
     fn get(&self, index: usize) -> &T {
@@ -148,6 +520,32 @@ impl<T: crate::traits::Poolable> super::owned::PoolInterface<T> for crate::pool:
     fn return_to_pool(&self, index: usize) {
         self.return_to_pool(index)
     }
+
+    #[inline]
+    fn as_usage(&self) -> &dyn PoolUsage {
+        self
+    }
+
+    #[inline]
+    fn is_valid_index(&self, index: usize) -> bool {
+        self.is_live(index)
+    }
+
+    #[inline]
+    fn is_address_stable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn mark_pinned(&self, index: usize) {
+        self.mark_pinned(index)
+    }
+
+    #[inline]
+    #[cfg(debug_assertions)]
+    fn record_allocation_site(&self, index: usize, site: &'static Location<'static>) {
+        self.record_allocation_site(index, site)
+    }
 }
 
 #[cfg(test)]
@@ -193,4 +591,182 @@ mod tests {
         assert_eq!(h1, h2);
         assert_ne!(h1, h3);
     }
+
+    #[test]
+    fn swap_with_returns_old_value() {
+        let pool = FixedPool::new(2).unwrap();
+        let mut handle = pool.allocate(alloc::vec![1u8, 2, 3]).unwrap();
+        let index = handle.index();
+
+        let boxed = alloc::boxed::Box::new(handle.swap_with(alloc::vec![9]));
+        assert_eq!(*boxed, [1, 2, 3]);
+        assert_eq!(*handle, [9]);
+
+        // Swapping keeps the same slot allocated
+        assert_eq!(handle.index(), index);
+        assert_eq!(pool.allocated(), 1);
+
+        let old = handle.swap_with(*boxed);
+        assert_eq!(old, [9]);
+        assert_eq!(*handle, [1, 2, 3]);
+    }
+
+    #[test]
+    fn handle_as_ref_and_as_mut() {
+        fn total<V: AsRef<i32>>(values: &[V]) -> i32 {
+            values.iter().map(|v| *v.as_ref()).sum()
+        }
+
+        let pool = FixedPool::new(4).unwrap();
+        let mut handles = alloc::vec![pool.allocate(1).unwrap(), pool.allocate(2).unwrap()];
+        *handles[0].as_mut() = 10;
+        assert_eq!(total(&handles), 12);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn handles_in_hash_set_lookup_by_value() {
+        use core::hash::{BuildHasher, Hash, Hasher};
+        use std::collections::hash_map::RandomState;
+        use std::collections::HashSet;
+
+        let pool = FixedPool::new(8).unwrap();
+        let mut set = HashSet::new();
+        for value in [3, 5, 8] {
+            set.insert(pool.allocate(value).unwrap());
+        }
+
+        // Lookups borrow the handle as its value
+        assert!(set.contains(&5));
+        assert!(!set.contains(&4));
+
+        let removed = set.take(&8).unwrap();
+        assert_eq!(*removed, 8);
+
+        // Equal values hash equally, whichever form is hashed
+        fn hash_of<V: Hash>(state: &RandomState, value: &V) -> u64 {
+            let mut hasher = state.build_hasher();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let state = RandomState::new();
+        let handle = pool.allocate(3).unwrap();
+        assert_eq!(hash_of(&state, &handle), hash_of(&state, &3));
+    }
+
+    #[test]
+    fn ptr_eq_compares_identity() {
+        use alloc::string::String;
+
+        let pool = FixedPool::new(4).unwrap();
+        let a = pool.allocate(String::from("same")).unwrap();
+        let b = pool.allocate(String::from("same")).unwrap();
+
+        assert!(a == b);
+        assert!(!a.ptr_eq(&b));
+        assert!(a.ptr_eq(&a));
+        assert_ne!(a.handle_id(), b.handle_id());
+        assert_eq!(a.handle_id().0, b.handle_id().0);
+        assert_eq!(b.handle_id().1, b.index());
+    }
+
+    #[test]
+    fn handle_reports_pool_state() {
+        use crate::{GrowingPool, GrowthStrategy, PoolConfig};
+
+        let pool = FixedPool::new(10).unwrap();
+        let first = pool.allocate(1).unwrap();
+        let _others: alloc::vec::Vec<_> = (0..3).map(|i| pool.allocate(i).unwrap()).collect();
+        assert_eq!(first.pool_capacity(), pool.capacity());
+        assert_eq!(first.pool_available(), pool.available());
+        assert_eq!(first.pool_available(), 6);
+
+        let config = PoolConfig::builder()
+            .capacity(2)
+            .growth_strategy(GrowthStrategy::Linear { amount: 2 })
+            .build()
+            .unwrap();
+        let growing = GrowingPool::with_config(config).unwrap();
+        let handle = growing.allocate(0).unwrap();
+        let _rest: alloc::vec::Vec<_> = (0..2).map(|i| growing.allocate(i).unwrap()).collect();
+        assert_eq!(handle.pool_capacity(), 4);
+        assert_eq!(handle.pool_available(), growing.available());
+        assert_eq!(handle.pool_available(), 1);
+    }
+
+    #[test]
+    fn clone_value_copies_the_object() {
+        let pool = FixedPool::new(2).unwrap();
+        let mut handle = pool.allocate(alloc::vec![1, 2]).unwrap();
+
+        let copy = handle.clone_value();
+        handle.push(3);
+        assert_eq!(copy, alloc::vec![1, 2]);
+        assert_eq!(pool.allocated(), 1);
+    }
+
+    #[test]
+    fn replace_runs_hooks_and_returns_old_value() {
+        use crate::traits::Poolable;
+
+        struct Tracked {
+            id: u32,
+            acquired: u32,
+            released: u32,
+        }
+
+        impl Poolable for Tracked {
+            fn on_acquire(&mut self) {
+                self.acquired += 1;
+            }
+
+            fn on_release(&mut self) {
+                self.released += 1;
+            }
+        }
+
+        let tracked = |id| Tracked {
+            id,
+            acquired: 0,
+            released: 0,
+        };
+
+        let pool = FixedPool::new(2).unwrap();
+        let mut handle = pool.allocate(tracked(1)).unwrap();
+        assert_eq!((handle.acquired, handle.released), (1, 0));
+
+        let old = handle.replace(tracked(2));
+        assert_eq!(old.id, 1);
+        assert_eq!((old.acquired, old.released), (1, 1));
+        assert_eq!(handle.id, 2);
+        assert_eq!((handle.acquired, handle.released), (1, 0));
+        assert_eq!(pool.available(), 1);
+    }
+
+    #[test]
+    fn pool_usage_through_dyn_interface() {
+        use super::PoolUsage;
+        use crate::config::{GrowthStrategy, PoolConfig};
+        use crate::pool::GrowingPool;
+        use alloc::vec::Vec;
+
+        let fixed = FixedPool::new(8).unwrap();
+        let config = PoolConfig::builder()
+            .capacity(2)
+            .growth_strategy(GrowthStrategy::Linear { amount: 2 })
+            .build()
+            .unwrap();
+        let growing = GrowingPool::with_config(config).unwrap();
+
+        let from_fixed: Vec<_> = (0..3).map(|i| fixed.allocate(i).unwrap()).collect();
+        let from_growing: Vec<_> = (0..3).map(|i| growing.allocate(i).unwrap()).collect();
+
+        let pools: [&dyn PoolUsage; 2] = [from_fixed[0].pool(), from_growing[0].pool()];
+        let usage: Vec<_> = pools
+            .iter()
+            .map(|pool| (pool.allocated(), pool.available(), pool.capacity()))
+            .collect();
+        assert_eq!(usage, [(3, 5, 8), (3, 1, 4)]);
+    }
 }
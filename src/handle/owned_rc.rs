@@ -0,0 +1,147 @@
+//! Owned handle that keeps its pool alive through an `Rc`.
+
+use crate::pool::FixedPool;
+use crate::traits::Poolable;
+use alloc::rc::Rc;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+/// An owned handle that holds an `Rc` to its [`FixedPool`].
+///
+/// [`OwnedHandle`](super::OwnedHandle) borrows its pool, so it cannot be
+/// stored in a `'static` struct or outlive the scope the pool lives in.
+/// `OwnedRcHandle` owns a reference to the pool instead, which keeps the
+/// pool alive for as long as any of its handles exist. It is created with
+/// [`FixedPool::allocate_owned`] and otherwise behaves like an
+/// `OwnedHandle`: exclusive access, and the slot is returned when the
+/// handle is dropped.
+///
+/// The pool is single-threaded, so it is shared through an `Rc` rather
+/// than an `Arc`, and the handle is confined to one thread like the pool.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::{FixedPool, OwnedRcHandle};
+/// use std::rc::Rc;
+///
+/// struct Scene {
+///     entities: Vec<OwnedRcHandle<String>>,
+/// }
+///
+/// let pool = Rc::new(FixedPool::new(8).unwrap());
+/// let scene = Scene {
+///     entities: vec![
+///         pool.allocate_owned(String::from("player")).unwrap(),
+///         pool.allocate_owned(String::from("camera")).unwrap(),
+///     ],
+/// };
+///
+/// assert_eq!(*scene.entities[1], "camera");
+/// assert_eq!(pool.allocated(), 2);
+///
+/// drop(scene);
+/// assert_eq!(pool.allocated(), 0);
+/// ```
+pub struct OwnedRcHandle<T: Poolable> {
+    pool: Rc<FixedPool<T>>,
+    index: usize,
+}
+
+impl<T: Poolable> OwnedRcHandle<T> {
+    /// Creates a new handle for an allocated slot.
+    ///
+    /// This is internal and should only be called by `FixedPool`.
+    #[inline]
+    pub(crate) fn new(pool: Rc<FixedPool<T>>, index: usize) -> Self {
+        Self { pool, index }
+    }
+
+    /// Returns the internal index of this handle.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the pool this handle's object was allocated from.
+    #[inline]
+    pub fn pool(&self) -> &Rc<FixedPool<T>> {
+        &self.pool
+    }
+}
+
+impl<T: Poolable> Deref for OwnedRcHandle<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.pool.get(self.index)
+    }
+}
+
+impl<T: Poolable> DerefMut for OwnedRcHandle<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.pool.get_mut(self.index)
+    }
+}
+
+impl<T: Poolable> Drop for OwnedRcHandle<T> {
+    fn drop(&mut self) {
+        self.pool.return_to_pool(self.index);
+    }
+}
+
+impl<T: Poolable + fmt::Debug> fmt::Debug for OwnedRcHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedRcHandle")
+            .field("index", &self.index)
+            .field("value", &**self)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    struct Registry {
+        items: Vec<OwnedRcHandle<u32>>,
+    }
+
+    fn build_registry(pool: &Rc<FixedPool<u32>>) -> Registry {
+        Registry {
+            items: (0..4).map(|i| pool.allocate_owned(i).unwrap()).collect(),
+        }
+    }
+
+    #[test]
+    fn handles_stored_in_struct_reclaim_slots_on_drop() {
+        let pool = Rc::new(FixedPool::new(4).unwrap());
+        let mut registry = build_registry(&pool);
+        assert!(pool.is_full());
+
+        *registry.items[2] += 40;
+        assert_eq!(*registry.items[2], 42);
+
+        registry.items.truncate(1);
+        assert_eq!(pool.allocated(), 1);
+
+        drop(registry);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn handle_keeps_pool_alive() {
+        let handle = {
+            let pool = Rc::new(FixedPool::new(2).unwrap());
+            pool.allocate_owned(String::from("kept")).unwrap()
+        };
+
+        assert_eq!(*handle, "kept");
+        assert_eq!(Rc::strong_count(handle.pool()), 1);
+        assert_eq!(handle.pool().allocated(), 1);
+    }
+}
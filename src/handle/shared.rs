@@ -1,7 +1,10 @@
 //! Shared handle with reference counting for pool-allocated objects.
 
+use crate::error::{Error, Result};
 use alloc::rc::Rc;
+use core::borrow::Borrow;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::ops::Deref;
 
 /// A shared handle to a pool-allocated object with reference counting.
@@ -12,17 +15,21 @@ use core::ops::Deref;
 /// # Examples
 ///
 /// ```rust
-/// use fastalloc::{FixedPool, SharedHandle};
+/// use fastalloc::FixedPool;
 ///
 /// let pool = FixedPool::<i32>::new(10).unwrap();
-/// // Note: This is a simplified example. Actual implementation would need
-/// // pool support for shared handles.
-/// ```
+/// let shared = pool.allocate_shared(42).unwrap();
+/// let shared2 = shared.clone();
+/// assert_eq!(shared.strong_count(), 2);
 ///
-/// # Note
+/// drop(shared);
+/// assert_eq!(pool.allocated(), 1);
 ///
-/// This is currently a placeholder implementation. Full reference-counted
-/// handles require additional pool infrastructure.
+/// // The slot is released when the last handle is dropped
+/// drop(shared2);
+/// assert_eq!(pool.allocated(), 0);
+/// ```
+#[must_use = "dropping the handle immediately returns the object to the pool"]
 pub struct SharedHandle<'pool, T> {
     pub(crate) inner: Rc<SharedHandleInner<'pool, T>>,
 }
@@ -34,19 +41,32 @@ pub(crate) struct SharedHandleInner<'pool, T> {
 }
 
 impl<'pool, T> SharedHandle<'pool, T> {
-    /// Creates a new shared handle.
+    /// Creates a shared handle that takes over the live slot at `index`.
+    ///
+    /// This is internal and should only be called by pool implementations,
+    /// or with an index whose other handle was given up, e.g. with
+    /// [`OwnedHandle::into_raw`](super::OwnedHandle::into_raw).
+    ///
+    /// # Errors
     ///
-    /// This is internal and should only be called by pool implementations.
+    /// Returns `Error::InvalidHandle` if `index` is out of range or its slot
+    /// is free.
     #[inline]
-    #[allow(dead_code)]
-    pub(crate) fn new(pool: &'pool dyn super::owned::PoolInterface<T>, index: usize) -> Self {
-        Self {
+    pub(crate) fn new(
+        pool: &'pool dyn super::owned::PoolInterface<T>,
+        index: usize,
+    ) -> Result<Self> {
+        if !pool.is_valid_index(index) {
+            return Err(Error::InvalidHandle);
+        }
+
+        Ok(Self {
             inner: Rc::new(SharedHandleInner {
                 pool,
                 index,
                 _marker: core::marker::PhantomData,
             }),
-        }
+        })
     }
 
     /// Returns the number of shared handles pointing to this object.
@@ -115,6 +135,26 @@ impl<'pool, T: PartialEq> PartialEq for SharedHandle<'pool, T> {
 
 impl<'pool, T: Eq> Eq for SharedHandle<'pool, T> {}
 
+impl<'pool, T: Hash> Hash for SharedHandle<'pool, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<'pool, T> AsRef<T> for SharedHandle<'pool, T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<'pool, T> Borrow<T> for SharedHandle<'pool, T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,11 +163,10 @@ mod tests {
     #[test]
     fn shared_handle_clone() {
         let pool = FixedPool::<i32>::new(10).unwrap();
-        let handle = pool.allocate(42).unwrap();
-        let index = handle.index();
+        let index = pool.allocate(42).unwrap().into_raw();
 
-        // Convert to shared handle (note: this bypasses normal pool lifecycle)
-        let shared = SharedHandle::new(&pool, index);
+        // Hand the slot over to a shared handle
+        let shared = SharedHandle::new(&pool, index).unwrap();
         assert_eq!(shared.strong_count(), 1);
 
         let shared2 = shared.clone();
@@ -137,7 +176,118 @@ mod tests {
         drop(shared2);
         assert_eq!(shared.strong_count(), 1);
 
-        // Prevent double-free by forgetting the original handle
-        core::mem::forget(handle);
+        drop(shared);
+        assert_eq!(pool.allocated(), 0);
+    }
+
+    #[test]
+    fn allocate_shared_releases_on_last_drop() {
+        let pool = FixedPool::<i32>::new(10).unwrap();
+        let shared = pool.allocate_shared(42).unwrap();
+        assert_eq!(*shared, 42);
+        assert_eq!(pool.allocated(), 1);
+
+        let shared2 = shared.clone();
+        assert_eq!(shared.strong_count(), 2);
+        assert_eq!(shared.index(), shared2.index());
+
+        drop(shared);
+        assert_eq!(pool.allocated(), 1);
+        assert_eq!(*shared2, 42);
+
+        drop(shared2);
+        assert_eq!(pool.allocated(), 0);
+        assert_eq!(pool.available(), 10);
+    }
+
+    #[test]
+    fn allocate_shared_from_growing_pool() {
+        use crate::config::{GrowthStrategy, PoolConfig};
+        use crate::pool::GrowingPool;
+
+        let config = PoolConfig::builder()
+            .capacity(1)
+            .growth_strategy(GrowthStrategy::Linear { amount: 1 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let a = pool.allocate_shared(1).unwrap();
+        let b = pool.allocate_shared(2).unwrap();
+        assert_eq!(pool.capacity(), 2);
+        assert_eq!((*a, *b), (1, 2));
+
+        drop(a);
+        drop(b);
+        assert_eq!(pool.allocated(), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn shared_handles_in_hash_set() {
+        use std::collections::HashSet;
+
+        let pool = FixedPool::new(4).unwrap();
+        let a = pool
+            .allocate_shared(alloc::string::String::from("a"))
+            .unwrap();
+        let b = pool
+            .allocate_shared(alloc::string::String::from("b"))
+            .unwrap();
+
+        let mut set = HashSet::new();
+        set.insert(a.clone());
+        set.insert(b);
+
+        // A clone of the same handle is equal and hashes the same
+        assert!(!set.insert(a));
+        assert!(set.contains(&alloc::string::String::from("b")));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn new_rejects_out_of_range_and_free_indices() {
+        let pool = FixedPool::<i32>::new(4).unwrap();
+        let live = pool.allocate(1).unwrap();
+
+        assert_eq!(
+            SharedHandle::new(&pool, 4).err(),
+            Some(Error::InvalidHandle)
+        );
+        assert_eq!(
+            SharedHandle::new(&pool, live.index() + 1).err(),
+            Some(Error::InvalidHandle)
+        );
+        assert_eq!(pool.allocated(), 1);
+    }
+
+    #[test]
+    fn pools_report_valid_indices() {
+        use crate::handle::PoolInterface;
+        use crate::pool::{GrowingPool, RingBufferPool, StaticPool};
+
+        let fixed = FixedPool::<i32>::new(2).unwrap();
+        let a = fixed.allocate(1).unwrap();
+        assert!(fixed.is_valid_index(a.index()));
+        assert!(!fixed.is_valid_index(1));
+        assert!(!fixed.is_valid_index(2));
+
+        let growing = GrowingPool::<i32>::default();
+        let b = growing.allocate(1).unwrap();
+        assert!(growing.is_valid_index(b.index()));
+        assert!(!growing.is_valid_index(100));
+
+        let ring = RingBufferPool::<i32>::new(3).unwrap();
+        let c = ring.allocate(1).unwrap();
+        let d = ring.allocate(2).unwrap();
+        assert!(ring.is_valid_index(c.index()) && ring.is_valid_index(d.index()));
+        assert!(!ring.is_valid_index(2));
+        drop(c);
+        assert!(!ring.is_valid_index(0));
+
+        let fixed_static = StaticPool::<i32, 2>::new();
+        let e = fixed_static.allocate(1).unwrap();
+        assert!(fixed_static.is_valid_index(e.index()));
+        assert!(!fixed_static.is_valid_index(2));
     }
 }
@@ -0,0 +1,130 @@
+//! Handle owning a contiguous run of pool slots.
+
+use crate::pool::FixedPool;
+use crate::traits::Poolable;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+/// A handle to a contiguous run of pool-allocated objects.
+///
+/// Created by [`FixedPool::allocate_slice`]. The handle dereferences to a
+/// slice of the objects, which makes it suitable for batch or SIMD
+/// processing, and returns the whole run to the pool when dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::FixedPool;
+///
+/// let pool = FixedPool::new(16).unwrap();
+/// let mut batch = pool.allocate_slice(vec![1.0f32; 8]).unwrap();
+///
+/// for x in batch.iter_mut() {
+///     *x *= 2.0;
+/// }
+/// assert_eq!(batch.iter().sum::<f32>(), 16.0);
+///
+/// drop(batch);
+/// assert!(pool.is_empty());
+/// ```
+pub struct SliceHandle<'pool, T: Poolable> {
+    pool: &'pool FixedPool<T>,
+    start: usize,
+    len: usize,
+}
+
+impl<'pool, T: Poolable> SliceHandle<'pool, T> {
+    /// Creates a new slice handle.
+    ///
+    /// This is internal and should only be called by pool implementations.
+    #[inline]
+    pub(crate) fn new(pool: &'pool FixedPool<T>, start: usize, len: usize) -> Self {
+        Self { pool, start, len }
+    }
+
+    /// Returns the internal index of the first slot in the run.
+    #[inline]
+    pub fn start_index(&self) -> usize {
+        self.start
+    }
+}
+
+impl<'pool, T: Poolable> Deref for SliceHandle<'pool, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // Safety: the run is allocated to this handle and fully initialized
+        unsafe { core::slice::from_raw_parts(self.pool.slot_ptr(self.start), self.len) }
+    }
+}
+
+impl<'pool, T: Poolable> DerefMut for SliceHandle<'pool, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safety: as in `deref`, and `&mut self` guarantees exclusive access
+        unsafe { core::slice::from_raw_parts_mut(self.pool.slot_ptr(self.start), self.len) }
+    }
+}
+
+impl<'pool, T: Poolable> Drop for SliceHandle<'pool, T> {
+    fn drop(&mut self) {
+        self.pool.return_run(self.start, self.len);
+    }
+}
+
+impl<'pool, T: Poolable + fmt::Debug> fmt::Debug for SliceHandle<'pool, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SliceHandle")
+            .field("start", &self.start)
+            .field("values", &&**self)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pool::FixedPool;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn slice_straddles_word_boundary() {
+        let pool = FixedPool::new(130).unwrap();
+        let singles: Vec<_> = (0..60).map(|i| pool.allocate(i).unwrap()).collect();
+
+        let mut slice = pool.allocate_slice((100..110).collect()).unwrap();
+        assert_eq!(slice.start_index(), 60);
+        assert_eq!(&slice[..], &(100..110).collect::<Vec<_>>()[..]);
+
+        slice[4] = -1;
+        assert_eq!(slice[4], -1);
+        assert_eq!(pool.allocated(), 70);
+
+        drop(slice);
+        assert_eq!(pool.allocated(), 60);
+        drop(singles);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn slice_requires_contiguous_space() {
+        let pool = FixedPool::new(4).unwrap();
+        let a = pool.allocate(0).unwrap();
+        let _b = pool.allocate(1).unwrap();
+        let _c = pool.allocate(2).unwrap();
+        drop(a);
+
+        // Two slots are free (0 and 3) but they are not adjacent
+        assert!(pool.allocate_slice(vec![7, 8]).is_err());
+        assert_eq!(pool.allocated(), 2);
+    }
+
+    #[test]
+    fn empty_slice() {
+        let pool = FixedPool::<i32>::new(2).unwrap();
+        let slice = pool.allocate_slice(Vec::new()).unwrap();
+        assert!(slice.is_empty());
+        assert_eq!(pool.allocated(), 0);
+    }
+}
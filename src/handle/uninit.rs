@@ -0,0 +1,100 @@
+//! Handle to a reserved slot that has not been initialized yet.
+
+use super::OwnedHandle;
+use crate::pool::FixedPool;
+use crate::traits::Poolable;
+use core::fmt;
+use core::mem::{self, MaybeUninit};
+
+/// A reserved but uninitialized slot in a [`FixedPool`].
+///
+/// Returned by [`FixedPool::allocate_uninit`] for hot paths that overwrite
+/// the whole object anyway. The slot can be filled in place through
+/// [`as_uninit_mut`](Self::as_uninit_mut) and then turned into an
+/// [`OwnedHandle`], or finished with [`write`](Self::write). Neither path
+/// calls `on_acquire` or the pool's acquire hooks.
+///
+/// Dropping the handle without finishing it frees the slot again; nothing
+/// is dropped, since nothing was initialized.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::FixedPool;
+///
+/// let pool = FixedPool::<[u64; 32]>::new(4).unwrap();
+///
+/// let mut slot = pool.allocate_uninit().unwrap();
+/// slot.as_uninit_mut().write([7; 32]);
+/// // Safety: the slot was fully written above
+/// let block = unsafe { slot.assume_init() };
+/// assert_eq!(block[31], 7);
+///
+/// let other = pool.allocate_uninit().unwrap().write([1; 32]);
+/// assert_eq!(other[0], 1);
+/// ```
+pub struct UninitHandle<'pool, T: Poolable> {
+    pool: &'pool FixedPool<T>,
+    index: usize,
+}
+
+impl<'pool, T: Poolable> UninitHandle<'pool, T> {
+    /// Creates a handle for a reserved slot.
+    ///
+    /// This is internal and should only be called by `FixedPool`.
+    #[inline]
+    pub(crate) fn new(pool: &'pool FixedPool<T>, index: usize) -> Self {
+        Self { pool, index }
+    }
+
+    /// Returns the internal index of the reserved slot.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the uninitialized slot for writing the object in place.
+    #[inline]
+    pub fn as_uninit_mut(&mut self) -> &mut MaybeUninit<T> {
+        // Safety: the slot is reserved for this handle alone
+        unsafe { &mut *self.pool.slot_ptr(self.index).cast::<MaybeUninit<T>>() }
+    }
+
+    /// Writes `value` into the slot and returns a handle to it.
+    #[inline]
+    #[track_caller]
+    pub fn write(mut self, value: T) -> OwnedHandle<'pool, T> {
+        self.as_uninit_mut().write(value);
+        // Safety: the slot was just initialized
+        unsafe { self.assume_init() }
+    }
+
+    /// Returns a handle to the object written through
+    /// [`as_uninit_mut`](Self::as_uninit_mut).
+    ///
+    /// # Safety
+    ///
+    /// The slot must have been fully initialized.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn assume_init(self) -> OwnedHandle<'pool, T> {
+        let (pool, index) = (self.pool, self.index);
+        mem::forget(self);
+        pool.commit_uninit(index);
+        OwnedHandle::new(pool, index)
+    }
+}
+
+impl<T: Poolable> Drop for UninitHandle<'_, T> {
+    fn drop(&mut self) {
+        self.pool.release_uninit(self.index);
+    }
+}
+
+impl<T: Poolable> fmt::Debug for UninitHandle<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UninitHandle")
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
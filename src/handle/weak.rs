@@ -12,10 +12,17 @@ use core::fmt;
 /// # Examples
 ///
 /// ```rust
-/// use fastalloc::{FixedPool, SharedHandle};
+/// use fastalloc::FixedPool;
 ///
 /// let pool = FixedPool::<i32>::new(10).unwrap();
-/// // This is a simplified example showing the concept
+/// let shared = pool.allocate_shared(42).unwrap();
+/// let weak = shared.downgrade();
+///
+/// assert_eq!(weak.upgrade().map(|h| *h), Some(42));
+///
+/// // Once the object is back in the pool, the weak handle no longer upgrades
+/// drop(shared);
+/// assert!(weak.upgrade().is_none());
 /// ```
 pub struct WeakHandle<'pool, T> {
     inner: Weak<super::shared::SharedHandleInner<'pool, T>>,
@@ -78,10 +85,9 @@ mod tests {
     #[test]
     fn weak_handle_upgrade() {
         let pool = FixedPool::<i32>::new(10).unwrap();
-        let handle = pool.allocate(42).unwrap();
-        let index = handle.index();
+        let index = pool.allocate(42).unwrap().into_raw();
 
-        let shared = SharedHandle::new(&pool, index);
+        let shared = SharedHandle::new(&pool, index).unwrap();
         let weak = shared.downgrade();
 
         assert_eq!(weak.strong_count(), 1);
@@ -97,25 +103,73 @@ mod tests {
         // Cannot upgrade after all strong references are gone
         let upgraded = weak.upgrade();
         assert!(upgraded.is_none());
-
-        // Prevent double-free
-        core::mem::forget(handle);
+        assert_eq!(pool.allocated(), 0);
     }
 
     #[test]
     fn weak_handle_clone() {
         let pool = FixedPool::<i32>::new(10).unwrap();
-        let handle = pool.allocate(42).unwrap();
-        let index = handle.index();
+        let index = pool.allocate(42).unwrap().into_raw();
+
+        let shared = SharedHandle::new(&pool, index).unwrap();
+        let weak = shared.downgrade();
+        let weak2 = weak.clone();
+
+        assert_eq!(weak.weak_count(), weak2.weak_count());
+
+        drop(shared);
+        assert_eq!(pool.allocated(), 0);
+    }
+
+    #[test]
+    fn weak_handle_upgrade_from_pool() {
+        let pool = FixedPool::<i32>::new(10).unwrap();
+        let shared = pool.allocate_shared(42).unwrap();
+        let weak = shared.downgrade();
+
+        assert_eq!(weak.strong_count(), 1);
 
-        let shared = SharedHandle::new(&pool, index);
+        // Can upgrade while shared handle exists
+        let upgraded = weak.upgrade();
+        assert_eq!(upgraded.as_deref(), Some(&42));
+        assert_eq!(weak.strong_count(), 2);
+
+        drop(shared);
+        assert_eq!(pool.allocated(), 1);
+        drop(upgraded);
+
+        // Slot freed exactly once and weak handle is dead
+        assert_eq!(pool.allocated(), 0);
+        assert!(weak.upgrade().is_none());
+        assert_eq!(weak.strong_count(), 0);
+    }
+
+    #[test]
+    fn weak_handle_clone_from_pool() {
+        let pool = FixedPool::<i32>::new(10).unwrap();
+        let shared = pool.allocate_shared(42).unwrap();
         let weak = shared.downgrade();
         let weak2 = weak.clone();
 
+        assert_eq!(weak.weak_count(), 2);
         assert_eq!(weak.weak_count(), weak2.weak_count());
 
-        // Cleanup
         drop(shared);
-        core::mem::forget(handle);
+        assert!(weak.upgrade().is_none());
+        assert!(weak2.upgrade().is_none());
+        assert_eq!(pool.available(), 10);
+    }
+
+    #[test]
+    fn slot_reused_after_weak_expires() {
+        let pool = FixedPool::<i32>::new(1).unwrap();
+        let shared = pool.allocate_shared(1).unwrap();
+        let weak = shared.downgrade();
+        drop(shared);
+
+        // The slot can be handed out again without the weak handle resurrecting it
+        let handle = pool.allocate(2).unwrap();
+        assert_eq!(*handle, 2);
+        assert!(weak.upgrade().is_none());
     }
 }
@@ -112,6 +112,7 @@ extern crate alloc;
 extern crate std;
 
 // Core modules
+pub mod allocator;
 pub mod config;
 pub mod error;
 pub mod handle;
@@ -119,7 +120,6 @@ pub mod pool;
 pub mod traits;
 
 // Internal modules
-mod allocator;
 mod utils;
 
 // Optional modules
@@ -128,18 +128,35 @@ mod utils;
 pub mod stats;
 
 // Re-exports for convenience
-pub use config::{GrowthStrategy, InitializationStrategy, PoolConfig};
-pub use error::{Error, Result};
-pub use handle::{OwnedHandle, SharedHandle, WeakHandle};
-pub use pool::{FixedPool, GrowingPool};
+pub use config::{GrowthStrategy, InitializationStrategy, PoolConfig, PoolEvent, ShrinkPolicy};
+pub use error::{Error, Result, RetryHint};
+pub use handle::{
+    MappedHandle, OwnedHandle, OwnedRcHandle, SharedHandle, SliceHandle, UninitHandle, WeakHandle,
+};
+pub use pool::{
+    BoxHandle, BoxPool, ChunkInfo, FixedPool, GrowingPool, PoolGuard, PooledString, RingBufferPool,
+    StaticPool, StringPool, TaggedHandle, TaggedPool, VecPoolSet,
+};
 pub use traits::Poolable;
 
 #[cfg(feature = "std")]
-pub use pool::{ThreadLocalPool, ThreadSafePool};
+pub use pool::{ArcSharedHandle, PoolCache, PoolSet, ScopedPool, ThreadLocalPool, ThreadSafePool};
 
 #[cfg(all(feature = "std", feature = "lock-free"))]
 #[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "lock-free"))))]
-pub use pool::LockFreePool;
+pub use pool::{LockFreePool, SpscHandle, SpscPool, SpscReleaser};
+
+#[cfg(feature = "parking_lot")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parking_lot")))]
+pub use pool::{RwLockHandle, RwLockPool};
+
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub use pool::{AsyncHandle, AsyncPool};
+
+#[cfg(feature = "mmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mmap")))]
+pub use pool::MmapPool;
 
 #[cfg(feature = "stats")]
 pub use stats::{PoolStatistics, StatisticsCollector};
@@ -148,17 +165,36 @@ pub use stats::{PoolStatistics, StatisticsCollector};
 pub mod prelude {
     //! Convenient re-exports of commonly used types
 
-    pub use crate::config::{GrowthStrategy, InitializationStrategy, PoolConfig};
+    pub use crate::config::{
+        GrowthStrategy, InitializationStrategy, PoolConfig, PoolEvent, ShrinkPolicy,
+    };
     pub use crate::error::{Error, Result};
-    pub use crate::handle::{OwnedHandle, SharedHandle, WeakHandle};
-    pub use crate::pool::{FixedPool, GrowingPool};
+    pub use crate::handle::{
+        MappedHandle, OwnedHandle, OwnedRcHandle, SharedHandle, SliceHandle, UninitHandle,
+        WeakHandle,
+    };
+    pub use crate::pool::{
+        BoxHandle, BoxPool, ChunkInfo, FixedPool, GrowingPool, PoolGuard, PooledString,
+        RingBufferPool, StaticPool, StringPool, TaggedHandle, TaggedPool, VecPoolSet,
+    };
     pub use crate::traits::Poolable;
 
     #[cfg(feature = "std")]
-    pub use crate::pool::{ThreadLocalPool, ThreadSafePool};
+    pub use crate::pool::{
+        ArcSharedHandle, PoolCache, PoolSet, ScopedPool, ThreadLocalPool, ThreadSafePool,
+    };
 
     #[cfg(all(feature = "std", feature = "lock-free"))]
-    pub use crate::pool::LockFreePool;
+    pub use crate::pool::{LockFreePool, SpscHandle, SpscPool, SpscReleaser};
+
+    #[cfg(feature = "parking_lot")]
+    pub use crate::pool::{RwLockHandle, RwLockPool};
+
+    #[cfg(feature = "async")]
+    pub use crate::pool::{AsyncHandle, AsyncPool};
+
+    #[cfg(feature = "mmap")]
+    pub use crate::pool::MmapPool;
 
     #[cfg(feature = "stats")]
     pub use crate::stats::{PoolStatistics, StatisticsCollector};
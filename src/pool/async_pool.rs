@@ -0,0 +1,191 @@
+//! Asynchronous memory pool implementation.
+
+use super::thread_safe::ThreadSafeHandle;
+use super::ThreadSafePool;
+use crate::config::{GrowthStrategy, PoolConfig};
+use crate::error::Result;
+use core::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Handle for allocations from an [`AsyncPool`].
+///
+/// The slot is returned to the pool and the permit released when the handle
+/// is dropped, waking one task waiting in [`AsyncPool::allocate`].
+pub struct AsyncHandle<T: crate::traits::Poolable> {
+    // Dropped before the permit, so a woken waiter always finds a free slot
+    handle: ThreadSafeHandle<T>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl<T: crate::traits::Poolable> Deref for AsyncHandle<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl<T: crate::traits::Poolable> DerefMut for AsyncHandle<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.handle
+    }
+}
+
+/// A thread-safe memory pool whose allocations wait for a free slot.
+///
+/// `AsyncPool` wraps a [`ThreadSafePool`] with a `tokio` semaphore holding
+/// one permit per slot the pool may hand out. [`allocate`](Self::allocate)
+/// first awaits a permit and only then locks the pool, so request handlers
+/// get natural backpressure instead of busy-retrying or failing when the
+/// pool is exhausted. Requires the `async` feature.
+///
+/// The number of permits is the configured `max_capacity`, or the initial
+/// capacity for pools that cannot grow. A pool that may grow without bound
+/// never makes callers wait.
+///
+/// # Deadlocks
+///
+/// A task that holds handles and then awaits another allocation from the
+/// same pool can wait forever if every permit is held by tasks doing the
+/// same. Drop handles before awaiting anything that may itself need a slot
+/// from this pool.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::AsyncPool;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let pool = AsyncPool::new(2).unwrap();
+///
+/// let mut handle = pool.allocate(42).await.unwrap();
+/// *handle += 1;
+/// assert_eq!(*handle, 43);
+/// # });
+/// ```
+pub struct AsyncPool<T> {
+    pool: ThreadSafePool<T>,
+    permits: Arc<Semaphore>,
+}
+
+impl<T: crate::traits::Poolable> AsyncPool<T> {
+    /// Creates a new async pool with the specified capacity.
+    pub fn new(capacity: usize) -> Result<Self> {
+        let config = PoolConfig::builder().capacity(capacity).build()?;
+        Self::with_config(config)
+    }
+
+    /// Creates a new async pool with the specified configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error [`ThreadSafePool::with_config`] would return.
+    pub fn with_config(config: PoolConfig<T>) -> Result<Self> {
+        let permits = match config.max_capacity() {
+            Some(max) => max,
+            None if matches!(config.growth_strategy(), GrowthStrategy::None) => config.capacity(),
+            None => Semaphore::MAX_PERMITS,
+        };
+
+        Ok(Self {
+            pool: ThreadSafePool::with_config(config)?,
+            permits: Arc::new(Semaphore::new(permits.min(Semaphore::MAX_PERMITS))),
+        })
+    }
+
+    /// Allocates an object, waiting until a slot is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error [`ThreadSafePool::allocate`] would return, such as
+    /// a failure to grow the pool.
+    pub async fn allocate(&self, value: T) -> Result<AsyncHandle<T>> {
+        let permit = Arc::clone(&self.permits)
+            .acquire_owned()
+            .await
+            .expect("pool semaphore is never closed");
+
+        let handle = self.pool.allocate(value)?;
+
+        Ok(AsyncHandle {
+            handle,
+            _permit: permit,
+        })
+    }
+
+    /// Allocates an object if a slot is available right now, without waiting.
+    pub fn try_allocate(&self, value: T) -> Option<AsyncHandle<T>> {
+        let permit = Arc::clone(&self.permits).try_acquire_owned().ok()?;
+        let handle = self.pool.allocate(value).ok()?;
+
+        Some(AsyncHandle {
+            handle,
+            _permit: permit,
+        })
+    }
+
+    /// Returns the current capacity of the pool.
+    pub fn capacity(&self) -> usize {
+        self.pool.capacity()
+    }
+
+    /// Returns the number of currently allocated objects.
+    pub fn allocated(&self) -> usize {
+        self.pool.allocated()
+    }
+}
+
+impl<T> Clone for AsyncPool<T> {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            permits: Arc::clone(&self.permits),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn waiter_resolves_when_handle_dropped() {
+        let pool = AsyncPool::new(1).unwrap();
+        let held = pool.allocate(1).await.unwrap();
+        assert!(pool.try_allocate(2).is_none());
+
+        let waiter_pool = pool.clone();
+        let waiter = tokio::spawn(async move { *waiter_pool.allocate(2).await.unwrap() });
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiter.is_finished());
+
+        drop(held);
+        let value = tokio::time::timeout(Duration::from_secs(5), waiter)
+            .await
+            .expect("waiter should resolve after a slot is freed")
+            .unwrap();
+        assert_eq!(value, 2);
+        assert_eq!(pool.allocated(), 0);
+    }
+
+    #[tokio::test]
+    async fn permits_follow_max_capacity() {
+        let config = PoolConfig::builder()
+            .capacity(1)
+            .max_capacity(Some(2))
+            .growth_strategy(GrowthStrategy::Linear { amount: 1 })
+            .build()
+            .unwrap();
+        let pool = AsyncPool::with_config(config).unwrap();
+
+        let _a = pool.allocate(1).await.unwrap();
+        let _b = pool.allocate(2).await.unwrap();
+        assert_eq!(pool.capacity(), 2);
+        assert!(pool.try_allocate(3).is_none());
+    }
+}
@@ -0,0 +1,415 @@
+//! Pool of trait objects with inline storage.
+
+use crate::allocator::{Allocator, StackAllocator};
+use crate::error::{Error, Result};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::{RefCell, UnsafeCell};
+use core::fmt;
+use core::mem::{self, MaybeUninit};
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+
+/// Largest alignment a value may need to be stored inline.
+const INLINE_ALIGN: usize = 16;
+
+/// Inline storage for one slot.
+#[repr(C, align(16))]
+struct InlineBuf<const N: usize>([MaybeUninit<u8>; N]);
+
+/// Where the object of an allocated slot lives.
+enum SlotPtr<D: ?Sized> {
+    /// In the slot's inline buffer
+    Inline(*mut D),
+    /// In a heap allocation, because it did not fit the buffer
+    Heap(*mut D),
+}
+
+impl<D: ?Sized> SlotPtr<D> {
+    #[inline]
+    fn as_ptr(&self) -> *mut D {
+        match *self {
+            SlotPtr::Inline(ptr) | SlotPtr::Heap(ptr) => ptr,
+        }
+    }
+
+    /// Drops the object and frees its heap allocation, if any.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must refer to a live object created by `BoxPool::allocate`.
+    unsafe fn release(self) {
+        match self {
+            SlotPtr::Inline(ptr) => ptr::drop_in_place(ptr),
+            SlotPtr::Heap(ptr) => drop(Box::from_raw(ptr)),
+        }
+    }
+}
+
+/// A pool of trait objects that stores each value inline in an `N`-byte slot.
+///
+/// `FixedPool<Box<dyn Trait>>` still heap-allocates every object. `BoxPool`
+/// instead gives each slot an `N`-byte buffer (aligned to 16 bytes) and
+/// moves the concrete value into it, keeping only a pointer with the
+/// trait's vtable next to it. Values larger than `N` bytes, or needing more
+/// than 16-byte alignment, fall back to a regular `Box`, so every type can
+/// be pooled and [`is_inline`](BoxHandle::is_inline) reports which path was
+/// taken.
+///
+/// Stable Rust cannot convert a generic `C` into `dyn Trait` by itself, so
+/// [`allocate`](Self::allocate) takes the conversion as a function, which is
+/// always the identity closure `|c| c`.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::BoxPool;
+///
+/// trait Command {
+///     fn run(&mut self) -> u32;
+/// }
+///
+/// struct Add(u32, u32);
+/// impl Command for Add {
+///     fn run(&mut self) -> u32 { self.0 + self.1 }
+/// }
+///
+/// struct Count(u32);
+/// impl Command for Count {
+///     fn run(&mut self) -> u32 { self.0 += 1; self.0 }
+/// }
+///
+/// let pool = BoxPool::<dyn Command, 16>::new(8).unwrap();
+///
+/// let mut add = pool.allocate(Add(2, 3), |c| c).unwrap();
+/// let mut count = pool.allocate(Count(0), |c| c).unwrap();
+///
+/// assert_eq!(add.run(), 5);
+/// assert_eq!(count.run() + count.run(), 3);
+/// assert!(add.is_inline());
+/// ```
+pub struct BoxPool<D: ?Sized, const N: usize> {
+    /// Inline buffers; never reallocated, so objects stay in place
+    buffers: Box<[UnsafeCell<InlineBuf<N>>]>,
+    /// Object pointer of each allocated slot
+    slots: RefCell<Vec<Option<SlotPtr<D>>>>,
+    allocator: RefCell<StackAllocator>,
+    capacity: usize,
+}
+
+impl<D: ?Sized, const N: usize> BoxPool<D, N> {
+    /// Creates a pool with `capacity` slots of `N` bytes each.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `capacity` is 0.
+    pub fn new(capacity: usize) -> Result<Self> {
+        if capacity == 0 {
+            return Err(Error::invalid_config("capacity must be at least 1"));
+        }
+
+        let buffers = (0..capacity)
+            .map(|_| UnsafeCell::new(InlineBuf([MaybeUninit::uninit(); N])))
+            .collect();
+        let mut slots = Vec::with_capacity(capacity);
+        slots.resize_with(capacity, || None);
+
+        Ok(Self {
+            buffers,
+            slots: RefCell::new(slots),
+            allocator: RefCell::new(StackAllocator::new(capacity)),
+            capacity,
+        })
+    }
+
+    /// Returns whether a value of type `C` is stored inline rather than on the heap.
+    #[inline]
+    pub const fn fits_inline<C>() -> bool {
+        mem::size_of::<C>() <= N && mem::align_of::<C>() <= INLINE_ALIGN
+    }
+
+    /// Moves `value` into a free slot and returns a handle to it as `D`.
+    ///
+    /// `coerce` converts the concrete reference into the pooled type; pass
+    /// `|c| c` and let the compiler insert the unsizing coercion.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if every slot is in use.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `coerce` returns a reference to anything other than the
+    /// whole value it was given.
+    pub fn allocate<C>(
+        &self,
+        value: C,
+        coerce: fn(&mut C) -> &mut D,
+    ) -> Result<BoxHandle<'_, D, N>> {
+        let index = self
+            .allocator
+            .borrow_mut()
+            .allocate()
+            .ok_or(Error::PoolExhausted {
+                capacity: self.capacity,
+                allocated: self.capacity,
+                can_grow: false,
+                name: None,
+            })?;
+
+        let slot = if Self::fits_inline::<C>() {
+            let target = self.buffers[index].get().cast::<C>();
+            // Safety: the slot is free, and the buffer is large and aligned enough
+            unsafe {
+                target.write(value);
+                SlotPtr::Inline(Self::coerce_checked(target, coerce))
+            }
+        } else {
+            let target = Box::into_raw(Box::new(value));
+            // Safety: the box was just created and is uniquely owned
+            SlotPtr::Heap(unsafe { Self::coerce_checked(target, coerce) })
+        };
+
+        self.slots.borrow_mut()[index] = Some(slot);
+        Ok(BoxHandle { pool: self, index })
+    }
+
+    /// Applies `coerce` and checks that the result is the whole value, with
+    /// the same size and alignment, so it can be dropped and deallocated as a `D`.
+    ///
+    /// # Safety
+    ///
+    /// `target` must point to a live, uniquely accessible `C`.
+    unsafe fn coerce_checked<C>(target: *mut C, coerce: fn(&mut C) -> &mut D) -> *mut D {
+        let object: &mut D = coerce(&mut *target);
+        assert!(
+            ptr::eq(object as *mut D as *const u8, target as *const u8)
+                && mem::size_of_val(object) == mem::size_of::<C>()
+                && mem::align_of_val(object) == mem::align_of::<C>(),
+            "BoxPool::allocate: coerce must return the value it was given"
+        );
+        object
+    }
+
+    /// Returns the total capacity of the pool.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of available (free) slots in the pool.
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.allocator.borrow().available()
+    }
+
+    /// Returns the number of currently allocated objects.
+    #[inline]
+    pub fn allocated(&self) -> usize {
+        self.capacity - self.available()
+    }
+
+    /// Returns the object pointer of an allocated slot.
+    #[inline]
+    fn object_ptr(&self, index: usize) -> *mut D {
+        self.slots.borrow()[index]
+            .as_ref()
+            .expect("handle refers to an allocated slot")
+            .as_ptr()
+    }
+
+    /// Whether the allocated slot at `index` stores its object inline.
+    #[inline]
+    fn is_inline(&self, index: usize) -> bool {
+        matches!(self.slots.borrow()[index], Some(SlotPtr::Inline(_)))
+    }
+
+    /// Drops the object in a slot and frees the slot.
+    fn release(&self, index: usize) {
+        let slot = self.slots.borrow_mut()[index].take();
+        if let Some(slot) = slot {
+            // Safety: the slot held a live object owned by the released handle
+            unsafe { slot.release() };
+        }
+        self.allocator.borrow_mut().free(index);
+    }
+}
+
+impl<D: ?Sized, const N: usize> Drop for BoxPool<D, N> {
+    fn drop(&mut self) {
+        // Drop objects whose handles were leaked
+        for slot in self.slots.get_mut().drain(..).flatten() {
+            // Safety: every remaining slot holds a live object
+            unsafe { slot.release() };
+        }
+    }
+}
+
+/// Handle to an object allocated from a [`BoxPool`].
+///
+/// Dereferences to the pooled trait object and drops it, returning the slot,
+/// when the handle is dropped.
+pub struct BoxHandle<'pool, D: ?Sized, const N: usize> {
+    pool: &'pool BoxPool<D, N>,
+    index: usize,
+}
+
+impl<D: ?Sized, const N: usize> BoxHandle<'_, D, N> {
+    /// Returns the internal index of this handle.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns whether the object lives in the slot's inline buffer.
+    ///
+    /// `false` means it was too large or too strictly aligned and was boxed.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.pool.is_inline(self.index)
+    }
+}
+
+impl<D: ?Sized, const N: usize> Deref for BoxHandle<'_, D, N> {
+    type Target = D;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // Safety: the slot is allocated to this handle and holds a live object
+        unsafe { &*self.pool.object_ptr(self.index) }
+    }
+}
+
+impl<D: ?Sized, const N: usize> DerefMut for BoxHandle<'_, D, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safety: as in `deref`, and `&mut self` guarantees exclusive access
+        unsafe { &mut *self.pool.object_ptr(self.index) }
+    }
+}
+
+impl<D: ?Sized, const N: usize> Drop for BoxHandle<'_, D, N> {
+    fn drop(&mut self) {
+        self.pool.release(self.index);
+    }
+}
+
+impl<D: ?Sized + fmt::Debug, const N: usize> fmt::Debug for BoxHandle<'_, D, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxHandle")
+            .field("index", &self.index)
+            .field("value", &&**self)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::rc::Rc;
+    use alloc::string::String;
+    use core::cell::Cell;
+
+    trait Command {
+        fn execute(&mut self) -> i64;
+    }
+
+    struct Add(i64, i64);
+    impl Command for Add {
+        fn execute(&mut self) -> i64 {
+            self.0 + self.1
+        }
+    }
+
+    struct Accumulate {
+        total: i64,
+        log: String,
+    }
+    impl Command for Accumulate {
+        fn execute(&mut self) -> i64 {
+            self.total += 10;
+            self.log.push('+');
+            self.total
+        }
+    }
+
+    #[test]
+    fn dispatches_through_trait() {
+        let pool = BoxPool::<dyn Command, 48>::new(4).unwrap();
+
+        let mut commands = [
+            pool.allocate(Add(1, 2), |c| c).unwrap(),
+            pool.allocate(
+                Accumulate {
+                    total: 0,
+                    log: String::new(),
+                },
+                |c| c,
+            )
+            .unwrap(),
+        ];
+        assert!(commands.iter().all(|c| c.is_inline()));
+
+        let results: Vec<i64> = commands.iter_mut().map(|c| c.execute()).collect();
+        assert_eq!(results, [3, 10]);
+        assert_eq!(commands[1].execute(), 20);
+        assert_eq!(pool.allocated(), 2);
+
+        drop(commands);
+        assert_eq!(pool.available(), 4);
+    }
+
+    #[test]
+    fn oversized_values_fall_back_to_heap() {
+        struct Big([i64; 8]);
+        impl Command for Big {
+            fn execute(&mut self) -> i64 {
+                self.0.iter().sum()
+            }
+        }
+
+        let pool = BoxPool::<dyn Command, 16>::new(2).unwrap();
+        let mut small = pool.allocate(Add(4, 5), |c| c).unwrap();
+        let mut big = pool.allocate(Big([1; 8]), |c| c).unwrap();
+
+        assert!(small.is_inline());
+        assert!(!big.is_inline());
+        assert_eq!(small.execute() + big.execute(), 17);
+        assert!(pool.allocate(Add(0, 0), |c| c).is_err());
+    }
+
+    #[test]
+    fn objects_are_dropped_on_release_and_pool_drop() {
+        struct Tracked(Rc<Cell<usize>>);
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+        impl Command for Tracked {
+            fn execute(&mut self) -> i64 {
+                0
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let pool = BoxPool::<dyn Command, 16>::new(2).unwrap();
+        drop(pool.allocate(Tracked(Rc::clone(&drops)), |c| c).unwrap());
+        assert_eq!(drops.get(), 1);
+
+        mem::forget(pool.allocate(Tracked(Rc::clone(&drops)), |c| c).unwrap());
+        drop(pool);
+        assert_eq!(drops.get(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "coerce must return the value it was given")]
+    fn coerce_to_less_aligned_field_is_rejected() {
+        #[repr(align(32))]
+        struct Wide([u8; 32]);
+
+        // Same address and size, but the heap box would be freed with the wrong layout
+        let pool = BoxPool::<[u8], 16>::new(1).unwrap();
+        let _ = pool.allocate(Wide([0; 32]), |w| &mut w.0);
+    }
+}
@@ -0,0 +1,157 @@
+//! Keyed cache of pooled objects that expires entries when objects are recycled.
+
+use crate::handle::{SharedHandle, WeakHandle};
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// A map from keys to pooled objects that does not keep the objects alive.
+///
+/// Entries hold a [`WeakHandle`], so caching an object never delays its
+/// return to the pool. Once every [`SharedHandle`] to an object has been
+/// dropped and its slot recycled, [`get`](Self::get) for its key returns
+/// `None` and removes the stale entry.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::{FixedPool, PoolCache};
+///
+/// let pool = FixedPool::new(16).unwrap();
+/// let mut cache = PoolCache::new();
+///
+/// let texture = pool.allocate_shared(String::from("grass.png")).unwrap();
+/// cache.insert("grass", &texture);
+/// assert_eq!(cache.get("grass").as_deref().map(String::as_str), Some("grass.png"));
+///
+/// // The object goes back to the pool, and the entry expires with it
+/// drop(texture);
+/// assert!(cache.get("grass").is_none());
+/// assert!(cache.is_empty());
+/// ```
+pub struct PoolCache<'pool, K, T> {
+    entries: HashMap<K, WeakHandle<'pool, T>>,
+}
+
+impl<'pool, K: Eq + Hash, T> PoolCache<'pool, K, T> {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Caches `handle` under `key`, returning the entry it replaces, if any.
+    ///
+    /// Only a weak reference is stored; the caller keeps `handle`.
+    pub fn insert(
+        &mut self,
+        key: K,
+        handle: &SharedHandle<'pool, T>,
+    ) -> Option<WeakHandle<'pool, T>> {
+        self.entries.insert(key, handle.downgrade())
+    }
+
+    /// Returns a shared handle to the object cached under `key`.
+    ///
+    /// Returns `None` if there is no entry, or if the object has been
+    /// returned to the pool, in which case the entry is evicted.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<SharedHandle<'pool, T>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let handle = self.entries.get(key)?.upgrade();
+        if handle.is_none() {
+            self.entries.remove(key);
+        }
+        handle
+    }
+
+    /// Removes the entry for `key`, returning its weak handle.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<WeakHandle<'pool, T>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries.remove(key)
+    }
+
+    /// Evicts every entry whose object has been returned to the pool.
+    pub fn purge(&mut self) {
+        self.entries.retain(|_, handle| handle.strong_count() > 0);
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the number of entries, including expired ones not evicted yet.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the cache has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<K: Eq + Hash, T> Default for PoolCache<'_, K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, T> fmt::Debug for PoolCache<'_, K, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoolCache")
+            .field("entries", &self.entries.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pool::FixedPool;
+
+    #[test]
+    fn lookup_evicts_recycled_objects() {
+        let pool = FixedPool::new(4).unwrap();
+        let mut cache = PoolCache::new();
+
+        let shared = pool.allocate_shared(7u32).unwrap();
+        let other = shared.clone();
+        assert!(cache.insert(1, &shared).is_none());
+        assert_eq!(cache.get(&1).as_deref(), Some(&7));
+
+        drop(shared);
+        drop(other);
+        assert_eq!(pool.allocated(), 0);
+
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&1).is_none());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn purge_keeps_live_entries() {
+        let pool = FixedPool::new(4).unwrap();
+        let mut cache = PoolCache::new();
+
+        let live = pool.allocate_shared(1u32).unwrap();
+        let dead = pool.allocate_shared(2u32).unwrap();
+        cache.insert("live", &live);
+        cache.insert("dead", &dead);
+        drop(dead);
+
+        cache.purge();
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get("live").as_deref(), Some(&1));
+        assert!(cache.remove("live").is_some());
+        assert!(cache.is_empty());
+    }
+}
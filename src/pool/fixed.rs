@@ -1,14 +1,23 @@
 //! Fixed-size memory pool implementation.
 
-use crate::allocator::{Allocator, StackAllocator};
-use crate::config::PoolConfig;
+use crate::allocator::{Allocator, FreeListAllocator, PoolAllocator, StackAllocator};
+use crate::config::{GrowthStrategy, PoolConfig, PoolEvent, WatermarkState};
 use crate::error::{Error, Result};
-use crate::handle::OwnedHandle;
+use crate::handle::{OwnedHandle, OwnedRcHandle, SharedHandle, SliceHandle, UninitHandle};
+use crate::pool::storage::SlotStorage;
+use crate::pool::{GrowingPool, PoolGuard};
 use crate::traits::Poolable;
+use crate::utils::{secure_zero, validate_alignment};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::cell::RefCell;
 use core::marker::PhantomData;
-use core::mem::MaybeUninit;
+use core::mem::{self, MaybeUninit};
+use core::num::NonZeroUsize;
+#[cfg(debug_assertions)]
+use core::panic::Location;
 use core::ptr;
 
 #[cfg(feature = "stats")]
@@ -40,6 +49,41 @@ use crate::stats::PoolStatistics;
 /// drop(handle);
 /// ```
 ///
+/// # Recycling
+///
+/// When the pool is configured with a reset function (see
+/// [`PoolConfigBuilder::reset_fn`](crate::config::PoolConfigBuilder::reset_fn)),
+/// released objects are reset and kept initialized in their slot instead of
+/// being dropped. [`FixedPool::allocate_recycled`] then hands the recycled
+/// object back out, which preserves heap capacity for types such as `Vec<u8>`.
+///
+/// ```rust
+/// use fastalloc::{FixedPool, PoolConfig};
+///
+/// let config = PoolConfig::builder()
+///     .capacity(4)
+///     .reset_fn(|| Vec::<u8>::with_capacity(1024), |buf| buf.clear())
+///     .build()
+///     .unwrap();
+/// let pool = FixedPool::with_config(config).unwrap();
+///
+/// let mut buf = pool.allocate_recycled().unwrap();
+/// buf.extend_from_slice(b"hello");
+/// drop(buf);
+///
+/// // Same buffer, cleared but with its capacity intact
+/// let buf = pool.allocate_recycled().unwrap();
+/// assert!(buf.is_empty());
+/// assert!(buf.capacity() >= 1024);
+/// ```
+///
+/// # Address stability
+///
+/// The storage is allocated once, when the pool is created, and is never
+/// reallocated, so an object stays at the same address for as long as its
+/// handle lives. Handles can therefore be pinned with
+/// [`OwnedHandle::into_pinned`].
+///
 /// # Performance
 ///
 /// - Allocation: < 20ns per object (typical)
@@ -48,14 +92,24 @@ use crate::stats::PoolStatistics;
 /// - Zero fragmentation
 pub struct FixedPool<T> {
     /// Storage for pool objects
-    storage: RefCell<Vec<MaybeUninit<T>>>,
+    storage: RefCell<SlotStorage<T>>,
     /// Allocator for managing free slots
-    allocator: RefCell<StackAllocator>,
+    allocator: RefCell<PoolAllocator>,
+    /// Free slots that still hold a pre-initialized or reset object waiting to be reused
+    recycled: RefCell<Vec<bool>>,
+    /// Allocated slots that are reserved but not yet initialized
+    pending: RefCell<Vec<usize>>,
+    /// Slots whose object is pinned; empty until a handle is first pinned
+    pinned: RefCell<Vec<bool>>,
     /// Total capacity
     capacity: usize,
-    /// Pool configuration
-    #[allow(dead_code)]
-    config: PoolConfig<T>,
+    /// Pool configuration, shared with clones of the pool
+    config: Arc<PoolConfig<T>>,
+    /// Which side of the utilization watermarks the pool is on
+    watermarks: WatermarkState,
+    /// Where each slot was last allocated, reported if its handle leaks
+    #[cfg(debug_assertions)]
+    allocation_sites: RefCell<Vec<Option<&'static Location<'static>>>>,
     /// Statistics collector
     #[cfg(feature = "stats")]
     stats: RefCell<crate::stats::StatisticsCollector>,
@@ -82,6 +136,57 @@ impl<T: Poolable> FixedPool<T> {
         Self::with_config(config)
     }
 
+    /// Creates a new fixed-size pool from a capacity that is known to be non-zero.
+    ///
+    /// Unlike [`FixedPool::new`] this cannot fail: the default configuration
+    /// always has a valid alignment, and like `Vec::with_capacity` the process
+    /// aborts if the storage cannot be allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::num::NonZeroUsize;
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::<String>::with_nonzero(NonZeroUsize::new(100).unwrap());
+    /// assert_eq!(pool.capacity(), 100);
+    /// ```
+    pub fn with_nonzero(capacity: NonZeroUsize) -> Self {
+        Self::from_config(PoolConfig {
+            capacity: capacity.get(),
+            ..PoolConfig::default()
+        })
+    }
+
+    /// Creates a pool that holds exactly `values`, with every slot allocated.
+    ///
+    /// The capacity is `values.len()` and `values[i]` is stored in slot `i`.
+    /// No handles are returned; the objects stay live and owned by the pool,
+    /// readable through [`get_by_index`](Self::get_by_index), until they are
+    /// claimed with [`from_raw_index`](Self::from_raw_index), drained with
+    /// [`into_iter`](IntoIterator::into_iter), or dropped with the pool.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let mut squares = FixedPool::prefilled((0..8u64).map(|n| n * n).collect()).unwrap();
+    /// assert!(squares.is_full());
+    /// assert_eq!(squares.get_by_index(5), Some(&25));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `values` is empty.
+    pub fn prefilled(values: Vec<T>) -> Result<Self> {
+        let pool = Self::new(values.len())?;
+        for value in values {
+            pool.allocate_index(value)?;
+        }
+        Ok(pool)
+    }
+
     /// Creates a new fixed-size pool with the specified configuration.
     ///
     /// # Examples
@@ -99,23 +204,221 @@ impl<T: Poolable> FixedPool<T> {
     /// let pool = FixedPool::<i32>::with_config(config).unwrap();
     /// ```
     pub fn with_config(config: PoolConfig<T>) -> Result<Self> {
+        Ok(Self::from_config(config))
+    }
+
+    /// Allocates the buffers for an already validated configuration.
+    fn from_config(config: PoolConfig<T>) -> Self {
+        let capacity = config.capacity();
+        let storage = SlotStorage::new(capacity, config.alignment());
+
+        Self::from_parts(
+            config,
+            storage,
+            StackAllocator::new(capacity).into(),
+            alloc::vec![false; capacity],
+        )
+    }
+
+    /// Creates a new fixed-size pool, returning an error instead of aborting
+    /// if the system allocator cannot provide the memory.
+    ///
+    /// [`FixedPool::with_config`] aborts the process on out-of-memory, like
+    /// `Vec::with_capacity`. This constructor reserves every buffer with
+    /// `try_reserve` so memory-constrained targets can recover.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{Error, FixedPool, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder().capacity(usize::MAX / 64).build().unwrap();
+    /// let result = FixedPool::<[u8; 64]>::try_with_config(config);
+    /// assert!(matches!(result, Err(Error::AllocationFailed { .. })));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::AllocationFailed` with the number of bytes requested
+    /// for slot storage if any allocation fails.
+    pub fn try_with_config(config: PoolConfig<T>) -> Result<Self> {
+        let capacity = config.capacity();
+        let bytes = capacity.saturating_mul(mem::size_of::<T>());
+        let failed = |_| Error::AllocationFailed { bytes };
+
+        let storage = SlotStorage::try_new(capacity, config.alignment())
+            .ok_or(Error::AllocationFailed { bytes })?;
+
+        let allocator = StackAllocator::try_new(capacity).map_err(failed)?;
+
+        let mut recycled = Vec::new();
+        recycled.try_reserve_exact(capacity).map_err(failed)?;
+        recycled.resize(capacity, false);
+
+        Ok(Self::from_parts(
+            config,
+            storage,
+            allocator.into(),
+            recycled,
+        ))
+    }
+
+    /// Creates a pool that uses a caller-provided buffer as slot storage.
+    ///
+    /// This places the objects in a specific memory region, such as a DMA
+    /// buffer or shared memory, instead of one the pool allocates. The pool
+    /// never frees the buffer; objects still alive when the pool is dropped
+    /// are dropped in place, and the memory stays with the caller. Allocator
+    /// metadata is still allocated on the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use fastalloc::FixedPool;
+    ///
+    /// let mut buffer = [MaybeUninit::<u32>::uninit(); 16];
+    ///
+    /// // Safety: the buffer outlives the pool and is not used while it exists
+    /// let pool = unsafe { FixedPool::from_raw_parts(buffer.as_mut_ptr(), 16, 4) }.unwrap();
+    /// let handle = pool.allocate(7).unwrap();
+    /// assert_eq!(*handle, 7);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads and writes of `capacity` slots of `T`
+    /// for the whole lifetime of the pool, and the buffer must not be
+    /// accessed through any other pointer while the pool exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `ptr` is null or `capacity`
+    /// is 0, and `Error::InvalidAlignment` if `alignment` is not a power of
+    /// two or `ptr` is not aligned to both `alignment` and `T`.
+    pub unsafe fn from_raw_parts(
+        ptr: *mut MaybeUninit<T>,
+        capacity: usize,
+        alignment: usize,
+    ) -> Result<Self> {
+        let ptr = ptr::NonNull::new(ptr)
+            .ok_or_else(|| Error::invalid_config("from_raw_parts requires a non-null buffer"))?;
+
+        validate_alignment(alignment)?;
+        let required = alignment.max(mem::align_of::<T>());
+        if ptr.as_ptr() as usize % required != 0 {
+            return Err(Error::InvalidAlignment {
+                alignment: required,
+            });
+        }
+
+        let config = PoolConfig::builder()
+            .capacity(capacity)
+            .alignment(alignment)
+            .build()?;
+
+        Ok(Self::from_parts(
+            config,
+            SlotStorage::borrowed(ptr, capacity),
+            StackAllocator::new(capacity).into(),
+            alloc::vec![false; capacity],
+        ))
+    }
+
+    /// Creates a pool whose slots are handed out by a custom allocator.
+    ///
+    /// The allocator decides which free slot each allocation uses, e.g. a
+    /// buddy allocator that keeps runs contiguous, or one that always picks
+    /// the lowest index. The built-in strategies are available boxed from the
+    /// [`allocator`](crate::allocator) module. The pool trusts the indices the
+    /// allocator hands out; see the safety contract on
+    /// [`SlotAllocator`](crate::allocator::SlotAllocator).
+    ///
+    /// A custom allocator only answers single-slot requests, so
+    /// [`allocate_slice`](Self::allocate_slice) always reports exhaustion and
+    /// [`peek_next_index`](Self::peek_next_index) always returns `None`.
+    /// Clones of the pool use the built-in allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{allocator, FixedPool};
+    ///
+    /// let pool = FixedPool::with_allocator(8, allocator::fifo(8)).unwrap();
+    /// let first = pool.allocate(1).unwrap();
+    /// let index = first.index();
+    /// drop(first);
+    ///
+    /// // FIFO reuse: the freed slot goes to the back of the queue
+    /// assert_ne!(pool.allocate(2).unwrap().index(), index);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `capacity` is 0, if the
+    /// allocator manages a different number of slots, or if any of its
+    /// slots is already allocated.
+    pub fn with_allocator(
+        capacity: usize,
+        allocator: Box<dyn crate::allocator::SlotAllocator>,
+    ) -> Result<Self> {
+        if allocator.capacity() != capacity {
+            return Err(Error::invalid_config(
+                "allocator capacity must match the pool capacity",
+            ));
+        }
+        if !allocator.is_empty() {
+            return Err(Error::invalid_config(
+                "allocator must start with every slot free",
+            ));
+        }
+
+        let config = PoolConfig::builder().capacity(capacity).build()?;
+        let storage = SlotStorage::new(capacity, config.alignment());
+
+        Ok(Self::from_parts(
+            config,
+            storage,
+            PoolAllocator::Custom(allocator),
+            alloc::vec![false; capacity],
+        ))
+    }
+
+    /// Assembles a pool from pre-allocated buffers and runs eager initialization.
+    fn from_parts(
+        config: PoolConfig<T>,
+        mut storage: SlotStorage<T>,
+        mut allocator: PoolAllocator,
+        mut recycled: Vec<bool>,
+    ) -> Self {
         let capacity = config.capacity();
+        allocator.set_sorted(config.deterministic());
 
-        // Allocate storage
-        let mut storage = Vec::with_capacity(capacity);
-        storage.resize_with(capacity, MaybeUninit::uninit);
+        // Eagerly construct every slot when an initializer is available
+        if config.pre_initialize() && !config.initialization_strategy.is_lazy() {
+            for (slot, primed) in storage.iter_mut().zip(recycled.iter_mut()) {
+                if let Some(value) = config.initialization_strategy.initialize() {
+                    slot.write(value);
+                    *primed = true;
+                }
+            }
+        }
 
-        let pool = Self {
+        Self {
             storage: RefCell::new(storage),
-            allocator: RefCell::new(StackAllocator::new(capacity)),
+            allocator: RefCell::new(allocator),
+            recycled: RefCell::new(recycled),
+            pending: RefCell::new(Vec::new()),
+            pinned: RefCell::new(Vec::new()),
             capacity,
-            config,
+            config: Arc::new(config),
+            watermarks: WatermarkState::new(),
+            #[cfg(debug_assertions)]
+            allocation_sites: RefCell::new(alloc::vec![None; capacity]),
             #[cfg(feature = "stats")]
             stats: RefCell::new(crate::stats::StatisticsCollector::new(capacity)),
             _marker: PhantomData,
-        };
-
-        Ok(pool)
+        }
     }
 
     /// Allocates an object from the pool with the given initial value.
@@ -134,283 +437,3378 @@ impl<T: Poolable> FixedPool<T> {
     ///
     /// Returns `Error::PoolExhausted` if the pool is at capacity.
     #[inline]
-    pub fn allocate(&self, mut value: T) -> Result<OwnedHandle<'_, T>> {
-        // Try to allocate a slot
+    #[track_caller]
+    #[must_use = "dropping the handle immediately returns the object to the pool"]
+    pub fn allocate(&self, value: T) -> Result<OwnedHandle<'_, T>> {
+        let index = self.allocate_index(value)?;
+        Ok(OwnedHandle::new(self, index))
+    }
+
+    /// Allocates an object and returns a handle that keeps the pool alive.
+    ///
+    /// Unlike [`allocate`](Self::allocate), the returned
+    /// [`OwnedRcHandle`] does not borrow the pool, so it can be stored in
+    /// `'static` structs and outlive the `Rc` it was allocated through.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    /// use std::rc::Rc;
+    ///
+    /// let pool = Rc::new(FixedPool::new(4).unwrap());
+    /// let handle = pool.allocate_owned(7).unwrap();
+    /// drop(pool);
+    ///
+    /// assert_eq!(*handle, 7);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if the pool is at capacity.
+    #[track_caller]
+    pub fn allocate_owned(self: &Rc<Self>, value: T) -> Result<OwnedRcHandle<T>> {
+        let index = self.allocate_index(value)?;
+        #[cfg(debug_assertions)]
+        self.record_allocation_site(index, Location::caller());
+        Ok(OwnedRcHandle::new(Rc::clone(self), index))
+    }
+
+    /// Allocates an object from the pool and returns a reference-counted handle.
+    ///
+    /// The slot is returned to the pool exactly once, when the last
+    /// `SharedHandle` pointing at it is dropped. Weak handles obtained via
+    /// [`SharedHandle::downgrade`] stop upgrading at that point.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(10).unwrap();
+    /// let shared = pool.allocate_shared(42).unwrap();
+    /// let weak = shared.downgrade();
+    ///
+    /// assert_eq!(*weak.upgrade().unwrap(), 42);
+    ///
+    /// drop(shared);
+    /// assert!(weak.upgrade().is_none());
+    /// assert_eq!(pool.allocated(), 0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if the pool is at capacity.
+    pub fn allocate_shared(&self, value: T) -> Result<SharedHandle<'_, T>> {
+        let index = self.allocate_index(value)?;
+        SharedHandle::new(self, index)
+    }
+
+    /// Allocates an object in a slot whose address is aligned to `align`.
+    ///
+    /// The pool's storage starts at the alignment it was built with (see
+    /// [`PoolConfigBuilder::alignment`](crate::config::PoolConfigBuilder::alignment)),
+    /// so whether a slot satisfies a smaller or equal alignment follows from
+    /// its index. This lets one pool serve both ordinary and, say,
+    /// page-aligned allocations. Only free slots at suitable offsets are
+    /// considered, so the request can fail while other slots are free.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{FixedPool, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder().capacity(64).alignment(64).build().unwrap();
+    /// let pool = FixedPool::<[u8; 16]>::with_config(config).unwrap();
+    ///
+    /// let _unaligned = pool.allocate([0; 16]).unwrap();
+    /// let line = pool.allocate_aligned([1; 16], 64).unwrap();
+    /// assert_eq!(line.as_ptr() as usize % 64, 0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidAlignment` if `align` is not a power of two or
+    /// exceeds the pool's alignment, and `Error::PoolExhausted` if no free
+    /// slot is suitably aligned.
+    #[track_caller]
+    pub fn allocate_aligned(&self, value: T, align: usize) -> Result<OwnedHandle<'_, T>> {
+        validate_alignment(align)?;
+        if align > self.config.alignment().max(mem::align_of::<T>()) {
+            return Err(Error::InvalidAlignment { alignment: align });
+        }
+
+        // The base is aligned to `align`, so only the slot's offset matters
+        let size = mem::size_of::<T>();
         let index = self
             .allocator
+            .borrow_mut()
+            .allocate_matching(|index| index.wrapping_mul(size) % align == 0);
+        let index = index.ok_or_else(|| self.exhausted(self.allocated()))?;
+
+        self.fill_slot(index, value);
+        Ok(OwnedHandle::new(self, index))
+    }
+
+    /// Reserves a slot, writes the value into it and returns the slot index.
+    #[inline]
+    fn allocate_index(&self, value: T) -> Result<usize> {
+        let index = self.reserve_slot()?;
+        self.fill_slot(index, value);
+        Ok(index)
+    }
+
+    /// Marks a free slot as allocated without touching its storage.
+    #[inline]
+    fn reserve_slot(&self) -> Result<usize> {
+        self.allocator
             .borrow_mut()
             .allocate()
-            .ok_or(Error::PoolExhausted {
-                capacity: self.capacity,
-                allocated: self.capacity,
-            })?;
+            .ok_or_else(|| self.exhausted(self.capacity))
+    }
+
+    /// Builds the error returned when no slot is free for an allocation.
+    #[inline]
+    fn exhausted(&self, allocated: usize) -> Error {
+        Error::PoolExhausted {
+            capacity: self.capacity,
+            allocated,
+            can_grow: false,
+            name: self.config.name(),
+        }
+    }
 
+    /// Writes `value` into a reserved slot and records the allocation.
+    #[inline]
+    fn fill_slot(&self, index: usize, mut value: T) {
         // Call on_acquire hook before borrowing storage
-        value.on_acquire();
+        self.config.acquire(&mut value);
 
         // Combine storage write and stats update to reduce borrows
         {
             let mut storage = self.storage.borrow_mut();
+            if mem::take(&mut self.recycled.borrow_mut()[index]) {
+                // Safety: recycled slots hold an initialized, reset object
+                unsafe { drop_slot(storage[index].as_mut_ptr(), self.config.zero_on_release()) };
+            }
             storage[index].write(value);
         }
 
+        self.commit_slot(index);
+    }
+
+    /// Records the allocation of a slot that has just been filled.
+    #[inline]
+    fn commit_slot(&self, index: usize) {
         #[cfg(feature = "stats")]
         self.stats.borrow_mut().record_allocation();
 
-        Ok(OwnedHandle::new(self, index))
+        self.notify_allocated(index);
     }
 
-    /// Allocates multiple objects from the pool in a single operation.
+    /// Allocates an object built by a fallible constructor.
     ///
-    /// This is more efficient than multiple individual `allocate` calls
-    /// as it reduces the number of borrow operations.
+    /// A slot is reserved first, then `f` is called. If construction fails
+    /// the slot is released immediately and the construction error is
+    /// returned in the inner `Result`; the slot is also released if `f`
+    /// panics, so a failed constructor never leaves a slot occupied. Nothing
+    /// from the pool is borrowed while `f` runs.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// use fastalloc::FixedPool;
     ///
-    /// let pool = FixedPool::new(100).unwrap();
-    /// let values = vec![1, 2, 3, 4, 5];
-    /// let handles = pool.allocate_batch(values).unwrap();
-    /// assert_eq!(handles.len(), 5);
+    /// let pool = FixedPool::<u16>::new(4).unwrap();
+    ///
+    /// let handle = pool.allocate_try_with(|| "80".parse()).unwrap().unwrap();
+    /// assert_eq!(*handle, 80);
+    ///
+    /// let failed = pool.allocate_try_with(|| "port".parse()).unwrap();
+    /// assert!(failed.is_err());
+    /// assert_eq!(pool.available(), 3);
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns `Error::PoolExhausted` if there aren't enough free slots.
-    pub fn allocate_batch(
+    /// Returns `Error::PoolExhausted` in the outer `Result` if the pool is at
+    /// capacity, in which case `f` is not called.
+    #[track_caller]
+    pub fn allocate_try_with<E, F>(
         &self,
-        values: alloc::vec::Vec<T>,
-    ) -> Result<alloc::vec::Vec<OwnedHandle<'_, T>>> {
-        // Check if we have enough capacity upfront
-        if values.len() > self.available() {
-            return Err(Error::PoolExhausted {
-                capacity: self.capacity,
-                allocated: self.allocated(),
-            });
+        f: F,
+    ) -> Result<core::result::Result<OwnedHandle<'_, T>, E>>
+    where
+        F: FnOnce() -> core::result::Result<T, E>,
+    {
+        /// Releases the reserved slot unless construction succeeds, including on panic.
+        struct Reservation<'a, T: Poolable> {
+            pool: &'a FixedPool<T>,
+            index: usize,
         }
 
-        let mut handles = alloc::vec::Vec::with_capacity(values.len());
-
-        for value in values {
-            // We know these won't fail due to the check above
-            match self.allocate(value) {
-                Ok(handle) => handles.push(handle),
-                Err(e) => {
-                    // This shouldn't happen, but if it does, clean up
-                    drop(handles);
-                    return Err(e);
-                }
+        impl<T: Poolable> Drop for Reservation<'_, T> {
+            fn drop(&mut self) {
+                // The slot was never written, so its recycled state still holds
+                self.pool.release_uninit(self.index);
             }
         }
 
-        Ok(handles)
+        let reservation = Reservation {
+            pool: self,
+            index: self.reserve_slot()?,
+        };
+        // Keep the slot out of live_indices and serialization while `f` runs
+        self.mark_pending(reservation.index);
+
+        let value = match f() {
+            Ok(value) => value,
+            Err(err) => return Ok(Err(err)),
+        };
+
+        let index = reservation.index;
+        mem::forget(reservation);
+        self.clear_pending(index);
+        self.fill_slot(index, value);
+        Ok(Ok(OwnedHandle::new(self, index)))
     }
 
-    /// Attempts to allocate from the pool, returning None if exhausted.
+    /// Reserves a slot without initializing it.
     ///
-    /// This is a convenience method that doesn't return an error.
+    /// The returned [`UninitHandle`] is filled in place and then converted
+    /// into an [`OwnedHandle`], which skips moving a value into the pool and
+    /// the `on_acquire` hooks. If the handle is dropped before it is
+    /// finished, the slot is freed again. A recycled object left in the slot
+    /// is dropped here, since the slot is about to be overwritten. Until the
+    /// handle is finished the slot counts as allocated, but
+    /// [`live_indices`](Self::live_indices), clones and serialization skip it.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```rust
     /// use fastalloc::FixedPool;
     ///
-    /// let pool = FixedPool::new(10).unwrap();
-    /// if let Some(handle) = pool.try_allocate(42) {
-    ///     assert_eq!(*handle, 42);
-    /// };
+    /// let pool = FixedPool::<u64>::new(2).unwrap();
+    /// let handle = pool.allocate_uninit().unwrap().write(5);
+    /// assert_eq!(*handle, 5);
+    ///
+    /// // Abandoning a reservation gives the slot back
+    /// drop(pool.allocate_uninit().unwrap());
+    /// assert_eq!(pool.available(), 1);
     /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if the pool is at capacity.
+    #[track_caller]
+    pub fn allocate_uninit(&self) -> Result<UninitHandle<'_, T>> {
+        let index = self.reserve_slot()?;
+        if mem::take(&mut self.recycled.borrow_mut()[index]) {
+            // Safety: recycled slots hold an initialized, reset object
+            unsafe { drop_slot(self.slot_ptr(index), self.config.zero_on_release()) };
+        }
+        self.mark_pending(index);
+        #[cfg(debug_assertions)]
+        self.record_allocation_site(index, Location::caller());
+        Ok(UninitHandle::new(self, index))
+    }
+
+    /// Frees a slot reserved by `allocate_uninit` that was never initialized.
     #[inline]
-    pub fn try_allocate(&self, value: T) -> Option<OwnedHandle<'_, T>> {
-        self.allocate(value).ok()
+    pub(crate) fn release_uninit(&self, index: usize) {
+        self.clear_pending(index);
+        self.allocator.borrow_mut().free(index);
     }
 
-    /// Returns the total capacity of the pool.
+    /// Records the allocation of a slot reserved by `allocate_uninit` once
+    /// it has been initialized.
     #[inline]
-    pub fn capacity(&self) -> usize {
-        self.capacity
+    pub(crate) fn commit_uninit(&self, index: usize) {
+        self.clear_pending(index);
+        self.commit_slot(index);
     }
 
-    /// Returns the number of available (free) slots in the pool.
+    /// Hides an allocated slot from every reader until it is initialized.
     #[inline]
-    pub fn available(&self) -> usize {
-        self.allocator.borrow().available()
+    fn mark_pending(&self, index: usize) {
+        self.pending.borrow_mut().push(index);
     }
 
-    /// Returns the number of currently allocated objects.
+    /// Makes a pending slot visible again, once initialized or freed.
     #[inline]
-    pub fn allocated(&self) -> usize {
-        self.capacity - self.available()
+    fn clear_pending(&self, index: usize) {
+        let mut pending = self.pending.borrow_mut();
+        if let Some(position) = pending.iter().position(|&i| i == index) {
+            pending.swap_remove(position);
+        }
     }
 
-    /// Returns whether the pool is full (no available slots).
+    /// Returns whether the slot at `index` is allocated but not yet initialized.
     #[inline]
-    pub fn is_full(&self) -> bool {
-        self.allocator.borrow().is_full()
+    fn is_pending(&self, index: usize) -> bool {
+        self.pending.borrow().contains(&index)
     }
 
-    /// Returns whether the pool is empty (all slots available).
+    /// Frees every pending slot, e.g. one whose `UninitHandle` was leaked.
+    /// Such slots were never initialized, so nothing in them is dropped.
+    fn release_pending(&mut self) {
+        let allocator = self.allocator.get_mut();
+        for index in self.pending.get_mut().drain(..) {
+            allocator.free(index);
+        }
+    }
+
+    /// Reports an allocation to the observer and watermarks. Must be called
+    /// with no borrows held.
     #[inline]
-    pub fn is_empty(&self) -> bool {
-        self.allocator.borrow().is_empty()
+    fn notify_allocated(&self, index: usize) {
+        if self.config.has_observer() {
+            let usage = self.allocated();
+            self.config.notify(PoolEvent::Allocated { index, usage });
+        }
+        if self.config.has_watermarks() {
+            let usage = self.allocated();
+            self.watermarks.update(&self.config, usage, self.capacity);
+        }
     }
 
-    /// Gets a reference to an object at the given index.
+    /// Reports a release to the observer and watermarks. Must be called with
+    /// no borrows held.
+    #[inline]
+    fn notify_freed(&self, index: usize) {
+        if self.config.has_observer() {
+            let usage = self.allocated();
+            self.config.notify(PoolEvent::Freed { index, usage });
+        }
+        if self.config.has_watermarks() {
+            let usage = self.allocated();
+            self.watermarks.update(&self.config, usage, self.capacity);
+        }
+    }
+
+    /// Allocates a recycled object from the pool without supplying a new value.
     ///
-    /// # Safety
+    /// If the slot handed out holds an object that was pre-initialized or
+    /// reset on release, that object is reused in place. Otherwise a fresh
+    /// object is created with the configured initializer. Either way `on_acquire` is called before the
+    /// handle is returned.
     ///
-    /// This is internal and should only be called with valid allocated indices.
-    #[inline(always)]
-    pub(crate) fn get(&self, index: usize) -> &T {
-        let storage = self.storage.borrow();
-        // Safety: index is valid and initialized by allocate()
-        // We extend the lifetime beyond the borrow - safe because pool owns the data
-        unsafe {
-            let ptr = storage.as_ptr();
-            &*ptr.add(index).cast::<T>()
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{FixedPool, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(8)
+    ///     .reset_fn(|| String::with_capacity(64), |s| s.clear())
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = FixedPool::with_config(config).unwrap();
+    ///
+    /// let mut s = pool.allocate_recycled().unwrap();
+    /// s.push_str("request");
+    /// assert_eq!(s.as_str(), "request");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if the pool was configured
+    /// without an initializer, and `Error::PoolExhausted` if the pool is at
+    /// capacity.
+    #[track_caller]
+    pub fn allocate_recycled(&self) -> Result<OwnedHandle<'_, T>> {
+        if self.config.initialization_strategy.is_lazy() {
+            return Err(Error::invalid_config(
+                "allocate_recycled requires an initialization strategy with an initializer",
+            ));
+        }
+
+        let index = self
+            .allocator
+            .borrow_mut()
+            .allocate()
+            .ok_or_else(|| self.exhausted(self.capacity))?;
+
+        let recycled = mem::take(&mut self.recycled.borrow_mut()[index]);
+        if !recycled {
+            // Non-lazy strategies always provide an initializer
+            let value = self
+                .config
+                .initialization_strategy
+                .initialize()
+                .expect("non-lazy initialization strategy has an initializer");
+            self.storage.borrow_mut()[index].write(value);
+        }
+
+        self.config.acquire(self.get_mut(index));
+
+        #[cfg(feature = "stats")]
+        self.stats.borrow_mut().record_allocation();
+
+        self.notify_allocated(index);
+
+        Ok(OwnedHandle::new(self, index))
+    }
+
+    /// Re-runs initialization over every free slot of the pool.
+    ///
+    /// Free slots that hold an object are reset with the configured reset
+    /// function, or replaced with a fresh object from the initializer if
+    /// there is no reset function. For pools created with `pre_initialize`,
+    /// free slots whose object was dropped on release are filled again, so
+    /// the whole free capacity holds ready-to-use objects afterwards.
+    ///
+    /// Pools without an initializer are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{FixedPool, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(4)
+    ///     .pre_initialize(true)
+    ///     .initializer(|| vec![0u8; 16])
+    ///     .build()
+    ///     .unwrap();
+    /// let mut pool = FixedPool::with_config(config).unwrap();
+    ///
+    /// {
+    ///     let mut buf = pool.allocate_recycled().unwrap();
+    ///     buf.clear();
+    /// }
+    ///
+    /// // Between phases, bring every free slot back to its initial state
+    /// pool.reset_all();
+    /// assert_eq!(pool.allocate_recycled().unwrap().len(), 16);
+    /// ```
+    pub fn reset_all(&mut self) {
+        let strategy = &self.config.initialization_strategy;
+        if strategy.is_lazy() {
+            return;
+        }
+
+        let storage = self.storage.get_mut();
+        let recycled = self.recycled.get_mut();
+        let allocator = self.allocator.get_mut();
+
+        for index in (0..self.capacity).filter(|&index| !allocator.is_allocated(index)) {
+            let slot = &mut storage[index];
+            if recycled[index] {
+                if strategy.has_reset() {
+                    // Safety: recycled slots hold an initialized object
+                    strategy.reset(unsafe { &mut *slot.as_mut_ptr() });
+                    continue;
+                }
+                // Safety: as above; the value is replaced right after
+                unsafe { drop_slot(slot.as_mut_ptr(), self.config.zero_on_release()) };
+                recycled[index] = false;
+            } else if !self.config.pre_initialize() {
+                continue;
+            }
+
+            if let Some(value) = strategy.initialize() {
+                slot.write(value);
+                recycled[index] = true;
+            }
+        }
+    }
+
+    /// Frees every live object for which `f` returns `false`.
+    ///
+    /// Culled objects go through the same release path as a dropped
+    /// handle: `on_release` runs, then the value is dropped (or reset, for
+    /// recycling pools) and its slot freed. Surviving objects keep their
+    /// slot index.
+    ///
+    /// Taking `&mut self` guarantees no borrowed handles are outstanding, so
+    /// the live objects are those whose handles were parked with
+    /// [`OwnedHandle::into_raw`]. Indices of culled objects no longer refer
+    /// to them and must not be passed to
+    /// [`from_raw_index`](Self::from_raw_index); use
+    /// [`get_by_index`](Self::get_by_index) to tell which indices survived.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let mut pool = FixedPool::new(8).unwrap();
+    /// let particles: Vec<usize> = (0..6)
+    ///     .map(|life| pool.allocate(life).unwrap().into_raw())
+    ///     .collect();
+    ///
+    /// // Cull particles that have run out of life
+    /// pool.retain(|&life| life > 2);
+    /// assert_eq!(pool.allocated(), 3);
+    ///
+    /// let alive: Vec<_> = particles
+    ///     .into_iter()
+    ///     .filter(|&index| pool.get_by_index(index).is_some())
+    ///     .collect();
+    /// assert_eq!(alive.len(), 3);
+    /// # for index in alive { drop(unsafe { pool.from_raw_index(index) }); }
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let culled: Vec<usize> = self
+            .live_indices()
+            .filter(|&index| !f(self.get(index)))
+            .collect();
+
+        for index in culled {
+            self.return_to_pool(index);
+        }
+    }
+
+    /// Returns whether released objects are reset and kept for reuse.
+    #[inline]
+    fn recycles(&self) -> bool {
+        self.config.initialization_strategy.has_reset()
+    }
+
+    /// Allocates multiple objects from the pool in a single operation.
+    ///
+    /// This is more efficient than multiple individual `allocate` calls
+    /// as it reduces the number of borrow operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(100).unwrap();
+    /// let values = vec![1, 2, 3, 4, 5];
+    /// let handles = pool.allocate_batch(values).unwrap();
+    /// assert_eq!(handles.len(), 5);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if there aren't enough free slots.
+    #[track_caller]
+    pub fn allocate_batch(
+        &self,
+        values: alloc::vec::Vec<T>,
+    ) -> Result<alloc::vec::Vec<OwnedHandle<'_, T>>> {
+        // Check if we have enough capacity upfront
+        if values.len() > self.available() {
+            return Err(self.exhausted(self.allocated()));
+        }
+
+        let mut handles = alloc::vec::Vec::with_capacity(values.len());
+
+        for value in values {
+            // We know these won't fail due to the check above
+            match self.allocate(value) {
+                Ok(handle) => handles.push(handle),
+                Err(e) => {
+                    // This shouldn't happen, but if it does, clean up
+                    drop(handles);
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(handles)
+    }
+
+    /// Allocates one object per item of `iter` without collecting the values first.
+    ///
+    /// The iterator's length is checked against the free slots up front and
+    /// every slot is reserved in a single allocator operation, so values can
+    /// be produced lazily without an intermediate `Vec` or per-item capacity
+    /// checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(100).unwrap();
+    /// let handles = pool.allocate_batch_iter((0..50).map(|i| i * 2)).unwrap();
+    /// assert_eq!(handles.len(), 50);
+    /// assert_eq!(*handles[10], 20);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if `iter.len()` exceeds the free slots.
+    /// If the iterator yields fewer items than it reported, every slot
+    /// reserved for the batch is released again and `Error::Custom` is
+    /// returned.
+    #[track_caller]
+    pub fn allocate_batch_iter<I>(&self, iter: I) -> Result<alloc::vec::Vec<OwnedHandle<'_, T>>>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut iter = iter.into_iter();
+        let len = iter.len();
+
+        let indices = self.allocator.borrow_mut().allocate_many(len);
+        let indices = indices.ok_or_else(|| self.exhausted(self.allocated()))?;
+
+        let mut handles = alloc::vec::Vec::with_capacity(len);
+        for (filled, &index) in indices.iter().enumerate() {
+            let Some(value) = iter.next() else {
+                // Slots already filled are released by dropping their handles
+                self.allocator.borrow_mut().free_many(&indices[filled..]);
+                drop(handles);
+                return Err(Error::custom(
+                    "iterator yielded fewer items than its reported length",
+                ));
+            };
+
+            self.fill_slot(index, value);
+            handles.push(OwnedHandle::new(self, index));
+        }
+
+        Ok(handles)
+    }
+
+    /// Returns the length of the longest run of consecutive free slots.
+    ///
+    /// This is the largest slice [`allocate_slice`](Self::allocate_slice)
+    /// can currently hand out, so batch work can be sized to fit before
+    /// attempting the allocation. A completely free pool returns its capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(8).unwrap();
+    /// let handles: Vec<_> = (0..8).map(|i| pool.allocate(i).unwrap()).collect();
+    /// drop(handles);
+    /// assert_eq!(pool.available_contiguous(), 8);
+    /// ```
+    pub fn available_contiguous(&self) -> usize {
+        self.allocator.borrow().longest_free_run()
+    }
+
+    /// Allocates a contiguous run of slots holding `values`, in order.
+    ///
+    /// The returned handle dereferences to `&mut [T]`, which is useful for
+    /// batch and SIMD processing, and frees the whole run when dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(64).unwrap();
+    /// let mut lanes = pool.allocate_slice(vec![1u32, 2, 3, 4]).unwrap();
+    /// lanes.iter_mut().for_each(|x| *x *= 10);
+    /// assert_eq!(&lanes[..], &[10, 20, 30, 40]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if there is no run of free slots long
+    /// enough, even when the total number of free slots would suffice.
+    pub fn allocate_slice(&self, values: alloc::vec::Vec<T>) -> Result<SliceHandle<'_, T>> {
+        let len = values.len();
+        if len == 0 {
+            return Ok(SliceHandle::new(self, 0, 0));
+        }
+
+        let start = self.allocator.borrow_mut().allocate_run(len);
+        let start = start.ok_or_else(|| self.exhausted(self.allocated()))?;
+
+        {
+            let mut storage = self.storage.borrow_mut();
+            let mut recycled = self.recycled.borrow_mut();
+            for (index, mut value) in (start..).zip(values) {
+                self.config.acquire(&mut value);
+                if mem::take(&mut recycled[index]) {
+                    // Safety: recycled slots hold an initialized, reset object
+                    unsafe {
+                        drop_slot(storage[index].as_mut_ptr(), self.config.zero_on_release())
+                    };
+                }
+                storage[index].write(value);
+            }
+        }
+
+        #[cfg(feature = "stats")]
+        {
+            let mut stats = self.stats.borrow_mut();
+            for _ in 0..len {
+                stats.record_allocation();
+            }
+        }
+
+        for index in start..start + len {
+            self.notify_allocated(index);
+        }
+
+        Ok(SliceHandle::new(self, start, len))
+    }
+
+    /// Returns a batch of handles to the pool in a single operation.
+    ///
+    /// This is cheaper than dropping the handles one by one: storage and the
+    /// allocator are each borrowed once for the whole batch, and the freed
+    /// slots are pushed back onto the free stack together. Each object still
+    /// goes through `on_release` exactly as it would on drop.
+    ///
+    /// Handles that were allocated from a different pool are simply dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(100).unwrap();
+    /// let handles = pool.allocate_batch((0..100).collect()).unwrap();
+    /// assert!(pool.is_full());
+    ///
+    /// pool.free_batch(handles);
+    /// assert!(pool.is_empty());
+    /// ```
+    pub fn free_batch(&self, handles: alloc::vec::Vec<OwnedHandle<'_, T>>) {
+        let mut freed = alloc::vec::Vec::with_capacity(handles.len());
+
+        {
+            let mut storage = self.storage.borrow_mut();
+            for handle in handles {
+                if !handle.is_from(self) {
+                    drop(handle);
+                    continue;
+                }
+
+                let index = handle.index();
+                // The slot is released below; the handle must not release it again
+                mem::forget(handle);
+
+                // Safety: the handle owned this initialized slot
+                unsafe { self.release_slot(&mut storage, index) };
+                freed.push(index);
+            }
+        }
+
+        self.allocator.borrow_mut().free_many(&freed);
+
+        #[cfg(feature = "stats")]
+        {
+            let mut stats = self.stats.borrow_mut();
+            for _ in 0..freed.len() {
+                stats.record_deallocation();
+            }
+        }
+
+        for &index in &freed {
+            self.notify_freed(index);
+        }
+    }
+
+    /// Exchanges the objects held by two handles from this pool.
+    ///
+    /// The values move between the slots and each handle keeps its index,
+    /// as with [`mem::swap`] on the two objects. No hooks run, since both
+    /// objects stay allocated. Useful for sorting pooled objects in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(4).unwrap();
+    /// let mut a = pool.allocate(String::from("a")).unwrap();
+    /// let mut b = pool.allocate(String::from("b")).unwrap();
+    /// let (index_a, index_b) = (a.index(), b.index());
+    ///
+    /// pool.swap(&mut a, &mut b);
+    /// assert_eq!((a.as_str(), b.as_str()), ("b", "a"));
+    /// assert_eq!((a.index(), b.index()), (index_a, index_b));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if either handle was allocated from a different pool.
+    pub fn swap(&self, a: &mut OwnedHandle<'_, T>, b: &mut OwnedHandle<'_, T>) {
+        assert!(
+            a.is_from(self) && b.is_from(self),
+            "handle belongs to a different pool"
+        );
+
+        // Take both pointers from a single borrow so neither reference outlives it
+        let storage = self.storage.borrow_mut();
+        let base = storage.as_mut_ptr();
+        // Safety: each handle owns an allocated, initialized slot of this
+        // pool, and `ptr::swap` allows the two slots to be the same
+        unsafe { ptr::swap(base.add(a.index()), base.add(b.index())) };
+    }
+
+    /// Moves the value out of a handle's slot and frees the slot.
+    ///
+    /// Unlike dropping the handle, this hands ownership of the object to the
+    /// caller. The `on_release` hook is not called, since the object leaves
+    /// the pool rather than being returned to it, and the slot is not
+    /// recycled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(4).unwrap();
+    /// let handle = pool.allocate(String::from("hello")).unwrap();
+    ///
+    /// let value = pool.take(handle);
+    /// assert_eq!(value, "hello");
+    /// assert_eq!(pool.available(), 4);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle was allocated from a different pool.
+    pub fn take(&self, handle: OwnedHandle<'_, T>) -> T {
+        assert!(handle.is_from(self), "handle belongs to a different pool");
+
+        let index = handle.index();
+        // The slot is freed below; the handle must not release it again
+        mem::forget(handle);
+
+        let value = {
+            let mut storage = self.storage.borrow_mut();
+            let value_ptr = storage[index].as_mut_ptr();
+            // Safety: the handle owned this initialized slot, and the slot is
+            // freed without dropping so the value is moved out exactly once
+            unsafe {
+                let value = ptr::read(value_ptr);
+                if self.config.zero_on_release() {
+                    secure_zero(value_ptr.cast::<u8>(), mem::size_of::<T>());
+                }
+                value
+            }
+        };
+
+        self.allocator.borrow_mut().free(index);
+
+        #[cfg(feature = "stats")]
+        self.stats.borrow_mut().record_deallocation();
+
+        self.notify_freed(index);
+
+        value
+    }
+
+    /// Moves an object from another pool into this one.
+    ///
+    /// This is the canonical way to hand an object between pools, e.g. from
+    /// one worker's pool to another's, without a round trip through the
+    /// heap: the value is moved out of its slot in `from` (see
+    /// [`take`](Self::take)), the source slot is freed, and the value is
+    /// allocated here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastalloc::FixedPool;
+    ///
+    /// let source = FixedPool::new(4).unwrap();
+    /// let target = FixedPool::new(4).unwrap();
+    ///
+    /// let handle = source.allocate(String::from("job")).unwrap();
+    /// let moved = target.transfer_in(handle, &source).unwrap();
+    ///
+    /// assert_eq!(*moved, "job");
+    /// assert_eq!(source.allocated(), 0);
+    /// assert_eq!(target.allocated(), 1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if this pool is full. The handle is
+    /// then dropped, so the object is released back into `from`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `handle` was not allocated from `from`.
+    pub fn transfer_in(
+        &self,
+        handle: OwnedHandle<'_, T>,
+        from: &FixedPool<T>,
+    ) -> Result<OwnedHandle<'_, T>> {
+        if self.is_full() {
+            return Err(self.exhausted(self.capacity));
+        }
+
+        let value = from.take(handle);
+        self.allocate(value)
+    }
+
+    /// Returns a guard that checks the pool is empty when it goes out of scope.
+    ///
+    /// See [`PoolGuard`] for details. This is meant for catching handle
+    /// leaks in tests.
+    pub fn guard(&self) -> PoolGuard<'_, T> {
+        PoolGuard::new(self)
+    }
+
+    /// Attempts to allocate from the pool, returning None if exhausted.
+    ///
+    /// This is a convenience method that doesn't return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(10).unwrap();
+    /// if let Some(handle) = pool.try_allocate(42) {
+    ///     assert_eq!(*handle, 42);
+    /// };
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[must_use = "dropping the handle immediately returns the object to the pool"]
+    pub fn try_allocate(&self, value: T) -> Option<OwnedHandle<'_, T>> {
+        self.allocate(value).ok()
+    }
+
+    /// Returns the total capacity of the pool.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of available (free) slots in the pool.
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.allocator.borrow().available()
+    }
+
+    /// Returns the number of currently allocated objects.
+    #[inline]
+    pub fn allocated(&self) -> usize {
+        self.capacity - self.available()
+    }
+
+    /// Returns whether the pool is full (no available slots).
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.allocator.borrow().is_full()
+    }
+
+    /// Returns whether the pool is empty (all slots available).
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.allocator.borrow().is_empty()
+    }
+
+    /// Returns the number of heap bytes the pool currently holds.
+    ///
+    /// This is the live size of the slot storage plus the allocator's and
+    /// the pool's own bookkeeping, as opposed to the estimate
+    /// `capacity() * size_of::<T>()`. Storage passed in through
+    /// [`from_raw_parts`](Self::from_raw_parts) belongs to the caller and is
+    /// not counted, nor is memory owned by the pooled objects themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::<[u8; 64]>::new(16).unwrap();
+    /// assert!(pool.memory_footprint() >= 16 * 64);
+    /// ```
+    pub fn memory_footprint(&self) -> usize {
+        #[cfg(debug_assertions)]
+        let sites = self.allocation_sites.borrow().capacity()
+            * mem::size_of::<Option<&'static Location<'static>>>();
+        #[cfg(not(debug_assertions))]
+        let sites = 0;
+
+        self.storage.borrow().heap_bytes()
+            + self.allocator.borrow().heap_bytes()
+            + self.recycled.borrow().capacity() * mem::size_of::<bool>()
+            + self.pending.borrow().capacity() * mem::size_of::<usize>()
+            + self.pinned.borrow().capacity() * mem::size_of::<bool>()
+            + sites
+    }
+
+    /// Returns the number of storage bytes occupied by live objects.
+    ///
+    /// This is `allocated()` times the slot stride. Slots are laid out back
+    /// to back like an array, so the stride is `size_of::<T>()`, which
+    /// already includes the padding `T`'s own alignment needs; the pool's
+    /// configured alignment only applies to the start of the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// #[repr(align(64))]
+    /// struct Line(u8);
+    /// impl fastalloc::Poolable for Line {}
+    ///
+    /// let pool = FixedPool::new(8).unwrap();
+    /// let line = pool.allocate(Line(1)).unwrap();
+    ///
+    /// assert_eq!(pool.allocated_bytes(), 64);
+    /// assert_eq!(pool.reserved_bytes(), 8 * 64);
+    /// # drop(line);
+    /// ```
+    #[inline]
+    pub fn allocated_bytes(&self) -> usize {
+        self.allocated() * mem::size_of::<T>()
+    }
+
+    /// Returns the number of storage bytes reserved for all slots, live or free.
+    ///
+    /// This is `capacity()` times the slot stride, as described for
+    /// [`allocated_bytes`](Self::allocated_bytes). Unlike
+    /// [`memory_footprint`](Self::memory_footprint) it leaves out the
+    /// allocator's bookkeeping, and it counts storage passed in through
+    /// [`from_raw_parts`](Self::from_raw_parts) too.
+    #[inline]
+    pub fn reserved_bytes(&self) -> usize {
+        self.capacity * mem::size_of::<T>()
+    }
+
+    /// Returns whether utilization (`allocated() / capacity()`) is above `threshold`.
+    ///
+    /// `threshold` is a fraction between 0.0 and 1.0. This is a cheap check
+    /// for load shedding, such as rejecting new requests before the pool is
+    /// actually exhausted, and does not need the `stats` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(10).unwrap();
+    /// let handles: Vec<_> = (0..9).map(|i| pool.allocate(i).unwrap()).collect();
+    ///
+    /// assert!(pool.is_under_pressure(0.8));
+    /// assert!(!pool.is_under_pressure(0.9));
+    /// # drop(handles);
+    /// ```
+    #[inline]
+    pub fn is_under_pressure(&self, threshold: f64) -> bool {
+        self.allocated() as f64 / self.capacity as f64 > threshold
+    }
+
+    /// Returns the slot index the next call to `allocate` would use, without allocating.
+    ///
+    /// Returns `None` if the pool is full. Combined with the `deterministic`
+    /// builder flag this is always the lowest free index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastalloc::{FixedPool, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder().capacity(4).deterministic(true).build().unwrap();
+    /// let pool = FixedPool::with_config(config).unwrap();
+    ///
+    /// let a = pool.allocate(1).unwrap();
+    /// let b = pool.allocate(2).unwrap();
+    /// drop(b);
+    /// drop(a);
+    /// assert_eq!(pool.peek_next_index(), Some(0));
+    /// ```
+    #[inline]
+    pub fn peek_next_index(&self) -> Option<usize> {
+        self.allocator.borrow().peek()
+    }
+
+    /// Returns an iterator over the indices of all allocated slots, in ascending order.
+    ///
+    /// Together with [`get_by_index`](Self::get_by_index) this allows scanning
+    /// every live object in storage order without going through handles. The
+    /// occupancy bitmap is read one 64-slot word at a time, so allocations and
+    /// releases made while iterating are only seen in words not yet reached.
+    /// Slots reserved with [`allocate_uninit`](Self::allocate_uninit) are
+    /// skipped until they are initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fastalloc::FixedPool;
+    ///
+    /// let mut pool = FixedPool::new(8).unwrap();
+    /// let a = pool.allocate(1).unwrap().into_raw();
+    /// let b = pool.allocate(2).unwrap();
+    /// let c = pool.allocate(3).unwrap().into_raw();
+    /// drop(b);
+    ///
+    /// let live: Vec<usize> = pool.live_indices().collect();
+    /// assert_eq!(live, [a, c]);
+    ///
+    /// let sum: i32 = live
+    ///     .into_iter()
+    ///     .filter_map(|index| pool.get_by_index(index).copied())
+    ///     .sum();
+    /// assert_eq!(sum, 4);
+    /// ```
+    pub fn live_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        let words = (self.capacity + 63) / 64;
+        (0..words)
+            .flat_map(move |word| {
+                let mut bits = self.allocator.borrow().occupancy_word(word);
+                core::iter::from_fn(move || {
+                    if bits == 0 {
+                        return None;
+                    }
+                    let bit = bits.trailing_zeros() as usize;
+                    bits &= bits - 1;
+                    Some(word * 64 + bit)
+                })
+            })
+            .filter(move |&index| !self.is_pending(index))
+    }
+
+    /// Returns a reference to the object in slot `index`, or `None` if the slot is free.
+    ///
+    /// Out-of-range indices also return `None`. Taking `&mut self`
+    /// guarantees no borrowed handles are outstanding, so the only live
+    /// objects are those whose handles were parked with
+    /// [`OwnedHandle::into_raw`] and nothing can mutate the object while
+    /// the reference is held.
+    #[inline]
+    pub fn get_by_index(&mut self, index: usize) -> Option<&T> {
+        if self.is_live(index) {
+            Some(self.get(index))
+        } else {
+            None
+        }
+    }
+
+    /// Re-wraps a slot index returned by [`OwnedHandle::into_raw`] into a handle.
+    ///
+    /// Together with `into_raw` this parks a handle as a plain `usize`, e.g.
+    /// in a large side table, and later turns it back into a handle that
+    /// frees the slot when dropped.
+    ///
+    /// # Safety
+    ///
+    /// `index` must have been returned by `into_raw` on a handle from this
+    /// pool, and must be re-wrapped at most once. Otherwise two handles would
+    /// own the same slot and the object would be released twice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range or the slot is not allocated.
+    #[track_caller]
+    pub unsafe fn from_raw_index(&self, index: usize) -> OwnedHandle<'_, T> {
+        assert!(
+            self.is_live(index),
+            "index does not refer to an allocated slot"
+        );
+        OwnedHandle::new(self, index)
+    }
+
+    /// Returns whether `index` is in range and its slot is allocated.
+    #[inline]
+    pub(crate) fn is_live(&self, index: usize) -> bool {
+        index < self.capacity
+            && self.allocator.borrow().is_allocated(index)
+            && !self.is_pending(index)
+    }
+
+    /// Gets a reference to an object at the given index.
+    ///
+    /// # Safety
+    ///
+    /// This is internal and should only be called with valid allocated indices.
+    #[inline(always)]
+    pub(crate) fn get(&self, index: usize) -> &T {
+        let storage = self.storage.borrow();
+        // Safety: index is valid and initialized by allocate()
+        // We extend the lifetime beyond the borrow - safe because pool owns the data
+        unsafe {
+            let ptr = storage.as_ptr();
+            &*ptr.add(index).cast::<T>()
+        }
+    }
+
+    /// Gets a mutable reference to an object at the given index.
+    ///
+    /// # Safety
+    ///
+    /// This is internal and should only be called with valid allocated indices.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) fn get_mut(&self, index: usize) -> &mut T {
+        let storage = self.storage.borrow_mut();
+        // Safety: index is valid and initialized by allocate()
+        // We extend the lifetime beyond the borrow - safe because pool owns the data
+        unsafe {
+            let ptr = storage.as_ptr() as *mut MaybeUninit<T>;
+            &mut *ptr.add(index).cast::<T>()
+        }
+    }
+
+    /// Returns a pointer to the object in the slot at `index`.
+    ///
+    /// Slots are stored contiguously, so consecutive indices yield adjacent objects.
+    #[inline]
+    pub(crate) fn slot_ptr(&self, index: usize) -> *mut T {
+        let storage = self.storage.borrow();
+        // Safety: index is within the storage allocation, which never moves
+        unsafe {
+            (storage.as_ptr() as *mut MaybeUninit<T>)
+                .add(index)
+                .cast::<T>()
+        }
+    }
+
+    /// Returns a run of slots to the pool (called by `SliceHandle` Drop).
+    pub(crate) fn return_run(&self, start: usize, len: usize) {
+        let freed: Vec<usize> = (start..start + len).collect();
+
+        {
+            let mut storage = self.storage.borrow_mut();
+            for &index in &freed {
+                // Safety: the run was allocated and initialized by allocate_slice
+                unsafe { self.release_slot(&mut storage, index) };
+            }
+        }
+
+        self.allocator.borrow_mut().free_many(&freed);
+
+        #[cfg(feature = "stats")]
+        {
+            let mut stats = self.stats.borrow_mut();
+            for _ in 0..len {
+                stats.record_deallocation();
+            }
+        }
+
+        for &index in &freed {
+            self.notify_freed(index);
+        }
+    }
+
+    /// Remembers `site` as the allocation site of slot `index`.
+    #[cfg(debug_assertions)]
+    pub(crate) fn record_allocation_site(&self, index: usize, site: &'static Location<'static>) {
+        self.allocation_sites.borrow_mut()[index] = Some(site);
+    }
+
+    /// Returns an object to the pool (called by handle Drop).
+    ///
+    /// # Safety
+    ///
+    /// This is internal and should only be called once per allocation.
+    pub(crate) fn return_to_pool(&self, index: usize) {
+        {
+            // Get the value and call on_release
+            let mut storage = self.storage.borrow_mut();
+
+            // Safety: index is valid and was initialized
+            unsafe { self.release_slot(&mut storage, index) };
+        }
+
+        // Mark the slot as free
+        self.allocator.borrow_mut().free(index);
+
+        #[cfg(feature = "stats")]
+        self.stats.borrow_mut().record_deallocation();
+
+        self.notify_freed(index);
+    }
+
+    /// Runs the release hooks on a slot and either recycles or drops its value.
+    ///
+    /// Pinned objects are always dropped: reusing one would hand it out
+    /// unpinned without it ever having been dropped.
+    ///
+    /// # Safety
+    ///
+    /// `index` must refer to an allocated, initialized slot. The caller is
+    /// responsible for freeing the slot in the allocator afterwards.
+    unsafe fn release_slot(&self, storage: &mut [MaybeUninit<T>], index: usize) {
+        let value_ptr = storage[index].as_mut_ptr();
+        (*value_ptr).on_release();
+
+        let pinned = self.unpin(index);
+        if self.recycles() && !pinned {
+            // Keep the object alive in its slot for the next allocation
+            self.config.initialization_strategy.reset(&mut *value_ptr);
+            self.recycled.borrow_mut()[index] = true;
+        } else {
+            drop_slot(value_ptr, self.config.zero_on_release());
+        }
+    }
+
+    /// Records that the object in slot `index` has been pinned.
+    pub(crate) fn mark_pinned(&self, index: usize) {
+        let mut pinned = self.pinned.borrow_mut();
+        if pinned.is_empty() {
+            pinned.resize(self.capacity, false);
+        }
+        pinned[index] = true;
+    }
+
+    /// Clears the pinned mark of slot `index`, returning whether it was set.
+    #[inline]
+    fn unpin(&self, index: usize) -> bool {
+        self.pinned
+            .borrow_mut()
+            .get_mut(index)
+            .is_some_and(mem::take)
+    }
+
+    /// Drops the objects of leaked pinned handles in place and frees their
+    /// slots, so that consumers moving objects out never move a pinned one.
+    fn drop_pinned(&mut self) {
+        let zero = self.config.zero_on_release();
+        let storage = self.storage.get_mut();
+        let allocator = self.allocator.get_mut();
+        for (index, pinned) in self.pinned.get_mut().iter_mut().enumerate() {
+            if mem::take(pinned) && allocator.is_allocated(index) {
+                // Safety: the slot holds the initialized object of a leaked
+                // handle, which nothing else can reach anymore
+                unsafe { drop_slot(storage[index].as_mut_ptr(), zero) };
+                allocator.free(index);
+            }
+        }
+    }
+
+    /// Converts the pool into a [`GrowingPool`] that grows with `strategy`.
+    ///
+    /// The slots are moved into the growing pool's first chunk, whose size
+    /// is the current capacity, and occupancy carries over: objects whose
+    /// handles were leaked stay allocated at the same index. Free slots
+    /// holding a recycled or pre-initialized object have it dropped, since
+    /// growing pools do not recycle objects. Objects of leaked pinned handles
+    /// cannot be moved, so they are dropped in place and their slots freed.
+    /// The rest of the configuration
+    /// is kept, and statistics start afresh.
+    ///
+    /// If clones of the pool still share its configuration, closures such as
+    /// the observer or reset function cannot be carried over and are
+    /// dropped, as with [`PoolConfig::clone_settings`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{FixedPool, GrowthStrategy};
+    ///
+    /// let pool = FixedPool::<u32>::new(2).unwrap();
+    /// let pool = pool.into_growing(GrowthStrategy::Linear { amount: 2 });
+    ///
+    /// let handles: Vec<_> = (0..3).map(|i| pool.allocate(i).unwrap()).collect();
+    /// assert_eq!(pool.capacity(), 4);
+    /// # drop(handles);
+    /// ```
+    pub fn into_growing(self, strategy: GrowthStrategy) -> GrowingPool<T> {
+        let capacity = self.capacity;
+        let (mut storage, allocator, recycled, config) = self.into_parts();
+
+        let mut chunk = Vec::with_capacity(capacity);
+        for (index, slot) in storage.iter_mut().enumerate() {
+            if allocator.is_allocated(index) {
+                // Safety: allocated slots hold an initialized object, which
+                // is moved out here and never touched again through `storage`
+                chunk.push(MaybeUninit::new(unsafe { slot.assume_init_read() }));
+            } else {
+                if recycled[index] {
+                    // Safety: recycled slots hold an initialized object owned by the pool
+                    unsafe { drop_slot(slot.as_mut_ptr(), config.zero_on_release()) };
+                }
+                chunk.push(MaybeUninit::uninit());
+            }
+        }
+
+        let allocator =
+            FreeListAllocator::with_occupied(capacity, |index| allocator.is_allocated(index));
+
+        let mut config = Arc::try_unwrap(config).unwrap_or_else(|shared| {
+            shared
+                .clone_settings()
+                .build()
+                .expect("settings of a live pool are valid")
+        });
+        config.growth_strategy = strategy;
+
+        GrowingPool::from_parts(config, chunk, allocator)
+    }
+
+    /// Takes the pool apart without dropping any objects still in its slots.
+    ///
+    /// Statistics are discarded and pinned objects are dropped. The caller
+    /// becomes responsible for the objects in allocated and recycled slots.
+    fn into_parts(mut self) -> (SlotStorage<T>, PoolAllocator, Vec<bool>, Arc<PoolConfig<T>>) {
+        self.release_pending();
+        self.drop_pinned();
+        let this = mem::ManuallyDrop::new(self);
+
+        // Safety: each field that owns resources is read exactly once and
+        // `this` is never dropped, so nothing is freed twice
+        unsafe {
+            drop(ptr::read(&this.pending));
+            drop(ptr::read(&this.pinned));
+            #[cfg(debug_assertions)]
+            drop(ptr::read(&this.allocation_sites));
+            #[cfg(feature = "stats")]
+            drop(ptr::read(&this.stats));
+            (
+                ptr::read(&this.storage).into_inner(),
+                ptr::read(&this.allocator).into_inner(),
+                ptr::read(&this.recycled).into_inner(),
+                ptr::read(&this.config),
+            )
+        }
+    }
+
+    /// Get current pool statistics.
+    ///
+    /// Each call also records a sample for
+    /// [`PoolStatistics::recent_allocations_per_second`].
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    pub fn statistics(&self) -> PoolStatistics {
+        let mut stats = self.stats.borrow_mut().sample();
+        stats.current_usage = self.allocated();
+        stats.name = self.config.name();
+        stats
+    }
+
+    /// Reset statistics counters.
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    pub fn reset_statistics(&self) {
+        self.stats.borrow_mut().reset();
+    }
+}
+
+/// Creates a pool with the default configuration: a capacity of 100 slots.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::FixedPool;
+///
+/// #[derive(Default)]
+/// struct Arena {
+///     nodes: FixedPool<u64>,
+/// }
+///
+/// let arena = Arena::default();
+/// assert_eq!(arena.nodes.capacity(), 100);
+/// ```
+impl<T: Poolable> Default for FixedPool<T> {
+    fn default() -> Self {
+        Self::from_config(PoolConfig::default())
+    }
+}
+
+/// Duplicates the pool together with its live objects.
+///
+/// Each allocated slot is cloned into the same index of the new pool, and the
+/// new pool has the same free slots, so indices taken from handles of the
+/// original refer to the same objects in the copy. Free slots, including ones
+/// holding a recycled object, are left empty. Handles keep borrowing the
+/// original pool; the copy's live objects are released when it is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::FixedPool;
+///
+/// let pool = FixedPool::new(8).unwrap();
+/// let handle = pool.allocate(String::from("state")).unwrap();
+///
+/// let snapshot = pool.clone();
+/// assert_eq!(snapshot.allocated(), 1);
+/// assert_eq!(snapshot.available(), pool.available());
+/// # drop(handle);
+/// ```
+impl<T: Poolable + Clone> Clone for FixedPool<T> {
+    fn clone(&self) -> Self {
+        let mut allocator = self.allocator.borrow().clone();
+        // Slots reserved but not yet initialized have nothing to copy
+        for &index in self.pending.borrow().iter() {
+            allocator.free(index);
+        }
+        let storage = self.storage.borrow();
+
+        let mut copy = SlotStorage::new(self.capacity, self.config.alignment());
+        for (index, (slot, target)) in storage.iter().zip(copy.iter_mut()).enumerate() {
+            if allocator.is_allocated(index) {
+                // Safety: allocated slots hold an initialized object
+                target.write(unsafe { slot.assume_init_ref() }.clone());
+            }
+        }
+
+        Self {
+            storage: RefCell::new(copy),
+            allocator: RefCell::new(allocator),
+            recycled: RefCell::new(alloc::vec![false; self.capacity]),
+            pending: RefCell::new(Vec::new()),
+            pinned: RefCell::new(Vec::new()),
+            capacity: self.capacity,
+            config: Arc::clone(&self.config),
+            watermarks: self.watermarks.clone(),
+            #[cfg(debug_assertions)]
+            allocation_sites: self.allocation_sites.clone(),
+            #[cfg(feature = "stats")]
+            stats: RefCell::new(self.stats.borrow().clone()),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Consumes the pool and yields every live object by value, in slot order.
+///
+/// Taking the pool by value guarantees no handle is outstanding, so the
+/// objects yielded are those whose handles were leaked, e.g. with
+/// `mem::forget`. Free slots are skipped and recycled objects held by free
+/// slots are dropped. Objects of leaked pinned handles are dropped in place
+/// instead of being yielded, since they must not move. This is useful at
+/// shutdown to flush pending state.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::FixedPool;
+///
+/// let pool = FixedPool::new(8).unwrap();
+/// for word in ["pending", "writes"] {
+///     std::mem::forget(pool.allocate(String::from(word)).unwrap());
+/// }
+///
+/// let flushed: Vec<String> = pool.into_iter().collect();
+/// assert_eq!(flushed, ["pending", "writes"]);
+/// ```
+impl<T: Poolable> IntoIterator for FixedPool<T> {
+    type Item = T;
+    type IntoIter = FixedPoolIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (mut storage, allocator, recycled, config) = self.into_parts();
+
+        for (index, slot) in storage.iter_mut().enumerate() {
+            if recycled[index] && !allocator.is_allocated(index) {
+                // Safety: recycled slots hold an initialized object owned by the pool
+                unsafe { drop_slot(slot.as_mut_ptr(), config.zero_on_release()) };
+            }
+        }
+
+        FixedPoolIntoIter {
+            remaining: allocator.capacity() - allocator.available(),
+            storage,
+            allocator,
+            next: 0,
+        }
+    }
+}
+
+/// Owning iterator over the live objects of a [`FixedPool`].
+///
+/// Created by [`FixedPool::into_iter`](IntoIterator::into_iter). Objects
+/// not consumed by the time the iterator is dropped are dropped with it.
+pub struct FixedPoolIntoIter<T> {
+    storage: SlotStorage<T>,
+    allocator: PoolAllocator,
+    /// Next slot to inspect
+    next: usize,
+    /// Number of live objects not yet yielded
+    remaining: usize,
+}
+
+impl<T> Iterator for FixedPoolIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.remaining > 0 {
+            let index = self.next;
+            self.next += 1;
+
+            if self.allocator.is_allocated(index) {
+                self.remaining -= 1;
+                // Safety: allocated slots hold an initialized object, and
+                // each slot is visited once
+                return Some(unsafe { self.storage[index].assume_init_read() });
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for FixedPoolIntoIter<T> {}
+
+impl<T> Drop for FixedPoolIntoIter<T> {
+    fn drop(&mut self) {
+        // Drop the live objects that were not yielded
+        for _ in self {}
+    }
+}
+
+/// Serializes the capacity and the live objects keyed by slot index.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize + Poolable> serde::Serialize for FixedPool<T> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        /// Live slots as an `index -> value` map.
+        struct LiveSlots<'a, T: Poolable>(&'a FixedPool<T>);
+
+        impl<T: serde::Serialize + Poolable> serde::Serialize for LiveSlots<'_, T> {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> core::result::Result<S::Ok, S::Error> {
+                let pool = self.0;
+                serializer.collect_map(pool.live_indices().map(|index| (index, pool.get(index))))
+            }
+        }
+
+        let mut state = serializer.serialize_struct("FixedPool", 2)?;
+        state.serialize_field("capacity", &self.capacity)?;
+        state.serialize_field("slots", &LiveSlots(self))?;
+        state.end()
+    }
+}
+
+/// Serialized form of a [`FixedPool`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "FixedPool")]
+struct FixedPoolRepr<T> {
+    capacity: usize,
+    slots: alloc::collections::BTreeMap<usize, T>,
+}
+
+/// Rebuilds a pool with a default configuration and every serialized object
+/// allocated at its original slot index.
+///
+/// The capacity comes from the input, so it is validated before anything is
+/// allocated: capacities whose storage or allocator metadata could not be
+/// addressed are rejected, and a capacity that is too large to allocate is
+/// reported as a deserialization error instead of aborting.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + Poolable> serde::Deserialize<'de> for FixedPool<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        use serde::de::Error as _;
+
+        let FixedPoolRepr { capacity, slots } = FixedPoolRepr::deserialize(deserializer)?;
+
+        // Each slot also costs a free-list entry, so bound by the larger of the two
+        let slot_bytes = mem::size_of::<T>().max(mem::size_of::<usize>());
+        if capacity
+            .checked_mul(slot_bytes)
+            .map_or(true, |bytes| bytes > isize::MAX as usize)
+        {
+            return Err(D::Error::custom(format_args!(
+                "capacity {} is too large",
+                capacity
+            )));
+        }
+        if slots.len() > capacity {
+            return Err(D::Error::custom(format_args!(
+                "{} slots do not fit in capacity {}",
+                slots.len(),
+                capacity
+            )));
+        }
+        if let Some(&index) = slots.keys().next_back() {
+            if index >= capacity {
+                return Err(D::Error::custom(format_args!(
+                    "slot index {} is out of range for capacity {}",
+                    index, capacity
+                )));
+            }
+        }
+
+        let config = PoolConfig::builder()
+            .capacity(capacity)
+            .build()
+            .map_err(D::Error::custom)?;
+        let mut pool = FixedPool::try_with_config(config).map_err(D::Error::custom)?;
+        let allocator =
+            StackAllocator::try_with_occupied(capacity, |index| slots.contains_key(&index))
+                .map_err(|_| {
+                    D::Error::custom(Error::AllocationFailed {
+                        bytes: capacity * slot_bytes,
+                    })
+                })?;
+        *pool.allocator.get_mut() = allocator.into();
+        for (index, value) in slots {
+            pool.fill_slot(index, value);
+        }
+
+        Ok(pool)
+    }
+}
+
+/// Drops the object at `ptr`, then wipes its bytes if `zero` is set.
+///
+/// # Safety
+///
+/// `ptr` must point to an initialized object that is not used again.
+#[inline]
+unsafe fn drop_slot<T>(ptr: *mut T, zero: bool) {
+    ptr::drop_in_place(ptr);
+    if zero {
+        secure_zero(ptr.cast::<u8>(), mem::size_of::<T>());
+    }
+}
+
+impl<T> Drop for FixedPool<T> {
+    fn drop(&mut self) {
+        // Handles borrow the pool, so any slot still allocated here belongs to a
+        // handle that was leaked (e.g. via `mem::forget`). Its object would
+        // otherwise never be dropped, so drop it along with recycled objects,
+        // which are owned by the pool itself.
+        let allocator = self.allocator.get_mut();
+
+        // Slots reserved by a leaked `UninitHandle` were never initialized
+        for index in self.pending.get_mut().drain(..) {
+            allocator.free(index);
+        }
+        let storage = self.storage.get_mut();
+        let recycled = self.recycled.get_mut();
+
+        for (index, slot) in storage.iter_mut().enumerate() {
+            if allocator.is_allocated(index) {
+                #[cfg(debug_assertions)]
+                let location = self.allocation_sites.get_mut()[index];
+                #[cfg(not(debug_assertions))]
+                let location = None;
+
+                self.config.notify(PoolEvent::Leaked { index, location });
+            }
+
+            if allocator.is_allocated(index) || recycled[index] {
+                // Safety: allocated and recycled slots hold an initialized object
+                // that nothing else can reach anymore
+                unsafe { drop_slot(slot.as_mut_ptr(), self.config.zero_on_release()) };
+            }
+        }
+    }
+}
+
+// Safety: FixedPool is Send if T is Send (storage is behind RefCell)
+unsafe impl<T: Send> Send for FixedPool<T> {}
+
+// Note: FixedPool is NOT Sync because it uses RefCell internally
+// Use ThreadSafePool for concurrent access
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_pool() {
+        let pool = FixedPool::<i32>::new(100).unwrap();
+        assert_eq!(pool.capacity(), 100);
+        assert_eq!(pool.available(), 100);
+        assert_eq!(pool.allocated(), 0);
+        assert!(pool.is_empty());
+        assert!(!pool.is_full());
+    }
+
+    #[test]
+    fn allocate_and_drop() {
+        let pool = FixedPool::new(10).unwrap();
+
+        {
+            let handle = pool.allocate(42).unwrap();
+            assert_eq!(*handle, 42);
+            assert_eq!(pool.allocated(), 1);
+            assert_eq!(pool.available(), 9);
+        }
+
+        // After drop, should be returned to pool
+        assert_eq!(pool.allocated(), 0);
+        assert_eq!(pool.available(), 10);
+    }
+
+    #[test]
+    fn allocate_until_full() {
+        let pool = FixedPool::new(3).unwrap();
+
+        let _h1 = pool.allocate(1).unwrap();
+        let _h2 = pool.allocate(2).unwrap();
+        let _h3 = pool.allocate(3).unwrap();
+
+        assert!(pool.is_full());
+
+        let result = pool.allocate(4);
+        assert!(matches!(
+            result,
+            Err(Error::PoolExhausted {
+                capacity: 3,
+                allocated: 3,
+                can_grow: false,
+                name: None,
+            })
+        ));
+        let err = result.unwrap_err();
+        assert!(err.is_transient());
+        assert_eq!(err.retry_hint(), crate::error::RetryHint::AfterRelease);
+    }
+
+    #[test]
+    fn batch_exhaustion_reports_no_growth() {
+        let pool = FixedPool::new(2).unwrap();
+        let _h = pool.allocate(1).unwrap();
+
+        let err = pool.allocate_batch(alloc::vec![2, 3]).unwrap_err();
+        assert_eq!(
+            err,
+            Error::PoolExhausted {
+                capacity: 2,
+                allocated: 1,
+                can_grow: false,
+                name: None,
+            }
+        );
+    }
+
+    #[test]
+    fn reuse_after_free() {
+        let pool = FixedPool::new(2).unwrap();
+
+        let h1 = pool.allocate(1).unwrap();
+        drop(h1);
+
+        let h2 = pool.allocate(2).unwrap();
+        assert_eq!(*h2, 2);
+    }
+
+    #[test]
+    fn modify_value() {
+        let pool = FixedPool::new(10).unwrap();
+
+        let mut handle = pool.allocate(10).unwrap();
+        assert_eq!(*handle, 10);
+
+        *handle = 20;
+        assert_eq!(*handle, 20);
+    }
+
+    #[test]
+    fn free_batch_returns_all_slots() {
+        use alloc::rc::Rc;
+
+        struct Counted(Rc<core::cell::Cell<usize>>);
+        impl Poolable for Counted {}
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(core::cell::Cell::new(0));
+        let pool = FixedPool::new(64).unwrap();
+        let handles: Vec<_> = (0..64)
+            .map(|_| pool.allocate(Counted(Rc::clone(&drops))).unwrap())
+            .collect();
+        assert!(pool.is_full());
+
+        pool.free_batch(handles);
+        assert!(pool.is_empty());
+        assert_eq!(drops.get(), 64);
+
+        // Freed slots are reusable
+        let again = pool.allocate_batch((0..64).map(|_| Counted(Rc::clone(&drops))).collect());
+        assert_eq!(again.unwrap().len(), 64);
+    }
+
+    #[test]
+    fn free_batch_drops_foreign_handles() {
+        let pool = FixedPool::new(4).unwrap();
+        let other = FixedPool::new(4).unwrap();
+
+        let handles = alloc::vec![
+            pool.allocate(1).unwrap(),
+            other.allocate(2).unwrap(),
+            pool.allocate(3).unwrap(),
+        ];
+
+        pool.free_batch(handles);
+        assert!(pool.is_empty());
+        assert!(other.is_empty());
+    }
+
+    /// Reads the raw bytes of a slot, whether or not it currently holds a value.
+    unsafe fn slot_bytes<T>(pool: &FixedPool<T>, index: usize) -> Vec<u8> {
+        let storage = pool.storage.borrow();
+        let ptr = storage[index].as_ptr().cast::<u8>();
+        core::slice::from_raw_parts(ptr, mem::size_of::<T>()).to_vec()
+    }
+
+    #[test]
+    fn zero_on_release_wipes_slot() {
+        let config = PoolConfig::builder()
+            .capacity(2)
+            .zero_on_release(true)
+            .build()
+            .unwrap();
+        let pool = FixedPool::<[u8; 32]>::with_config(config).unwrap();
+
+        let handle = pool.allocate([0x5A; 32]).unwrap();
+        let index = handle.index();
+        assert_eq!(unsafe { slot_bytes(&pool, index) }, [0x5A; 32]);
+
+        drop(handle);
+        assert_eq!(unsafe { slot_bytes(&pool, index) }, [0u8; 32]);
+    }
+
+    #[test]
+    fn zero_on_release_wipes_recycled_objects_when_dropped() {
+        let mut buffer = [MaybeUninit::<[u8; 32]>::uninit(); 2];
+        let config = PoolConfig::builder()
+            .capacity(2)
+            .zero_on_release(true)
+            .reset_fn(|| [0x5A; 32], |_| {})
+            .build()
+            .unwrap();
+        let buffer_ptr = ptr::NonNull::new(buffer.as_mut_ptr()).unwrap();
+        // Safety: the buffer outlives the pool and is only read after it is dropped
+        let storage = unsafe { SlotStorage::borrowed(buffer_ptr, 2) };
+        let pool = FixedPool::from_parts(
+            config,
+            storage,
+            StackAllocator::new(2).into(),
+            alloc::vec![false; 2],
+        );
+
+        let handle = pool.allocate_recycled().unwrap();
+        let index = handle.index();
+        drop(handle);
+        // Still waiting to be reused, so not wiped yet
+        assert_eq!(unsafe { slot_bytes(&pool, index) }, [0x5A; 32]);
+
+        drop(pool);
+        assert_eq!(unsafe { buffer[index].assume_init() }, [0u8; 32]);
+    }
+
+    #[test]
+    fn recycled_vec_keeps_capacity() {
+        let config = PoolConfig::builder()
+            .capacity(2)
+            .reset_fn(|| Vec::<u8>::with_capacity(16), |v| v.clear())
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+
+        let mut buf = pool.allocate_recycled().unwrap();
+        buf.extend(0..200u8);
+        let grown_capacity = buf.capacity();
+        let ptr = buf.as_ptr();
+        drop(buf);
+
+        for _ in 0..3 {
+            let mut buf = pool.allocate_recycled().unwrap();
+            assert!(buf.is_empty());
+            assert_eq!(buf.capacity(), grown_capacity);
+            assert_eq!(buf.as_ptr(), ptr);
+            buf.extend(0..100u8);
+        }
+
+        assert_eq!(pool.allocated(), 0);
+    }
+
+    #[test]
+    fn allocate_recycled_requires_reset() {
+        let pool = FixedPool::<Vec<u8>>::new(2).unwrap();
+        let result = pool.allocate_recycled();
+        assert!(matches!(result, Err(Error::InvalidConfiguration { .. })));
+        assert_eq!(pool.allocated(), 0);
+    }
+
+    #[test]
+    fn allocate_over_recycled_slot_drops_old_value() {
+        use alloc::rc::Rc;
+
+        #[allow(dead_code)]
+        struct Tracked(Option<Rc<()>>);
+        impl Poolable for Tracked {}
+
+        let tracker = Rc::new(());
+        let config = PoolConfig::builder()
+            .capacity(1)
+            .reset_fn(|| Tracked(None), |_| {})
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+
+        drop(pool.allocate(Tracked(Some(Rc::clone(&tracker)))).unwrap());
+        // The released value is parked in the slot, still holding a reference
+        assert_eq!(Rc::strong_count(&tracker), 2);
+
+        // Allocating a fresh value over the slot drops the recycled one
+        let handle = pool.allocate(Tracked(None)).unwrap();
+        assert_eq!(Rc::strong_count(&tracker), 1);
+        drop(handle);
+
+        // Recycled values are dropped with the pool
+        drop(pool.allocate(Tracked(Some(Rc::clone(&tracker)))).unwrap());
+        assert_eq!(Rc::strong_count(&tracker), 2);
+        drop(pool);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    fn released_slot_not_wiped_by_default() {
+        let pool = FixedPool::<[u8; 32]>::new(2).unwrap();
+
+        let handle = pool.allocate([0x5A; 32]).unwrap();
+        let index = handle.index();
+        drop(handle);
+
+        assert_eq!(unsafe { slot_bytes(&pool, index) }, [0x5A; 32]);
+    }
+
+    #[test]
+    fn pre_initialized_slots_hold_initializer_value() {
+        let config = PoolConfig::builder()
+            .capacity(3)
+            .pre_initialize(true)
+            .initializer(|| 7u32)
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+
+        // Every slot is constructed before the first allocation
+        assert!(pool.recycled.borrow().iter().all(|&primed| primed));
+        for index in 0..3 {
+            assert_eq!(*pool.get(index), 7);
+        }
+        assert!(pool.is_empty());
+
+        let handle = pool.allocate_recycled().unwrap();
+        assert_eq!(*handle, 7);
+    }
+
+    #[test]
+    fn pre_initialize_without_initializer_stays_lazy() {
+        let config = PoolConfig::<u32>::builder()
+            .capacity(3)
+            .pre_initialize(true)
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+
+        assert!(pool.recycled.borrow().iter().all(|&primed| !primed));
+    }
+
+    #[test]
+    fn reset_all_restores_free_slots() {
+        let config = PoolConfig::builder()
+            .capacity(2)
+            .pre_initialize(true)
+            .reset_fn(|| alloc::vec![1u8, 2, 3], |v| v.truncate(1))
+            .build()
+            .unwrap();
+        let mut pool = FixedPool::with_config(config).unwrap();
+
+        {
+            let mut a = pool.allocate_recycled().unwrap();
+            a.push(4);
+        }
+        // Released value was reset by the reset function
+        assert_eq!(*pool.get(0), [1]);
+
+        pool.reset_all();
+        assert_eq!(*pool.get(0), [1]);
+        assert_eq!(*pool.get(1), [1]);
+    }
+
+    #[test]
+    fn reset_all_reinitializes_without_reset_fn() {
+        let config = PoolConfig::builder()
+            .capacity(2)
+            .pre_initialize(true)
+            .initializer(|| alloc::vec![9u8; 4])
+            .build()
+            .unwrap();
+        let mut pool = FixedPool::with_config(config).unwrap();
+
+        {
+            let mut a = pool.allocate_recycled().unwrap();
+            a.clear();
+        }
+        // Without a reset function the released value is dropped
+        assert!(!pool.recycled.borrow()[0]);
+
+        pool.reset_all();
+        assert!(pool.recycled.borrow().iter().all(|&primed| primed));
+        assert_eq!(*pool.get(0), [9; 4]);
+        assert_eq!(*pool.get(1), [9; 4]);
+    }
+
+    #[test]
+    fn drop_pool_drops_leaked_handles() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Counted;
+        impl Poolable for Counted {}
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let pool = FixedPool::new(4).unwrap();
+        let kept = pool.allocate(Counted).unwrap();
+        mem::forget(pool.allocate(Counted).unwrap());
+        drop(kept);
+        assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+
+        // The leaked object is dropped with the pool, exactly once
+        drop(pool);
+        assert_eq!(DROPS.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn try_with_config_reports_allocation_failure() {
+        #[allow(dead_code)]
+        struct Page([u8; 4096]);
+        impl Poolable for Page {}
+
+        // Large enough that the request is rejected before reaching the
+        // system allocator, so the test never actually reserves the memory
+        let capacity = usize::MAX / 4096;
+        let config = PoolConfig::builder().capacity(capacity).build().unwrap();
+
+        match FixedPool::<Page>::try_with_config(config) {
+            Err(Error::AllocationFailed { bytes }) => assert_eq!(bytes, capacity * 4096),
+            other => panic!("expected AllocationFailed, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn try_with_config_builds_usable_pool() {
+        let config = PoolConfig::builder().capacity(8).build().unwrap();
+        let pool = FixedPool::<u64>::try_with_config(config).unwrap();
+
+        let handle = pool.allocate(5).unwrap();
+        assert_eq!(*handle, 5);
+        assert_eq!(pool.available(), 7);
+    }
+
+    #[test]
+    fn clone_copies_live_slots_at_same_indices() {
+        let pool = FixedPool::new(8).unwrap();
+        let handles: Vec<_> = (0..6)
+            .map(|i| pool.allocate(alloc::format!("value-{}", i)).unwrap())
+            .collect();
+
+        // Leave a sparse set of live slots: 0, 2, 4
+        let live: Vec<_> = handles
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, h)| (i % 2 == 0).then_some(h))
+            .collect();
+
+        let copy = pool.clone();
+        assert_eq!(copy.available(), pool.available());
+        assert_eq!(copy.allocated(), 3);
+
+        for handle in &live {
+            let index = handle.index();
+            assert!(copy.allocator.borrow().is_allocated(index));
+            assert_eq!(copy.get(index), &**handle);
+        }
+        for index in [1, 3, 5, 6, 7] {
+            assert!(!copy.allocator.borrow().is_allocated(index));
+        }
+
+        // The copy is independent of the original
+        copy.get_mut(0).push_str("-copy");
+        assert_eq!(*live[0], "value-0");
+        assert_eq!(copy.get(0), "value-0-copy");
+    }
+
+    #[test]
+    fn clone_shares_configuration() {
+        let config = PoolConfig::builder()
+            .capacity(2)
+            .reset_fn(Vec::<u8>::new, |v| v.clear())
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+        drop(pool.allocate_recycled().unwrap());
+
+        let copy = pool.clone();
+        // Recycled objects are not copied, but recycling is still configured
+        assert!(copy.recycled.borrow().iter().all(|&primed| !primed));
+        let buf = copy.allocate_recycled().unwrap();
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn observer_sees_allocate_and_free() {
+        use std::sync::{Arc, Mutex};
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let config = PoolConfig::builder()
+            .capacity(4)
+            .observer(move |event| sink.lock().unwrap().push(event))
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+
+        let a = pool.allocate(1).unwrap();
+        let b = pool.allocate(2).unwrap();
+        drop(a);
+        pool.free_batch(vec![b]);
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                PoolEvent::Allocated { index: 0, usage: 1 },
+                PoolEvent::Allocated { index: 1, usage: 2 },
+                PoolEvent::Freed { index: 0, usage: 1 },
+                PoolEvent::Freed { index: 1, usage: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn live_indices_over_sparse_set() {
+        let mut pool = FixedPool::new(200).unwrap();
+        let indices: Vec<usize> = (0..200u32)
+            .map(|i| pool.allocate(i * 10).unwrap().into_raw())
+            .collect();
+
+        // Keep every seventh slot, spanning several bitmap words
+        for index in indices.into_iter().filter(|i| i % 7 != 0) {
+            // Safety: each index came from into_raw and is re-wrapped once
+            drop(unsafe { pool.from_raw_index(index) });
+        }
+
+        let live: Vec<_> = pool.live_indices().collect();
+        let expected: Vec<_> = (0..200).filter(|i| i % 7 == 0).collect();
+        assert_eq!(live, expected);
+
+        for index in live {
+            assert_eq!(pool.get_by_index(index), Some(&(index as u32 * 10)));
+        }
+        assert_eq!(pool.get_by_index(1), None);
+        assert_eq!(pool.get_by_index(199), None);
+        assert_eq!(pool.get_by_index(200), None);
+    }
+
+    #[test]
+    fn live_indices_empty_pool() {
+        let mut pool = FixedPool::<i32>::new(10).unwrap();
+        assert_eq!(pool.live_indices().count(), 0);
+        assert_eq!(pool.get_by_index(0), None);
+    }
+
+    #[test]
+    fn with_nonzero_returns_pool() {
+        let pool = FixedPool::with_nonzero(NonZeroUsize::new(3).unwrap());
+        assert_eq!(pool.capacity(), 3);
+        assert_eq!(*pool.allocate(7).unwrap(), 7);
+
+        assert!(FixedPool::<i32>::new(0).is_err());
+    }
+
+    #[test]
+    fn take_moves_value_out() {
+        use alloc::string::String;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        struct Counted(String);
+        impl Poolable for Counted {}
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let pool = FixedPool::new(2).unwrap();
+        let handle = pool.allocate(Counted(String::from("pooled"))).unwrap();
+        assert_eq!(pool.available(), 1);
+
+        let value = pool.take(handle);
+        assert_eq!(pool.available(), 2);
+        assert_eq!(DROPS.load(Ordering::SeqCst), 0);
+        assert_eq!(value.0, "pooled");
+
+        drop(value);
+        drop(pool);
+        assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn reset_on_acquire_cleans_incoming_values() {
+        struct Counter(u32);
+        impl Poolable for Counter {}
+
+        let build = |reset_on_acquire| {
+            let config = PoolConfig::builder()
+                .capacity(2)
+                .reset_fn(|| Counter(0), |c: &mut Counter| c.0 = 0)
+                .reset_on_acquire(reset_on_acquire)
+                .build()
+                .unwrap();
+            FixedPool::with_config(config).unwrap()
+        };
+
+        // A value left dirty, e.g. because its release never ran, is reset
+        let pool = build(true);
+        let handle = pool.allocate(Counter(5)).unwrap();
+        assert_eq!(handle.0, 0);
+
+        let mut recycled = pool.allocate_recycled().unwrap();
+        recycled.0 = 9;
+        drop(recycled);
+        assert_eq!(pool.allocate_recycled().unwrap().0, 0);
+
+        // Without the flag the value is stored as given
+        let pool = build(false);
+        assert_eq!(pool.allocate(Counter(5)).unwrap().0, 5);
+    }
+
+    #[test]
+    fn transfer_in_moves_between_pools() {
+        use alloc::string::String;
+
+        let source = FixedPool::new(2).unwrap();
+        let target = FixedPool::new(1).unwrap();
+
+        let handle = source.allocate(String::from("payload")).unwrap();
+        let _other = source.allocate(String::from("stays")).unwrap();
+        assert_eq!(source.available(), 0);
+
+        let moved = target.transfer_in(handle, &source).unwrap();
+        assert_eq!(*moved, "payload");
+        assert_eq!(source.available(), 1);
+        assert_eq!(target.available(), 0);
+
+        // A full destination leaves the value in the source pool's care
+        let again = source.allocate(String::from("extra")).unwrap();
+        assert!(target.transfer_in(again, &source).is_err());
+        assert_eq!(source.available(), 1);
+    }
+
+    #[test]
+    fn deterministic_mode_allocates_ascending_after_interleaved_frees() {
+        let config = PoolConfig::builder()
+            .capacity(8)
+            .deterministic(true)
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+
+        let mut handles: Vec<_> = (0..8).map(|i| Some(pool.allocate(i).unwrap())).collect();
+        for index in [5, 2, 7, 0, 3] {
+            handles[index] = None;
+        }
+        assert_eq!(pool.peek_next_index(), Some(0));
+
+        let mut indices = Vec::new();
+        let mut reallocated = Vec::new();
+        while let Some(index) = pool.peek_next_index() {
+            indices.push(index);
+            reallocated.push(pool.allocate(0).unwrap());
+            if index == 2 {
+                // Freeing a higher slot mid-way must not jump the queue
+                handles[6] = None;
+            }
+        }
+        assert_eq!(indices, [0, 2, 3, 5, 6, 7]);
+        assert!(pool.is_full());
+        assert_eq!(pool.peek_next_index(), None);
+    }
+
+    #[test]
+    fn allocate_batch_iter_takes_lazy_values() {
+        let pool = FixedPool::new(64).unwrap();
+        let handles = pool.allocate_batch_iter((0..50).map(|i| i * 3)).unwrap();
+        assert_eq!(handles.len(), 50);
+        assert!(handles.iter().enumerate().all(|(i, h)| **h == i * 3));
+        assert_eq!(pool.allocated(), 50);
+
+        let err = pool.allocate_batch_iter(0..20).err().unwrap();
+        assert!(matches!(err, Error::PoolExhausted { .. }));
+        assert_eq!(pool.allocated(), 50);
+    }
+
+    #[test]
+    fn allocate_batch_iter_rolls_back_short_iterator() {
+        /// Claims ten items but only ever yields three.
+        struct Lying(usize);
+        impl Iterator for Lying {
+            type Item = usize;
+            fn next(&mut self) -> Option<usize> {
+                self.0 += 1;
+                (self.0 <= 3).then_some(self.0)
+            }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (10, Some(10))
+            }
+        }
+        impl ExactSizeIterator for Lying {}
+
+        let pool = FixedPool::new(16).unwrap();
+        let _held = pool.allocate(0).unwrap();
+
+        let err = pool.allocate_batch_iter(Lying(0)).err().unwrap();
+        assert!(matches!(err, Error::Custom { .. }));
+        assert_eq!(pool.allocated(), 1);
+
+        // Every slot is usable again after the rollback
+        let handles = pool.allocate_batch_iter(0..15).unwrap();
+        assert!(pool.is_full());
+        drop(handles);
+    }
+
+    #[test]
+    fn available_contiguous_tracks_fragmentation() {
+        let pool = FixedPool::new(100).unwrap();
+        assert_eq!(pool.available_contiguous(), 100);
+
+        let mut handles: Vec<_> = (0..100).map(|i| Some(pool.allocate(i).unwrap())).collect();
+        assert_eq!(pool.available_contiguous(), 0);
+
+        for index in (20..30).chain(60..90) {
+            handles[index] = None;
+        }
+        assert_eq!(pool.available(), 40);
+        assert_eq!(pool.available_contiguous(), 30);
+
+        let run = pool.allocate_slice((0..30).collect()).unwrap();
+        assert_eq!(pool.available_contiguous(), 10);
+        drop(run);
+    }
+
+    #[test]
+    fn allocate_try_with_frees_slot_on_failure() {
+        use alloc::string::String;
+
+        let pool = FixedPool::<String>::new(2).unwrap();
+        let _held = pool.allocate(String::from("held")).unwrap();
+        assert_eq!(pool.available(), 1);
+
+        for _ in 0..3 {
+            let result = pool.allocate_try_with(|| Err::<String, _>("open failed"));
+            assert_eq!(result.unwrap().err(), Some("open failed"));
+            assert_eq!(pool.available(), 1);
+        }
+
+        let handle = pool
+            .allocate_try_with(|| Ok::<_, ()>(String::from("fd")))
+            .unwrap()
+            .unwrap();
+        assert_eq!(handle.as_str(), "fd");
+        assert!(pool
+            .allocate_try_with(|| Ok::<_, ()>(String::new()))
+            .is_err());
+    }
+
+    #[test]
+    fn reset_fn_runs_on_every_release() {
+        use alloc::string::String;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        let resets = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&resets);
+        let config = PoolConfig::builder()
+            .capacity(4)
+            .reset_fn(String::new, move |s: &mut String| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                s.clear();
+            })
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+
+        // Plain allocate, not only allocate_recycled, is reset on drop
+        let handle = pool.allocate(String::from("dirty")).unwrap();
+        drop(handle);
+        assert_eq!(resets.load(Ordering::SeqCst), 1);
+
+        let handles: Vec<_> = (0..3)
+            .map(|_| pool.allocate(String::from("x")).unwrap())
+            .collect();
+        pool.free_batch(handles);
+        assert_eq!(resets.load(Ordering::SeqCst), 4);
+
+        let recycled = pool.allocate_recycled().unwrap();
+        assert!(recycled.is_empty());
+    }
+
+    #[test]
+    fn from_raw_parts_uses_caller_buffer() {
+        let mut buffer = [MaybeUninit::<u64>::uninit(); 8];
+        let range = buffer.as_ptr_range();
+
+        {
+            // Safety: the buffer outlives the pool and is untouched meanwhile
+            let pool = unsafe { FixedPool::from_raw_parts(buffer.as_mut_ptr(), 8, 8) }.unwrap();
+            assert_eq!(pool.capacity(), 8);
+
+            let handles: Vec<_> = (0..8u64).map(|i| pool.allocate(i * 11).unwrap()).collect();
+            for handle in &handles {
+                let addr = (&**handle as *const u64).cast::<MaybeUninit<u64>>();
+                assert!(range.contains(&addr));
+            }
+            assert!(pool.allocate(0).is_err());
+
+            // Leak the handles so the values stay in the buffer after the pool
+            mem::forget(handles);
+        }
+
+        // The pool did not free the stack buffer, and its contents remain
+        let values: Vec<u64> = buffer
+            .iter()
+            .map(|slot| unsafe { slot.assume_init() })
+            .collect();
+        assert_eq!(values, (0..8u64).map(|i| i * 11).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn from_raw_parts_validates_buffer() {
+        let mut buffer = [MaybeUninit::<u64>::uninit(); 4];
+
+        let null = unsafe { FixedPool::<u64>::from_raw_parts(ptr::null_mut(), 4, 8) };
+        assert!(matches!(null, Err(Error::InvalidConfiguration { .. })));
+
+        let zero = unsafe { FixedPool::from_raw_parts(buffer.as_mut_ptr(), 0, 8) };
+        assert!(matches!(zero, Err(Error::InvalidConfiguration { .. })));
+
+        let misaligned = unsafe { FixedPool::from_raw_parts(buffer.as_mut_ptr(), 4, 3) };
+        assert!(matches!(misaligned, Err(Error::InvalidAlignment { .. })));
+    }
+
+    #[test]
+    fn allocate_aligned_returns_page_aligned_slots() {
+        let config = PoolConfig::builder()
+            .capacity(2048)
+            .alignment(4096)
+            .build()
+            .unwrap();
+        let pool = FixedPool::<u64>::with_config(config).unwrap();
+        let first = pool.allocate(0).unwrap();
+        assert_eq!(first.index(), 0);
+
+        // 512 eight-byte slots per page, so 4 of the 2048 slots are page-aligned
+        // and the first of them is already taken
+        let pages: Vec<_> = (0..3)
+            .map(|i| pool.allocate_aligned(i, 4096).unwrap())
+            .collect();
+        for page in &pages {
+            assert_eq!(&**page as *const u64 as usize % 4096, 0);
+            assert_eq!(page.index() % 512, 0);
+        }
+        assert_eq!(*pages[2], 2);
+
+        assert!(matches!(
+            pool.allocate_aligned(9, 4096),
+            Err(Error::PoolExhausted { .. })
+        ));
+        assert!(pool.allocate(9).is_ok());
+    }
+
+    #[test]
+    fn allocate_aligned_rejects_alignment_above_pool() {
+        let config = PoolConfig::builder()
+            .capacity(8)
+            .alignment(64)
+            .build()
+            .unwrap();
+        let pool = FixedPool::<u64>::with_config(config).unwrap();
+
+        assert!(matches!(
+            pool.allocate_aligned(1, 128),
+            Err(Error::InvalidAlignment { alignment: 128 })
+        ));
+        assert!(matches!(
+            pool.allocate_aligned(1, 24),
+            Err(Error::InvalidAlignment { .. })
+        ));
+        assert_eq!(pool.available(), 8);
+    }
+
+    #[test]
+    fn storage_honors_configured_alignment() {
+        let config = PoolConfig::builder()
+            .capacity(3)
+            .alignment(256)
+            .build()
+            .unwrap();
+        let pool = FixedPool::<u8>::with_config(config).unwrap();
+        let first = pool.allocate(1).unwrap();
+        assert_eq!(&*first as *const u8 as usize % 256, 0);
+
+        let copy = pool.clone();
+        assert_eq!(copy.get(first.index()) as *const u8 as usize % 256, 0);
+    }
+
+    #[test]
+    fn default_pool_has_capacity_100() {
+        let pool = FixedPool::<i32>::default();
+        assert_eq!(pool.capacity(), 100);
+
+        let handle = pool.allocate(3).unwrap();
+        assert_eq!(*handle, 3);
+        assert_eq!(pool.available(), 99);
+    }
+
+    #[test]
+    fn into_growing_keeps_live_objects_across_growth() {
+        use alloc::string::String;
+
+        let pool = FixedPool::<String>::new(4).unwrap();
+        let kept: Vec<usize> = ["a", "b", "c"]
+            .iter()
+            .map(|s| {
+                let handle = pool.allocate(String::from(*s)).unwrap();
+                let index = handle.index();
+                mem::forget(handle);
+                index
+            })
+            .collect();
+
+        let pool = pool.into_growing(GrowthStrategy::Linear { amount: 4 });
+        assert_eq!(pool.capacity(), 4);
+        assert_eq!(pool.allocated(), 3);
+
+        // Fill the last slot and trigger growth
+        let handles: Vec<_> = (0..5)
+            .map(|i| pool.allocate(alloc::format!("new-{}", i)).unwrap())
+            .collect();
+        assert_eq!(pool.capacity(), 8);
+        assert!(handles.iter().all(|handle| !kept.contains(&handle.index())));
+
+        for (index, expected) in kept.iter().zip(["a", "b", "c"]) {
+            assert_eq!(pool.get(*index), expected);
+        }
+        for &index in &kept {
+            pool.return_to_pool(index);
+        }
+        drop(handles);
+        assert_eq!(pool.allocated(), 0);
+    }
+
+    #[test]
+    fn into_growing_drops_recycled_objects_and_keeps_config() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+        struct Counted;
+        impl Poolable for Counted {}
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+            }
         }
+
+        let config = PoolConfig::builder()
+            .capacity(3)
+            .max_capacity(Some(6))
+            .reset_fn(|| Counted, |_| {})
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+        drop(pool.allocate_recycled().unwrap());
+        assert_eq!(DROPS.load(Ordering::SeqCst), 0);
+
+        let pool = pool.into_growing(GrowthStrategy::Linear { amount: 3 });
+        assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+        assert_eq!(pool.allocated(), 0);
+
+        let handles: Vec<_> = (0..6).map(|_| pool.allocate(Counted).unwrap()).collect();
+        assert_eq!(pool.capacity(), 6);
+        assert!(pool.allocate(Counted).is_err());
+        drop(handles);
     }
 
-    /// Gets a mutable reference to an object at the given index.
-    ///
-    /// # Safety
-    ///
-    /// This is internal and should only be called with valid allocated indices.
-    #[inline(always)]
-    #[allow(clippy::mut_from_ref)]
-    pub(crate) fn get_mut(&self, index: usize) -> &mut T {
-        let storage = self.storage.borrow_mut();
-        // Safety: index is valid and initialized by allocate()
-        // We extend the lifetime beyond the borrow - safe because pool owns the data
-        unsafe {
-            let ptr = storage.as_ptr() as *mut MaybeUninit<T>;
-            &mut *ptr.add(index).cast::<T>()
+    #[test]
+    fn into_iter_yields_live_values_once() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+        struct Counted(u32);
+        impl Poolable for Counted {}
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let pool = FixedPool::new(8).unwrap();
+        for value in 0..5 {
+            let handle = pool.allocate(Counted(value)).unwrap();
+            if value % 2 == 0 {
+                mem::forget(handle);
+            }
         }
+        assert_eq!(DROPS.load(Ordering::SeqCst), 2);
+
+        let mut iter = pool.into_iter();
+        assert_eq!(iter.len(), 3);
+        let first = iter.next().unwrap();
+        assert_eq!(first.0, 0);
+        drop(first);
+        assert_eq!(DROPS.load(Ordering::SeqCst), 3);
+
+        let rest: Vec<_> = iter.collect();
+        assert_eq!(rest.iter().map(|c| c.0).collect::<Vec<_>>(), [2, 4]);
+        drop(rest);
+        assert_eq!(DROPS.load(Ordering::SeqCst), 5);
     }
 
-    /// Returns an object to the pool (called by handle Drop).
-    ///
-    /// # Safety
-    ///
-    /// This is internal and should only be called once per allocation.
-    pub(crate) fn return_to_pool(&self, index: usize) {
-        // Get the value and call on_release
-        let mut storage = self.storage.borrow_mut();
+    #[test]
+    fn into_iter_drops_unconsumed_values() {
+        use alloc::rc::Rc;
 
-        // Safety: index is valid and was initialized
-        unsafe {
-            let value_ptr = storage[index].as_mut_ptr();
-            (*value_ptr).on_release();
-            ptr::drop_in_place(value_ptr);
+        struct Tracked(#[allow(dead_code)] Rc<()>);
+        impl Poolable for Tracked {}
+
+        let tracker = Rc::new(());
+        let pool = FixedPool::new(4).unwrap();
+        for _ in 0..3 {
+            mem::forget(pool.allocate(Tracked(Rc::clone(&tracker))).unwrap());
         }
 
-        // Mark the slot as free
-        self.allocator.borrow_mut().free(index);
+        let mut iter = pool.into_iter();
+        drop(iter.next());
+        assert_eq!(Rc::strong_count(&tracker), 3);
+        drop(iter);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
 
-        #[cfg(feature = "stats")]
-        self.stats.borrow_mut().record_deallocation();
+    #[test]
+    fn raw_index_round_trip_frees_once() {
+        use core::cell::Cell;
+
+        struct Counted<'a>(u32, &'a Cell<usize>);
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+            }
+        }
+        impl Poolable for Counted<'_> {}
+
+        let drops = Cell::new(0);
+        let mut pool = FixedPool::new(4).unwrap();
+        let _other = pool.allocate(Counted(1, &drops)).unwrap().into_raw();
+        let index = pool.allocate(Counted(2, &drops)).unwrap().into_raw();
+
+        assert_eq!(pool.allocated(), 2);
+        assert_eq!(drops.get(), 0);
+        assert_eq!(pool.get_by_index(index).map(|c| c.0), Some(2));
+
+        // Safety: the index came from into_raw on this pool and is used once
+        let handle = unsafe { pool.from_raw_index(index) };
+        assert_eq!(handle.index(), index);
+        assert_eq!(handle.0, 2);
+
+        drop(handle);
+        assert_eq!(drops.get(), 1);
+        assert_eq!(pool.allocated(), 1);
     }
 
-    /// Get current pool statistics.
-    #[cfg(feature = "stats")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
-    pub fn statistics(&self) -> PoolStatistics {
-        let mut stats = self.stats.borrow().snapshot();
-        stats.current_usage = self.allocated();
-        stats
+    #[test]
+    #[should_panic(expected = "does not refer to an allocated slot")]
+    fn from_raw_index_rejects_free_slots() {
+        let pool = FixedPool::<u32>::new(4).unwrap();
+        // Safety: the call panics before a handle is created
+        let _ = unsafe { pool.from_raw_index(2) };
     }
 
-    /// Reset statistics counters.
-    #[cfg(feature = "stats")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
-    pub fn reset_statistics(&self) {
-        self.stats.borrow_mut().reset();
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_preserves_slots() {
+        let pool = FixedPool::new(8).unwrap();
+        let handles: Vec<_> = (0..6)
+            .map(|i| pool.allocate(alloc::format!("object {}", i)).unwrap())
+            .collect();
+        // Leave a sparse pool behind: slots 1, 3 and 4 stay live
+        let live: Vec<_> = handles
+            .into_iter()
+            .filter(|handle| matches!(handle.index(), 1 | 3 | 4))
+            .collect();
+
+        let json = serde_json::to_string(&pool).unwrap();
+        let mut restored: FixedPool<alloc::string::String> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.capacity(), 8);
+        assert_eq!(restored.allocated(), 3);
+        assert_eq!(
+            restored.live_indices().collect::<Vec<_>>(),
+            pool.live_indices().collect::<Vec<_>>()
+        );
+        for handle in &live {
+            assert_eq!(restored.get_by_index(handle.index()), Some(&**handle));
+        }
+
+        // Restored slots are owned by the pool; new allocations use free slots
+        let extra = restored.allocate(alloc::string::String::new()).unwrap();
+        assert!(!matches!(extra.index(), 1 | 3 | 4));
     }
-}
 
-impl<T> Drop for FixedPool<T> {
-    fn drop(&mut self) {
-        // Clean up any remaining allocated objects
-        // Note: In this implementation, objects are dropped when handles are dropped
-        // The pool itself doesn't need additional cleanup
-        let _allocator = self.allocator.borrow();
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize_rejects_out_of_range_slots() {
+        let json = r#"{"capacity":2,"slots":{"5":1}}"#;
+        let error = serde_json::from_str::<FixedPool<u32>>(json)
+            .err()
+            .expect("slot 5 does not fit");
+        assert!(alloc::string::ToString::to_string(&error).contains("out of range"));
+    }
+
+    #[test]
+    fn pressure_tracks_utilization() {
+        let pool = FixedPool::new(4).unwrap();
+        assert!(!pool.is_under_pressure(0.0));
+
+        let mut handles = Vec::new();
+        handles.push(pool.allocate(0).unwrap());
+        assert!(pool.is_under_pressure(0.0));
+        assert!(!pool.is_under_pressure(0.25)); // exactly at the threshold
+
+        handles.extend((1..3).map(|i| pool.allocate(i).unwrap()));
+        assert!(pool.is_under_pressure(0.5));
+        assert!(!pool.is_under_pressure(0.75));
 
-        // All handles should be dropped before the pool
-        // Any remaining objects will be dropped with the storage Vec
+        handles.push(pool.allocate(3).unwrap());
+        assert!(pool.is_under_pressure(0.99));
+        assert!(!pool.is_under_pressure(1.0));
+
+        handles.clear();
+        assert!(!pool.is_under_pressure(0.0));
     }
-}
 
-// Safety: FixedPool is Send if T is Send (storage is behind RefCell)
-unsafe impl<T: Send> Send for FixedPool<T> {}
+    #[test]
+    fn custom_allocator_drives_slot_choice() {
+        use crate::allocator::SlotAllocator;
+        use alloc::collections::BTreeSet;
 
-// Note: FixedPool is NOT Sync because it uses RefCell internally
-// Use ThreadSafePool for concurrent access
+        /// Always hands out the lowest free index.
+        struct LowestFirst {
+            free: BTreeSet<usize>,
+            capacity: usize,
+        }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        // SAFETY: indices come from a set of `0..capacity` and go back
+        // into it only on `free`.
+        unsafe impl SlotAllocator for LowestFirst {
+            fn allocate(&mut self) -> Option<usize> {
+                let index = *self.free.iter().next()?;
+                self.free.remove(&index);
+                Some(index)
+            }
+
+            fn free(&mut self, index: usize) {
+                assert!(self.free.insert(index), "double free of slot {}", index);
+            }
+
+            fn available(&self) -> usize {
+                self.free.len()
+            }
+
+            fn capacity(&self) -> usize {
+                self.capacity
+            }
+
+            fn is_allocated(&self, index: usize) -> bool {
+                !self.free.contains(&index)
+            }
+        }
+
+        let allocator = LowestFirst {
+            free: (0..4).collect(),
+            capacity: 4,
+        };
+        let pool = FixedPool::with_allocator(4, Box::new(allocator)).unwrap();
+
+        let handles: Vec<_> = (0..4).map(|i| pool.allocate(i * 10).unwrap()).collect();
+        assert!(pool.is_full());
+        let mut handles = handles.into_iter();
+        let first = handles.next().unwrap();
+        let _second = handles.next().unwrap();
+        let third = handles.next().unwrap();
+        drop((third, first));
+
+        // Lowest index first, regardless of release order
+        assert_eq!(pool.allocate(7).unwrap().index(), 0);
+        let reused = pool.allocate(8).unwrap();
+        assert_eq!((reused.index(), *reused), (0, 8));
+        assert_eq!(pool.allocate(9).unwrap().index(), 2);
+    }
 
     #[test]
-    fn new_pool() {
-        let pool = FixedPool::<i32>::new(100).unwrap();
-        assert_eq!(pool.capacity(), 100);
-        assert_eq!(pool.available(), 100);
-        assert_eq!(pool.allocated(), 0);
-        assert!(pool.is_empty());
-        assert!(!pool.is_full());
+    fn with_allocator_validates_the_allocator() {
+        assert!(FixedPool::<u8>::with_allocator(4, crate::allocator::stack(8)).is_err());
+        assert!(FixedPool::<u8>::with_allocator(0, crate::allocator::stack(0)).is_err());
+
+        let mut used = crate::allocator::free_list(4);
+        used.allocate();
+        assert!(FixedPool::<u8>::with_allocator(4, used).is_err());
     }
 
     #[test]
-    fn allocate_and_drop() {
-        let pool = FixedPool::new(10).unwrap();
+    fn prefilled_pool_owns_every_value() {
+        let values: Vec<_> = ["zero", "one", "two"]
+            .map(alloc::string::String::from)
+            .into();
+        let mut pool = FixedPool::prefilled(values).unwrap();
+
+        assert_eq!(pool.capacity(), 3);
+        assert!(pool.is_full());
+        assert_eq!(pool.get_by_index(0).map(|s| s.as_str()), Some("zero"));
+        assert_eq!(pool.get_by_index(2).map(|s| s.as_str()), Some("two"));
+        assert_eq!(pool.get_by_index(3), None);
+
+        // Safety: slot 1 is allocated and no handle owns it
+        let one = unsafe { pool.from_raw_index(1) };
+        assert_eq!(pool.take(one), "one");
+        assert_eq!(pool.available(), 1);
+
+        let rest: Vec<_> = pool.into_iter().collect();
+        assert_eq!(rest, ["zero", "two"]);
+    }
+
+    #[test]
+    fn prefilled_rejects_empty_values() {
+        assert!(FixedPool::<u8>::prefilled(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn configured_name_appears_in_errors_and_statistics() {
+        use alloc::string::ToString;
+
+        let config = PoolConfig::builder()
+            .capacity(1)
+            .name("connection_pool")
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+        let _held = pool.allocate(1).unwrap();
 
+        let err = pool.allocate(2).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::PoolExhausted {
+                name: Some("connection_pool"),
+                ..
+            }
+        ));
+        assert!(err
+            .to_string()
+            .starts_with("Pool 'connection_pool' exhausted"));
+
+        #[cfg(feature = "stats")]
         {
-            let handle = pool.allocate(42).unwrap();
-            assert_eq!(*handle, 42);
-            assert_eq!(pool.allocated(), 1);
-            assert_eq!(pool.available(), 9);
+            let stats = pool.statistics();
+            assert_eq!(stats.name, Some("connection_pool"));
+            assert!(stats.to_string().contains("connection_pool"));
         }
+    }
 
-        // After drop, should be returned to pool
-        assert_eq!(pool.allocated(), 0);
-        assert_eq!(pool.available(), 10);
+    #[test]
+    #[cfg(all(feature = "std", debug_assertions))]
+    fn leaked_handle_reports_allocation_site() {
+        use std::sync::{Arc, Mutex};
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let config = PoolConfig::builder()
+            .capacity(4)
+            .observer(move |event| {
+                if let PoolEvent::Leaked { .. } = event {
+                    sink.lock().unwrap().push(event);
+                }
+            })
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+
+        drop(pool.allocate(1).unwrap());
+        let (leaked, line) = (pool.allocate(2).unwrap(), line!());
+        let index = leaked.index();
+        mem::forget(leaked);
+        drop(pool);
+
+        let events = events.lock().unwrap();
+        let [PoolEvent::Leaked {
+            index: leaked_index,
+            location: Some(location),
+        }] = events[..]
+        else {
+            panic!("expected one leak with a location, got {:?}", events);
+        };
+        assert_eq!(leaked_index, index);
+        assert_eq!(location.file(), file!());
+        assert_eq!(location.line(), line);
     }
 
     #[test]
-    fn allocate_until_full() {
-        let pool = FixedPool::new(3).unwrap();
+    fn swap_exchanges_values_between_slots() {
+        use alloc::vec;
 
-        let _h1 = pool.allocate(1).unwrap();
-        let _h2 = pool.allocate(2).unwrap();
-        let _h3 = pool.allocate(3).unwrap();
+        let mut pool = FixedPool::new(4).unwrap();
+        let mut a = pool.allocate(vec![1, 2]).unwrap();
+        let mut b = pool.allocate(vec![3]).unwrap();
+        let (index_a, index_b) = (a.index(), b.index());
+
+        pool.swap(&mut a, &mut b);
+        assert_eq!((&*a, &*b), (&vec![3], &vec![1, 2]));
+        assert_eq!((a.index(), b.index()), (index_a, index_b));
+
+        let (index_a, index_b) = (a.into_raw(), b.into_raw());
+        assert_eq!(pool.get_by_index(index_a), Some(&vec![3]));
+        // Safety: both indices came from into_raw and are re-wrapped once
+        let (a, b) = unsafe { (pool.from_raw_index(index_a), pool.from_raw_index(index_b)) };
+
+        // Each slot now drops the value that moved into it
+        drop(a);
+        assert_eq!(*b, [1, 2]);
+        drop(b);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "handle belongs to a different pool")]
+    fn swap_rejects_foreign_handles() {
+        let pool = FixedPool::new(2).unwrap();
+        let other = FixedPool::new(2).unwrap();
+        let mut a = pool.allocate(1).unwrap();
+        let mut b = other.allocate(2).unwrap();
+        pool.swap(&mut a, &mut b);
+    }
+
+    #[test]
+    fn retain_frees_objects_failing_predicate() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static RELEASED: AtomicUsize = AtomicUsize::new(0);
+
+        struct Tracked(u32);
+        impl Poolable for Tracked {
+            fn on_release(&mut self) {
+                RELEASED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let mut pool = FixedPool::new(10).unwrap();
+        let indices: Vec<usize> = (0..10)
+            .map(|i| pool.allocate(Tracked(i)).unwrap().into_raw())
+            .collect();
+
+        pool.retain(|value| value.0 % 2 == 0);
+
+        assert_eq!(pool.allocated(), 5);
+        assert_eq!(RELEASED.load(Ordering::Relaxed), 5);
+        for (i, &index) in indices.iter().enumerate() {
+            match pool.get_by_index(index) {
+                Some(value) => assert_eq!(value.0, i as u32),
+                None => assert_eq!(i % 2, 1),
+            }
+        }
 
+        // Freed slots are reusable, and survivors are released exactly once
+        let handles: Vec<_> = (0..5).map(|i| pool.allocate(Tracked(i)).unwrap()).collect();
         assert!(pool.is_full());
+        drop(handles);
+        for index in indices.into_iter().step_by(2) {
+            drop(unsafe { pool.from_raw_index(index) });
+        }
+        assert!(pool.is_empty());
+        assert_eq!(RELEASED.load(Ordering::Relaxed), 15);
+    }
 
-        let result = pool.allocate(4);
-        assert!(matches!(result, Err(Error::PoolExhausted { .. })));
+    #[test]
+    fn pinned_handle_keeps_its_address() {
+        let pool = FixedPool::new(64).unwrap();
+        let mut pinned = pool.allocate(7u64).unwrap().into_pinned().unwrap();
+        let before = &*pinned as *const u64;
+
+        let others: Vec<_> = (0..63).map(|i| pool.allocate(i).unwrap()).collect();
+        *pinned.as_mut().get_mut() += 1;
+
+        assert!(pool.is_full());
+        assert_eq!(&*pinned as *const u64, before);
+        assert_eq!(*pinned, 8);
+        drop(others);
     }
 
     #[test]
-    fn reuse_after_free() {
+    fn leaked_pinned_objects_are_dropped_in_place() {
+        use core::cell::Cell;
+        use core::marker::PhantomPinned;
+
+        #[derive(Debug)]
+        struct Anchor<'a> {
+            dropped_at: &'a Cell<usize>,
+            _pin: PhantomPinned,
+        }
+        impl Drop for Anchor<'_> {
+            fn drop(&mut self) {
+                self.dropped_at.set(self as *mut Self as usize);
+            }
+        }
+        impl Poolable for Anchor<'_> {}
+
+        let dropped_at = Cell::new(0);
+        let anchor = || Anchor {
+            dropped_at: &dropped_at,
+            _pin: PhantomPinned,
+        };
+
         let pool = FixedPool::new(2).unwrap();
+        let pinned = pool.allocate(anchor()).unwrap().into_pinned().unwrap();
+        let address = &*pinned as *const Anchor<'_> as usize;
+        mem::forget(pinned);
+        assert_eq!(pool.into_iter().count(), 0);
+        assert_eq!(dropped_at.get(), address);
 
-        let h1 = pool.allocate(1).unwrap();
-        drop(h1);
+        let pool = FixedPool::new(2).unwrap();
+        let pinned = pool.allocate(anchor()).unwrap().into_pinned().unwrap();
+        let address = &*pinned as *const Anchor<'_> as usize;
+        mem::forget(pinned);
 
-        let h2 = pool.allocate(2).unwrap();
-        assert_eq!(*h2, 2);
+        // A slot that was pinned once is moved normally once reused unpinned
+        drop(pool.allocate(anchor()).unwrap().into_pinned().unwrap());
+        mem::forget(pool.allocate(anchor()).unwrap());
+        let growing = pool.into_growing(GrowthStrategy::Linear { amount: 2 });
+        assert_eq!(dropped_at.get(), address);
+        assert_eq!(growing.allocated(), 1);
     }
 
     #[test]
-    fn modify_value() {
-        let pool = FixedPool::new(10).unwrap();
+    fn pinned_objects_are_not_recycled() {
+        let config = PoolConfig::builder()
+            .capacity(2)
+            .reset_fn(|| Vec::<u8>::with_capacity(16), Vec::clear)
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
 
-        let mut handle = pool.allocate(10).unwrap();
-        assert_eq!(*handle, 10);
+        let pinned = pool.allocate_recycled().unwrap().into_pinned().unwrap();
+        drop(pinned);
+        assert!(pool.recycled.borrow().iter().all(|&primed| !primed));
 
-        *handle = 20;
-        assert_eq!(*handle, 20);
+        let unpinned = pool.allocate_recycled().unwrap();
+        let index = unpinned.index();
+        drop(unpinned);
+        assert!(pool.recycled.borrow()[index]);
+    }
+
+    #[test]
+    fn memory_footprint_counts_owned_storage_only() {
+        let pool = FixedPool::<[u8; 128]>::new(32).unwrap();
+        let footprint = pool.memory_footprint();
+        assert!(footprint >= 32 * 128);
+        assert!(footprint < 32 * 128 + 32 * 64);
+
+        let mut buffer = [MaybeUninit::<[u8; 128]>::uninit(); 32];
+        // Safety: the buffer outlives the pool and is untouched meanwhile
+        let borrowed = unsafe { FixedPool::from_raw_parts(buffer.as_mut_ptr(), 32, 1) }.unwrap();
+        assert!(borrowed.memory_footprint() < 32 * 128);
+    }
+
+    #[test]
+    fn uninit_handle_write_and_abandon() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static ACQUIRED: AtomicUsize = AtomicUsize::new(0);
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        struct Tracked(u32);
+        impl Poolable for Tracked {
+            fn on_acquire(&mut self) {
+                ACQUIRED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let pool = FixedPool::new(2).unwrap();
+
+        let handle = pool.allocate_uninit().unwrap().write(Tracked(1));
+        let mut slot = pool.allocate_uninit().unwrap();
+        slot.as_uninit_mut().write(Tracked(2));
+        let other = unsafe { slot.assume_init() };
+        assert_eq!((handle.0, other.0), (1, 2));
+        assert!(pool.is_full());
+        assert_eq!(ACQUIRED.load(Ordering::Relaxed), 0);
+
+        drop((handle, other));
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 2);
+
+        // Dropping an unfinished reservation frees the slot without dropping
+        let slot = pool.allocate_uninit().unwrap();
+        assert_eq!(pool.allocated(), 1);
+        drop(slot);
+        assert!(pool.is_empty());
+        assert_eq!(DROPPED.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn reserved_slots_stay_hidden_until_initialized() {
+        let mut pool = FixedPool::new(4).unwrap();
+        let kept = pool
+            .allocate(alloc::string::String::from("kept"))
+            .unwrap()
+            .into_raw();
+
+        let slot = pool.allocate_uninit().unwrap();
+        assert_eq!(pool.allocated(), 2);
+        assert_eq!(pool.live_indices().collect::<Vec<_>>(), [kept]);
+        let handle = slot.write(alloc::string::String::from("written"));
+        assert_eq!(pool.live_indices().count(), 2);
+        drop(handle);
+
+        // The slot is reserved while the constructor runs
+        let seen = pool
+            .allocate_try_with(|| Ok::<_, ()>(alloc::format!("{}", pool.live_indices().count())))
+            .unwrap()
+            .unwrap()
+            .into_raw();
+        assert_eq!(pool.get_by_index(seen).map(|s| s.as_str()), Some("1"));
+        drop(unsafe { pool.from_raw_index(seen) });
+
+        // A leaked reservation is never read, yielded or dropped
+        let leaked = pool.allocate_uninit().unwrap();
+        let index = leaked.index();
+        mem::forget(leaked);
+        assert_eq!(pool.get_by_index(index), None);
+        assert_eq!(pool.clone().allocated(), 1);
+        assert_eq!(pool.into_iter().collect::<Vec<_>>(), ["kept"]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize_rejects_unallocatable_capacities() {
+        let error = |json: &str| serde_json::from_str::<FixedPool<u64>>(json).err().unwrap();
+
+        let huge = alloc::format!(r#"{{"capacity":{},"slots":{{}}}}"#, usize::MAX / 4);
+        assert!(error(&huge).to_string().contains("too large"));
+
+        // Addressable, but far beyond any real allocation
+        let failed = error(r#"{"capacity":1000000000000,"slots":{}}"#);
+        assert!(failed.to_string().contains("allocation of"), "{}", failed);
+
+        let overfull = error(r#"{"capacity":1,"slots":{"0":1,"1":2}}"#);
+        assert!(overfull.to_string().contains("do not fit"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_skips_reserved_slots() {
+        let pool = FixedPool::<u32>::new(2).unwrap();
+        let _slot = pool.allocate_uninit().unwrap();
+        assert_eq!(
+            serde_json::to_string(&pool).unwrap(),
+            r#"{"capacity":2,"slots":{}}"#
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn watermarks_fire_once_per_crossing() {
+        use std::sync::{Arc, Mutex};
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let (high, low) = (Arc::clone(&events), Arc::clone(&events));
+        let config = PoolConfig::builder()
+            .capacity(10)
+            .on_high_watermark(0.8, move |usage| high.lock().unwrap().push(("high", usage)))
+            .on_low_watermark(0.2, move |usage| low.lock().unwrap().push(("low", usage)))
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+
+        // Crossing the high watermark fires once
+        let mut handles: Vec<_> = (0..9).map(|i| pool.allocate(i).unwrap()).collect();
+        assert_eq!(*events.lock().unwrap(), [("high", 9)]);
+
+        // Staying above it, or between the watermarks, does not fire again
+        handles.push(pool.allocate(9).unwrap());
+        handles.truncate(3);
+        handles.push(pool.allocate(10).unwrap());
+        assert_eq!(events.lock().unwrap().len(), 1);
+
+        // Dropping below the low watermark fires once
+        handles.truncate(1);
+        handles.clear();
+        assert_eq!(*events.lock().unwrap(), [("high", 9), ("low", 1)]);
+
+        // The high watermark is armed again
+        let _handles: Vec<_> = (0..9).map(|i| pool.allocate(i).unwrap()).collect();
+        assert_eq!(events.lock().unwrap().last(), Some(&("high", 9)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn lone_high_watermark_rearms_below_its_ratio() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let fired = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&fired);
+        let config = PoolConfig::builder()
+            .capacity(4)
+            .on_high_watermark(0.5, move |_| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            })
+            .build()
+            .unwrap();
+        let pool = FixedPool::with_config(config).unwrap();
+
+        for _ in 0..3 {
+            let handles: Vec<_> = (0..3).map(|i| pool.allocate(i).unwrap()).collect();
+            drop(handles);
+        }
+        assert_eq!(fired.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn allocated_and_reserved_bytes_use_padded_stride() {
+        #[repr(align(32))]
+        struct Padded(u8);
+        impl Poolable for Padded {}
+
+        let pool = FixedPool::new(4).unwrap();
+        assert_eq!((pool.allocated_bytes(), pool.reserved_bytes()), (0, 128));
+
+        let handles: Vec<_> = (0..3).map(|i| pool.allocate(Padded(i)).unwrap()).collect();
+        assert_eq!(pool.allocated_bytes(), 3 * 32);
+        assert!(pool.allocated_bytes() <= pool.reserved_bytes());
+        assert!(pool.reserved_bytes() <= pool.memory_footprint());
+        assert_eq!(handles[2].0, 2);
+
+        // Stride is padded past the one byte of payload
+        assert!(pool.reserved_bytes() / pool.capacity() > mem::size_of::<u8>());
+    }
+
+    #[test]
+    fn configured_alignment_does_not_pad_slots() {
+        let config = PoolConfig::builder()
+            .capacity(4)
+            .alignment(64)
+            .build()
+            .unwrap();
+        let pool = FixedPool::<u64>::with_config(config).unwrap();
+        let _handle = pool.allocate(1).unwrap();
+
+        assert_eq!(pool.allocated_bytes(), 8);
+        assert_eq!(pool.reserved_bytes(), 32);
     }
 }
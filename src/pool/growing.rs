@@ -1,20 +1,117 @@
 //! Growing memory pool implementation.
 
-use crate::allocator::{Allocator, FreeListAllocator};
-use crate::config::PoolConfig;
+use crate::allocator::{Allocator, BitmapAllocator, FreeListAllocator};
+use crate::config::{PoolConfig, PoolEvent, ShrinkPolicy};
 use crate::error::{Error, Result};
-use crate::handle::{OwnedHandle, PoolInterface};
+use crate::handle::{OwnedHandle, PoolInterface, PoolUsage, SharedHandle};
 use crate::traits::Poolable;
+use crate::utils::secure_zero;
 use alloc::vec;
 use alloc::vec::Vec;
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
 use core::marker::PhantomData;
-use core::mem::MaybeUninit;
+use core::mem::{self, MaybeUninit};
+use core::pin::Pin;
 use core::ptr;
 
 #[cfg(feature = "stats")]
 use crate::stats::PoolStatistics;
 
+#[cfg(all(feature = "std", debug_assertions))]
+use core::sync::atomic::{AtomicU32, Ordering};
+#[cfg(all(feature = "std", debug_assertions))]
+use std::sync::Arc;
+
+/// Generation counter of one slot, shared with the handles allocated from it.
+///
+/// Handles read it without locking the pool, so the counters live in
+/// reference-counted blocks that stay put while the pool grows.
+#[cfg(all(feature = "std", debug_assertions))]
+#[derive(Clone)]
+pub(crate) struct SlotGeneration {
+    block: Arc<[AtomicU32]>,
+    offset: usize,
+}
+
+#[cfg(all(feature = "std", debug_assertions))]
+impl SlotGeneration {
+    /// Reads the slot's current generation.
+    #[inline]
+    pub(crate) fn load(&self) -> u32 {
+        self.block[self.offset].load(Ordering::Acquire)
+    }
+}
+
+/// Generation counters for every slot, one block per growth step.
+#[cfg(all(feature = "std", debug_assertions))]
+struct SlotGenerations {
+    /// First slot index of each block, with the block
+    blocks: Vec<(usize, Arc<[AtomicU32]>)>,
+}
+
+#[cfg(all(feature = "std", debug_assertions))]
+impl SlotGenerations {
+    fn new(capacity: usize) -> Self {
+        let mut generations = Self { blocks: Vec::new() };
+        generations.extend(0, capacity);
+        generations
+    }
+
+    /// Adds counters for the `amount` slots starting at `start`.
+    fn extend(&mut self, start: usize, amount: usize) {
+        let block = (0..amount).map(|_| AtomicU32::new(0)).collect();
+        self.blocks.push((start, block));
+    }
+
+    /// Drops the blocks of slots at or past `capacity`.
+    fn truncate(&mut self, capacity: usize) {
+        self.blocks.retain(|(start, _)| *start < capacity);
+    }
+
+    /// Returns the block holding the counter of slot `index`, and its offset there.
+    fn locate(&self, index: usize) -> (&Arc<[AtomicU32]>, usize) {
+        let block = self.blocks.partition_point(|(start, _)| *start <= index) - 1;
+        let (start, block) = &self.blocks[block];
+        (block, index - start)
+    }
+
+    fn slot(&self, index: usize) -> SlotGeneration {
+        let (block, offset) = self.locate(index);
+        SlotGeneration {
+            block: Arc::clone(block),
+            offset,
+        }
+    }
+
+    /// Advances the generation of slot `index`, marking its handles as stale.
+    fn bump(&self, index: usize) {
+        let (block, offset) = self.locate(index);
+        block[offset].fetch_add(1, Ordering::Release);
+    }
+
+    fn heap_bytes(&self) -> usize {
+        self.blocks.capacity() * mem::size_of::<(usize, Arc<[AtomicU32]>)>()
+            + self
+                .blocks
+                .iter()
+                .map(|(_, block)| mem::size_of::<[usize; 2]>() + mem::size_of_val(&**block))
+                .sum::<usize>()
+    }
+}
+
+/// Index range and occupancy of one storage chunk of a [`GrowingPool`].
+///
+/// Returned by [`GrowingPool::chunk_layout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkInfo {
+    /// Flat index of the first slot in the chunk
+    pub start_index: usize,
+    /// Number of slots in the chunk
+    pub len: usize,
+    /// Number of slots in the chunk holding a live object
+    pub live_count: usize,
+}
+
 /// A memory pool that can grow dynamically based on demand.
 ///
 /// This pool starts with an initial capacity and can grow according to
@@ -41,6 +138,13 @@ use crate::stats::PoolStatistics;
 /// }
 /// ```
 ///
+/// # Contiguous storage
+///
+/// By default each growth adds a separate chunk, so objects never move but
+/// the pool is only contiguous within a chunk. A pool built with
+/// [`PoolConfigBuilder::contiguous`](crate::config::PoolConfigBuilder::contiguous)
+/// reallocates a single region instead and moves existing objects on growth.
+///
 /// # Performance
 ///
 /// - Allocation: < 50ns per object (may spike during growth)
@@ -56,6 +160,13 @@ pub struct GrowingPool<T> {
     capacity: RefCell<usize>,
     /// Cumulative chunk sizes for fast O(log n) chunk lookup
     chunk_boundaries: RefCell<Vec<usize>>,
+    /// Consecutive releases that left the pool above the shrink policy's free ratio
+    idle_releases: Cell<usize>,
+    /// Slots whose object is pinned; empty until a handle is first pinned
+    pinned: RefCell<Vec<bool>>,
+    /// Per-slot generation, bumped on every release, for stale-handle checks
+    #[cfg(all(feature = "std", debug_assertions))]
+    generations: RefCell<SlotGenerations>,
     /// Pool configuration
     config: PoolConfig<T>,
     /// Statistics collector
@@ -88,62 +199,207 @@ impl<T: Poolable> GrowingPool<T> {
         let mut storage_chunk = Vec::with_capacity(capacity);
         storage_chunk.resize_with(capacity, MaybeUninit::uninit);
 
-        let storage = vec![storage_chunk];
+        Ok(Self::from_parts(
+            config,
+            storage_chunk,
+            FreeListAllocator::new(capacity),
+        ))
+    }
 
-        let pool = Self {
-            storage: RefCell::new(storage),
-            allocator: RefCell::new(FreeListAllocator::new(capacity)),
+    /// Assembles a pool whose first chunk is `chunk`, with occupancy tracked by `allocator`.
+    ///
+    /// The chunk length is the initial capacity; every slot the allocator
+    /// marks as allocated must hold an initialized object.
+    pub(crate) fn from_parts(
+        config: PoolConfig<T>,
+        chunk: Vec<MaybeUninit<T>>,
+        allocator: FreeListAllocator,
+    ) -> Self {
+        let capacity = chunk.len();
+        debug_assert_eq!(capacity, allocator.capacity());
+
+        Self {
+            storage: RefCell::new(vec![chunk]),
+            allocator: RefCell::new(allocator),
             capacity: RefCell::new(capacity),
             chunk_boundaries: RefCell::new(vec![capacity]),
+            idle_releases: Cell::new(0),
+            pinned: RefCell::new(Vec::new()),
+            #[cfg(all(feature = "std", debug_assertions))]
+            generations: RefCell::new(SlotGenerations::new(capacity)),
             config,
             #[cfg(feature = "stats")]
             stats: RefCell::new(crate::stats::StatisticsCollector::new(capacity)),
             _marker: PhantomData,
-        };
-
-        Ok(pool)
+        }
     }
 
-    /// Grows the pool by allocating an additional chunk of memory.
-    fn grow(&self) -> Result<()> {
-        let growth_amount = self
+    /// Computes the capacity one growth step from `current_capacity` would reach.
+    ///
+    /// Applies the growth strategy, optional word alignment, and the
+    /// overflow and `max_capacity` checks, without touching the pool. A
+    /// step past `max_capacity` is clamped to it.
+    fn next_capacity(&self, current_capacity: usize) -> Result<usize> {
+        let mut growth_amount = self
             .config
             .growth_strategy()
-            .compute_growth(*self.capacity.borrow());
+            .compute_growth(current_capacity);
 
         if growth_amount == 0 {
             return Err(Error::PoolExhausted {
                 capacity: *self.capacity.borrow(),
-                allocated: *self.capacity.borrow() - self.allocator.borrow().available(),
+                allocated: self.allocated(),
+                can_grow: false,
+                name: self.config.name(),
             });
         }
 
-        let current_capacity = *self.capacity.borrow();
-        let new_capacity = current_capacity + growth_amount;
+        if self.config.align_growth_to_words() {
+            growth_amount = BitmapAllocator::word_aligned_growth(current_capacity, growth_amount);
+        }
 
-        // Check max capacity constraint
         if let Some(max) = self.config.max_capacity() {
-            if new_capacity > max {
+            if current_capacity.saturating_add(growth_amount) > max {
+                // Cut a step that overshoots the maximum short, so the pool
+                // still fills up to it and only fails once it is reached
+                if current_capacity < max {
+                    return Ok(max);
+                }
                 return Err(Error::MaxCapacityExceeded {
                     current: current_capacity,
-                    requested: new_capacity,
+                    requested: current_capacity.saturating_add(growth_amount),
                     max,
                 });
             }
         }
 
-        // Allocate new storage chunk
-        let mut new_chunk = Vec::with_capacity(growth_amount);
-        new_chunk.resize_with(growth_amount, MaybeUninit::uninit);
+        let new_capacity =
+            current_capacity
+                .checked_add(growth_amount)
+                .ok_or(Error::CapacityOverflow {
+                    current: current_capacity,
+                    additional: growth_amount,
+                })?;
+
+        Ok(new_capacity)
+    }
+
+    /// Predicts the capacity needed to hold `additional` more objects, without growing.
+    ///
+    /// Simulates the growth strategy from the current capacity until at
+    /// least `additional` slots would be free, and returns the resulting
+    /// capacity. Returns the current capacity if there is already room, and
+    /// `None` if the pool cannot grow far enough, e.g. because it would
+    /// exceed `max_capacity`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{GrowingPool, GrowthStrategy, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(10)
+    ///     .growth_strategy(GrowthStrategy::Exponential { factor: 2.0 })
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = GrowingPool::<i32>::with_config(config).unwrap();
+    ///
+    /// assert_eq!(pool.predict_capacity_for(25), Some(40));
+    /// assert_eq!(pool.capacity(), 10);
+    /// ```
+    pub fn predict_capacity_for(&self, additional: usize) -> Option<usize> {
+        self.capacity_for(additional).ok()
+    }
+
+    /// Simulates the growth strategy until `additional` more objects fit,
+    /// returning the error that growth would fail with otherwise.
+    fn capacity_for(&self, additional: usize) -> Result<usize> {
+        let mut capacity = self.capacity();
+        let needed = self
+            .allocated()
+            .checked_add(additional)
+            .ok_or(Error::CapacityOverflow {
+                current: capacity,
+                additional,
+            })?;
+
+        while capacity < needed {
+            capacity = self.next_capacity(capacity)?;
+        }
+
+        Ok(capacity)
+    }
+
+    /// Grows the pool by one step of its growth strategy.
+    ///
+    /// A pool already at its `max_capacity` reports `Error::PoolExhausted`
+    /// with `can_grow: false`, like a fixed pool.
+    fn grow(&self) -> Result<()> {
+        let current_capacity = *self.capacity.borrow();
+        let new_capacity = match self.next_capacity(current_capacity) {
+            Err(Error::MaxCapacityExceeded { .. }) => return Err(self.exhausted()),
+            result => result?,
+        };
+        self.grow_to(new_capacity)
+    }
+
+    /// Builds the error for a full pool, noting whether it could still grow.
+    fn exhausted(&self) -> Error {
+        Error::PoolExhausted {
+            capacity: self.capacity(),
+            allocated: self.allocated(),
+            can_grow: self.can_grow(),
+            name: self.config.name(),
+        }
+    }
+
+    /// Grows the pool to `new_capacity` by allocating an additional chunk of
+    /// memory, or by reallocating the single storage region for contiguous
+    /// pools.
+    ///
+    /// Storage is reserved with `try_reserve_exact`, so a failing system
+    /// allocator leaves the pool unchanged and reports
+    /// `Error::AllocationFailed` instead of aborting.
+    fn grow_to(&self, new_capacity: usize) -> Result<()> {
+        let current_capacity = *self.capacity.borrow();
+        let growth_amount = new_capacity - current_capacity;
+        let failed = |_| Error::AllocationFailed {
+            bytes: growth_amount.saturating_mul(mem::size_of::<T>()),
+        };
+
+        if self.config.contiguous() {
+            // Reallocate the single storage region; `MaybeUninit` slots are
+            // moved bytewise, so initialized objects keep their contents
+            let mut storage = self.storage.borrow_mut();
+            let region = &mut storage[0];
+            region.try_reserve_exact(growth_amount).map_err(failed)?;
+            region.resize_with(new_capacity, MaybeUninit::uninit);
+
+            self.chunk_boundaries.borrow_mut()[0] = new_capacity;
+        } else {
+            // Allocate new storage chunk
+            let mut new_chunk = Vec::new();
+            new_chunk.try_reserve_exact(growth_amount).map_err(failed)?;
+            new_chunk.resize_with(growth_amount, MaybeUninit::uninit);
+
+            self.storage.borrow_mut().push(new_chunk);
+            self.chunk_boundaries.borrow_mut().push(new_capacity);
+        }
 
-        self.storage.borrow_mut().push(new_chunk);
         self.allocator.borrow_mut().extend(growth_amount);
         *self.capacity.borrow_mut() = new_capacity;
-        self.chunk_boundaries.borrow_mut().push(new_capacity);
+
+        #[cfg(all(feature = "std", debug_assertions))]
+        self.generations
+            .borrow_mut()
+            .extend(current_capacity, growth_amount);
 
         #[cfg(feature = "stats")]
         self.stats.borrow_mut().record_growth(new_capacity);
 
+        self.config.notify(PoolEvent::Grew { new_capacity });
+        self.config.notify_grow(current_capacity, new_capacity);
+
         Ok(())
     }
 
@@ -169,83 +425,176 @@ impl<T: Poolable> GrowingPool<T> {
     /// // Pool will grow automatically
     /// let h3 = pool.allocate(3).unwrap();
     /// ```
-    pub fn allocate(&self, mut value: T) -> Result<OwnedHandle<'_, T>> {
-        // Try to allocate a slot
-        let index = {
-            let mut allocator = self.allocator.borrow_mut();
-            if let Some(idx) = allocator.allocate() {
-                idx
-            } else {
-                // Drop the borrow before growing
-                drop(allocator);
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` with `can_grow: false` if the pool is
+    /// full and already at its maximum capacity, and `Error::AllocationFailed` if the system
+    /// allocator cannot provide the storage for growth. The pool is left
+    /// unchanged and usable in both cases.
+    #[must_use = "dropping the handle immediately returns the object to the pool"]
+    pub fn allocate(&self, value: T) -> Result<OwnedHandle<'_, T>> {
+        let index = self.allocate_index(value)?;
+        Ok(OwnedHandle::new(self, index))
+    }
 
-                // Pool is full, try to grow
-                self.grow()?;
+    /// Allocates an object from the pool and returns a reference-counted handle.
+    ///
+    /// The slot is returned to the pool exactly once, when the last
+    /// `SharedHandle` pointing at it is dropped. The pool grows on demand
+    /// just like [`GrowingPool::allocate`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{GrowingPool, PoolConfig, GrowthStrategy};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(1)
+    ///     .growth_strategy(GrowthStrategy::Linear { amount: 1 })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let pool = GrowingPool::with_config(config).unwrap();
+    /// let shared = pool.allocate_shared(7).unwrap();
+    /// let other = shared.clone();
+    ///
+    /// drop(shared);
+    /// assert_eq!(pool.allocated(), 1);
+    /// drop(other);
+    /// assert_eq!(pool.allocated(), 0);
+    /// ```
+    pub fn allocate_shared(&self, value: T) -> Result<SharedHandle<'_, T>> {
+        let index = self.allocate_index(value)?;
+        SharedHandle::new(self, index)
+    }
 
-                // Try again after growth
-                self.allocator
-                    .borrow_mut()
-                    .allocate()
-                    .ok_or_else(|| Error::PoolExhausted {
-                        capacity: *self.capacity.borrow(),
-                        allocated: *self.capacity.borrow(),
-                    })?
-            }
-        };
+    /// Allocates every value of `values`, growing at most once.
+    ///
+    /// Allocating a batch one value at a time grows the pool once per
+    /// strategy step it overflows. This instead computes the capacity the
+    /// growth strategy would reach for the whole batch, as
+    /// [`predict_capacity_for`](Self::predict_capacity_for) does, and adds it
+    /// in a single growth, so a large batch produces one chunk rather than
+    /// many small ones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{GrowingPool, GrowthStrategy, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(10)
+    ///     .growth_strategy(GrowthStrategy::Exponential { factor: 2.0 })
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = GrowingPool::with_config(config).unwrap();
+    ///
+    /// let handles = pool.allocate_batch((0..100).collect()).unwrap();
+    /// assert_eq!(*handles[99], 99);
+    /// assert_eq!(pool.capacity(), 160);
+    /// assert_eq!(pool.chunk_layout().len(), 2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`allocate`](Self::allocate) if the pool cannot grow
+    /// enough for the whole batch, e.g. because it would exceed
+    /// `max_capacity`. Nothing is allocated in that case and the values are
+    /// dropped.
+    pub fn allocate_batch(&self, values: Vec<T>) -> Result<Vec<OwnedHandle<'_, T>>> {
+        if values.len() > self.available() {
+            let new_capacity = self.capacity_for(values.len())?;
+            self.grow_to(new_capacity)?;
+        }
 
-        #[cfg(feature = "stats")]
-        self.stats.borrow_mut().record_allocation();
+        let mut handles = Vec::with_capacity(values.len());
+        for value in values {
+            // Room was made above; should this still fail, dropping the
+            // handles releases the values allocated so far
+            handles.push(self.allocate(value)?);
+        }
 
-        // Call on_acquire hook
-        value.on_acquire();
+        Ok(handles)
+    }
 
-        // Find which chunk and offset, then write the value
-        {
-            let mut storage = self.storage.borrow_mut();
-            let mut remaining = index;
-            let mut found = false;
+    /// Allocates an object whose address is guaranteed not to change until it is dropped.
+    ///
+    /// A chunked growing pool never moves existing objects: growth pushes a
+    /// new storage chunk and leaves earlier chunks in place, and a slot is
+    /// only reused after its object has been dropped. The handle can
+    /// therefore be pinned, which allows pooling self-referential types such
+    /// as futures. If the handle is leaked, dropping the pool drops the
+    /// object in place before its chunk is freed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if the pool was built with
+    /// [`contiguous`](crate::config::PoolConfigBuilder::contiguous) storage, because
+    /// growth would move the object. Whether a pool is contiguous is a
+    /// runtime setting, so this is checked before anything is allocated
+    /// rather than at compile time. Otherwise fails like
+    /// [`allocate`](Self::allocate).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{GrowingPool, GrowthStrategy, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(1)
+    ///     .growth_strategy(GrowthStrategy::Linear { amount: 1 })
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = GrowingPool::with_config(config).unwrap();
+    ///
+    /// let pinned = pool.allocate_pinned(5).unwrap();
+    /// assert_eq!(*pinned, 5);
+    /// ```
+    pub fn allocate_pinned(&self, value: T) -> Result<Pin<OwnedHandle<'_, T>>> {
+        if self.config.contiguous() {
+            return Err(Error::invalid_config(
+                "pinned allocation requires chunked storage, contiguous pools move objects on growth",
+            ));
+        }
 
-            for chunk in storage.iter_mut() {
-                if remaining < chunk.len() {
-                    chunk[remaining].write(value);
-                    found = true;
-                    break;
-                }
-                remaining -= chunk.len();
-            }
+        let handle = self.allocate(value)?;
+        self.mark_pinned(handle.index());
+        // Safety: chunked storage never moves an object while it is live, the
+        // handle drops the object in place before its slot is reused, and the
+        // pool drops it in place if the handle is leaked
+        Ok(unsafe { Pin::new_unchecked(handle) })
+    }
 
-            if !found {
-                panic!("Index out of bounds: {}", index);
-            }
+    /// Records that the object in slot `index` has been pinned.
+    pub(crate) fn mark_pinned(&self, index: usize) {
+        let mut pinned = self.pinned.borrow_mut();
+        if pinned.len() < self.capacity() {
+            pinned.resize(self.capacity(), false);
         }
-
-        Ok(OwnedHandle::new(self, index))
+        pinned[index] = true;
     }
 
     /// Internal allocation method that returns just the index.
     ///
     /// This is used by thread-safe wrappers to allocate without creating a handle.
-    pub(crate) fn allocate_internal(&mut self, mut value: T) -> Result<usize> {
-        // Try to allocate a slot
-        let index = {
-            let mut allocator = self.allocator.borrow_mut();
-            if let Some(idx) = allocator.allocate() {
-                idx
-            } else {
-                // Drop the borrow before growing
-                drop(allocator);
+    #[cfg(feature = "std")]
+    pub(crate) fn allocate_internal(&mut self, value: T) -> Result<usize> {
+        self.allocate_index(value)
+    }
 
-                // Pool is full, try to grow
+    /// Reserves a slot (growing if needed), writes the value and returns the index.
+    fn allocate_index(&self, mut value: T) -> Result<usize> {
+        // Bind the result so the allocator borrow ends before growing
+        let reserved = self.allocator.borrow_mut().allocate();
+        let index = match reserved {
+            Some(index) => index,
+            None => {
                 self.grow()?;
-
-                // Try again after growth
                 self.allocator
                     .borrow_mut()
                     .allocate()
-                    .ok_or_else(|| Error::PoolExhausted {
-                        capacity: *self.capacity.borrow(),
-                        allocated: *self.capacity.borrow(),
-                    })?
+                    .ok_or_else(|| self.exhausted())?
             }
         };
 
@@ -253,26 +602,14 @@ impl<T: Poolable> GrowingPool<T> {
         self.stats.borrow_mut().record_allocation();
 
         // Call on_acquire hook
-        value.on_acquire();
+        self.config.acquire(&mut value);
 
-        // Find which chunk and offset, then write the value
-        {
-            let mut storage = self.storage.borrow_mut();
-            let mut remaining = index;
-            let mut found = false;
-
-            for chunk in storage.iter_mut() {
-                if remaining < chunk.len() {
-                    chunk[remaining].write(value);
-                    found = true;
-                    break;
-                }
-                remaining -= chunk.len();
-            }
+        let (chunk_idx, offset) = self.compute_chunk_location(index);
+        self.storage.borrow_mut()[chunk_idx][offset].write(value);
 
-            if !found {
-                panic!("Index out of bounds: {}", index);
-            }
+        if self.config.has_observer() {
+            let usage = self.allocated();
+            self.config.notify(PoolEvent::Allocated { index, usage });
         }
 
         Ok(index)
@@ -331,6 +668,221 @@ impl<T: Poolable> GrowingPool<T> {
         self.allocator.borrow().is_empty()
     }
 
+    /// Returns the number of heap bytes the pool currently holds.
+    ///
+    /// Sums the allocated size of every storage chunk and the pool's
+    /// bookkeeping, including the chunk boundaries and the allocator, so it
+    /// follows growth as it happens. Memory owned by the pooled objects
+    /// themselves is not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{GrowingPool, GrowthStrategy, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(4)
+    ///     .growth_strategy(GrowthStrategy::Linear { amount: 4 })
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = GrowingPool::<u64>::with_config(config).unwrap();
+    /// let before = pool.memory_footprint();
+    ///
+    /// let handles: Vec<_> = (0..5).map(|i| pool.allocate(i).unwrap()).collect();
+    /// assert!(pool.memory_footprint() >= before + 4 * 8);
+    /// ```
+    pub fn memory_footprint(&self) -> usize {
+        let storage = self.storage.borrow();
+        let chunks: usize = storage
+            .iter()
+            .map(|chunk| chunk.capacity() * mem::size_of::<MaybeUninit<T>>())
+            .sum();
+
+        #[cfg(all(feature = "std", debug_assertions))]
+        let generations = self.generations.borrow().heap_bytes();
+        #[cfg(not(all(feature = "std", debug_assertions)))]
+        let generations = 0;
+
+        chunks
+            + storage.capacity() * mem::size_of::<Vec<MaybeUninit<T>>>()
+            + self.chunk_boundaries.borrow().capacity() * mem::size_of::<usize>()
+            + self.allocator.borrow().heap_bytes()
+            + self.pinned.borrow().capacity() * mem::size_of::<bool>()
+            + generations
+    }
+
+    /// Returns the index range and live object count of each storage chunk.
+    ///
+    /// The initial allocation is the first chunk and every growth adds one
+    /// more, unless the pool uses contiguous storage, in which case there is
+    /// always a single chunk. A late chunk with a low `live_count` is a sign
+    /// that most of its memory is idle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{GrowingPool, GrowthStrategy, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(2)
+    ///     .growth_strategy(GrowthStrategy::Linear { amount: 4 })
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = GrowingPool::with_config(config).unwrap();
+    /// let handles: Vec<_> = (0..3).map(|i| pool.allocate(i).unwrap()).collect();
+    ///
+    /// let layout = pool.chunk_layout();
+    /// assert_eq!(layout.len(), 2);
+    /// assert_eq!((layout[1].start_index, layout[1].len, layout[1].live_count), (2, 4, 1));
+    /// ```
+    pub fn chunk_layout(&self) -> Vec<ChunkInfo> {
+        let boundaries = self.chunk_boundaries.borrow();
+        let allocator = self.allocator.borrow();
+
+        let mut start_index = 0;
+        boundaries
+            .iter()
+            .map(|&end| {
+                let live_count = (start_index..end)
+                    .filter(|&index| allocator.is_allocated(index))
+                    .count();
+                let info = ChunkInfo {
+                    start_index,
+                    len: end - start_index,
+                    live_count,
+                };
+                start_index = end;
+                info
+            })
+            .collect()
+    }
+
+    /// Returns the flat indices of the live objects in storage chunk `chunk`, in ascending order.
+    ///
+    /// Chunks are numbered as in [`chunk_layout`](Self::chunk_layout). An
+    /// incremental compactor can pick the emptiest chunk, move just these
+    /// objects out of it and then release it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk` is not less than the number of chunks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{GrowingPool, GrowthStrategy, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(2)
+    ///     .growth_strategy(GrowthStrategy::Linear { amount: 4 })
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = GrowingPool::with_config(config).unwrap();
+    /// let mut handles: Vec<_> = (0..6).map(|i| pool.allocate(i).unwrap()).collect();
+    /// assert_eq!(pool.live_indices_in_chunk(1), [2, 3, 4, 5]);
+    ///
+    /// handles.retain(|handle| handle.index() != 3);
+    /// assert_eq!(pool.live_indices_in_chunk(1), [2, 4, 5]);
+    /// ```
+    pub fn live_indices_in_chunk(&self, chunk: usize) -> Vec<usize> {
+        let boundaries = self.chunk_boundaries.borrow();
+        assert!(chunk < boundaries.len(), "chunk index out of bounds");
+
+        let start = if chunk == 0 { 0 } else { boundaries[chunk - 1] };
+        let allocator = self.allocator.borrow();
+        (start..boundaries[chunk])
+            .filter(|&index| allocator.is_allocated(index))
+            .collect()
+    }
+
+    /// Returns the fraction of storage chunks that are partially used, from 0.0 to 1.0.
+    ///
+    /// A chunk counts as fragmented when it holds at least one live object
+    /// but is not full. Full and empty chunks do not count: a full chunk
+    /// wastes nothing, and an empty one could simply be released. A value
+    /// near 1.0 after a cleanup phase means live objects are spread thinly
+    /// over many chunks, which is the point where compacting pays off.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{GrowingPool, GrowthStrategy, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(4)
+    ///     .growth_strategy(GrowthStrategy::Linear { amount: 4 })
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = GrowingPool::with_config(config).unwrap();
+    ///
+    /// let mut handles: Vec<_> = (0..8).map(|i| pool.allocate(i).unwrap()).collect();
+    /// assert_eq!(pool.fragmentation_ratio(), 0.0);
+    ///
+    /// handles.truncate(6); // frees two slots in the second chunk
+    /// assert_eq!(pool.fragmentation_ratio(), 0.5);
+    /// ```
+    pub fn fragmentation_ratio(&self) -> f64 {
+        let (fragmented, chunks) = self.fragmented_chunks();
+        fragmented as f64 / chunks as f64
+    }
+
+    /// Returns `(partially used chunks, total chunks)`.
+    fn fragmented_chunks(&self) -> (usize, usize) {
+        let layout = self.chunk_layout();
+        let fragmented = layout
+            .iter()
+            .filter(|chunk| chunk.live_count > 0 && chunk.live_count < chunk.len)
+            .count();
+        (fragmented, layout.len())
+    }
+
+    /// Returns whether the pool is close to running out of slots.
+    ///
+    /// Utilization is measured against the most slots the pool can ever
+    /// hold: the current capacity once it can no longer grow, or
+    /// `max_capacity` while growth is still possible. An unbounded pool that
+    /// can still grow is never under pressure. `threshold` is a fraction
+    /// between 0.0 and 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::{GrowingPool, GrowthStrategy, PoolConfig};
+    ///
+    /// let config = PoolConfig::builder()
+    ///     .capacity(4)
+    ///     .max_capacity(Some(8))
+    ///     .growth_strategy(GrowthStrategy::Linear { amount: 4 })
+    ///     .build()
+    ///     .unwrap();
+    /// let pool = GrowingPool::with_config(config).unwrap();
+    /// let handles: Vec<_> = (0..4).map(|i| pool.allocate(i).unwrap()).collect();
+    ///
+    /// // Full at the current capacity, but only half of the maximum is used
+    /// assert!(!pool.is_under_pressure(0.5));
+    /// assert!(pool.is_under_pressure(0.4));
+    /// # drop(handles);
+    /// ```
+    pub fn is_under_pressure(&self, threshold: f64) -> bool {
+        let limit = if !self.can_grow() {
+            self.capacity()
+        } else if let Some(max) = self.config.max_capacity() {
+            max
+        } else {
+            return false;
+        };
+
+        self.allocated() as f64 / limit as f64 > threshold
+    }
+
+    /// Returns the name set with
+    /// [`PoolConfigBuilder::name`](crate::config::PoolConfigBuilder::name), if any.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub(crate) fn name(&self) -> Option<&'static str> {
+        self.config.name()
+    }
+
     /// Returns whether the pool can grow further.
     #[inline]
     pub fn can_grow(&self) -> bool {
@@ -382,33 +934,135 @@ impl<T: Poolable> GrowingPool<T> {
         }
     }
 
-    /// Returns an object to the pool.
-    pub(crate) fn return_to_pool(&self, index: usize) {
-        let (chunk_idx, offset) = self.compute_chunk_location(index);
-
-        // Get the value and call on_release
-        let mut storage = self.storage.borrow_mut();
-
-        unsafe {
-            let value_ptr = storage[chunk_idx][offset].as_mut_ptr();
-            (*value_ptr).on_release();
-            ptr::drop_in_place(value_ptr);
+    /// Returns the generation counter of the slot at `index`.
+    ///
+    /// The generation changes every time the slot is released, so a handle
+    /// that captured it at allocation can detect that its slot was reused.
+    /// The counter is shared, so handles can read it without the pool.
+    #[cfg(all(feature = "std", debug_assertions))]
+    #[inline]
+    pub(crate) fn generation(&self, index: usize) -> SlotGeneration {
+        self.generations.borrow().slot(index)
+    }
+
+    /// Advances a slot's generation as if it had been released and reused.
+    #[cfg(all(test, feature = "std", debug_assertions))]
+    pub(crate) fn bump_generation(&self, index: usize) {
+        self.generations.borrow().bump(index);
+    }
+
+    /// Returns an object to the pool.
+    pub(crate) fn return_to_pool(&self, index: usize) {
+        let (chunk_idx, offset) = self.compute_chunk_location(index);
+
+        {
+            // Get the value and call on_release
+            let mut storage = self.storage.borrow_mut();
+
+            unsafe {
+                let value_ptr = storage[chunk_idx][offset].as_mut_ptr();
+                (*value_ptr).on_release();
+                ptr::drop_in_place(value_ptr);
+
+                if self.config.zero_on_release() {
+                    secure_zero(value_ptr.cast::<u8>(), mem::size_of::<T>());
+                }
+            }
         }
 
         // Mark the slot as free
         self.allocator.borrow_mut().free(index);
+        if let Some(pinned) = self.pinned.borrow_mut().get_mut(index) {
+            *pinned = false;
+        }
+
+        #[cfg(all(feature = "std", debug_assertions))]
+        self.generations.borrow().bump(index);
 
         #[cfg(feature = "stats")]
         self.stats.borrow_mut().record_deallocation();
+
+        if self.config.has_observer() {
+            let usage = self.allocated();
+            self.config.notify(PoolEvent::Freed { index, usage });
+        }
+
+        if let Some(policy) = self.config.shrink_policy() {
+            self.apply_shrink_policy(policy);
+        }
+    }
+
+    /// Counts a release towards the shrink policy, shrinking once the pool
+    /// has stayed mostly free for long enough.
+    fn apply_shrink_policy(&self, policy: ShrinkPolicy) {
+        let free_ratio = self.available() as f64 / self.capacity() as f64;
+        if free_ratio <= policy.min_free_ratio {
+            self.idle_releases.set(0);
+            return;
+        }
+
+        let idle = self.idle_releases.get() + 1;
+        if idle < policy.idle_threshold {
+            self.idle_releases.set(idle);
+            return;
+        }
+
+        self.idle_releases.set(0);
+        self.shrink_trailing_chunks();
+    }
+
+    /// Frees trailing chunks that hold no live objects, keeping the initial chunk.
+    ///
+    /// Contiguous pools keep their single region as is.
+    fn shrink_trailing_chunks(&self) {
+        if self.config.contiguous() {
+            return;
+        }
+
+        let new_capacity = {
+            let mut storage = self.storage.borrow_mut();
+            let mut boundaries = self.chunk_boundaries.borrow_mut();
+            let mut allocator = self.allocator.borrow_mut();
+
+            while boundaries.len() > 1 {
+                let start = boundaries[boundaries.len() - 2];
+                let end = boundaries[boundaries.len() - 1];
+                if (start..end).any(|index| allocator.is_allocated(index)) {
+                    break;
+                }
+                storage.pop();
+                boundaries.pop();
+            }
+
+            let new_capacity = *boundaries.last().expect("the initial chunk is kept");
+            if new_capacity == *self.capacity.borrow() {
+                return;
+            }
+            allocator.truncate(new_capacity);
+            new_capacity
+        };
+
+        *self.capacity.borrow_mut() = new_capacity;
+        self.pinned.borrow_mut().truncate(new_capacity);
+
+        #[cfg(all(feature = "std", debug_assertions))]
+        self.generations.borrow_mut().truncate(new_capacity);
+
+        self.config.notify(PoolEvent::Shrunk { new_capacity });
     }
 
     /// Get current pool statistics.
+    ///
+    /// Each call also records a sample for
+    /// [`PoolStatistics::recent_allocations_per_second`].
     #[cfg(feature = "stats")]
     #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
     pub fn statistics(&self) -> PoolStatistics {
-        let mut stats = self.stats.borrow().snapshot();
+        let mut stats = self.stats.borrow_mut().sample();
         stats.current_usage = self.allocated();
         stats.capacity = self.capacity();
+        (stats.fragmented_chunks, stats.chunk_count) = self.fragmented_chunks();
+        stats.name = self.config.name();
         stats
     }
 
@@ -420,6 +1074,56 @@ impl<T: Poolable> GrowingPool<T> {
     }
 }
 
+/// Creates a pool with the default configuration: an initial capacity of
+/// 100 slots and no growth strategy, so it behaves like a fixed pool until
+/// built with a different [`PoolConfig`].
+impl<T: Poolable> Default for GrowingPool<T> {
+    fn default() -> Self {
+        Self::with_config(PoolConfig::default()).expect("default configuration is valid")
+    }
+}
+
+impl<T> Drop for GrowingPool<T> {
+    fn drop(&mut self) {
+        // Handles borrow the pool, so any slot still allocated here belongs to
+        // a handle that was leaked. Drop its object in place before the chunks
+        // are freed, which keeps the drop guarantee of pinned handles.
+        let zero = self.config.zero_on_release();
+        let allocator = self.allocator.get_mut();
+        let pinned = self.pinned.get_mut();
+
+        let mut index = 0;
+        for chunk in self.storage.get_mut().iter_mut() {
+            for slot in chunk.iter_mut() {
+                let is_pinned = pinned.get(index).copied().unwrap_or(false);
+                if is_pinned || allocator.is_allocated(index) {
+                    // Safety: allocated slots hold an initialized object that
+                    // nothing else can reach anymore
+                    unsafe {
+                        ptr::drop_in_place(slot.as_mut_ptr());
+                        if zero {
+                            secure_zero(slot.as_mut_ptr().cast::<u8>(), mem::size_of::<T>());
+                        }
+                    }
+                }
+                index += 1;
+            }
+        }
+    }
+}
+
+impl<T: Poolable> PoolUsage for GrowingPool<T> {
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[inline]
+    fn available(&self) -> usize {
+        self.available()
+    }
+}
+
 impl<T: Poolable> PoolInterface<T> for GrowingPool<T> {
     #[inline]
     fn get(&self, index: usize) -> &T {
@@ -435,6 +1139,26 @@ impl<T: Poolable> PoolInterface<T> for GrowingPool<T> {
     fn return_to_pool(&self, index: usize) {
         self.return_to_pool(index)
     }
+
+    #[inline]
+    fn as_usage(&self) -> &dyn PoolUsage {
+        self
+    }
+
+    #[inline]
+    fn is_valid_index(&self, index: usize) -> bool {
+        index < self.capacity() && self.allocator.borrow().is_allocated(index)
+    }
+
+    #[inline]
+    fn is_address_stable(&self) -> bool {
+        !self.config.contiguous()
+    }
+
+    #[inline]
+    fn mark_pinned(&self, index: usize) {
+        self.mark_pinned(index)
+    }
 }
 
 unsafe impl<T: Send> Send for GrowingPool<T> {}
@@ -496,6 +1220,745 @@ mod tests {
 
         // Should fail - cannot grow beyond max
         let result = pool.allocate(5);
+        assert!(matches!(
+            result,
+            Err(Error::PoolExhausted {
+                capacity: 4,
+                allocated: 4,
+                can_grow: false,
+                ..
+            })
+        ));
+        assert!(!pool.can_grow());
+        let err = result.unwrap_err();
+        assert!(err.is_transient());
+        assert_eq!(err.retry_hint(), crate::error::RetryHint::AfterRelease);
+    }
+
+    #[test]
+    fn exhausted_without_growth_strategy_cannot_grow() {
+        let config = PoolConfig::builder().capacity(1).build().unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let _h = pool.allocate(1).unwrap();
+        let err = pool.allocate(2).unwrap_err();
+        assert_eq!(
+            err,
+            Error::PoolExhausted {
+                capacity: 1,
+                allocated: 1,
+                can_grow: false,
+                name: None,
+            }
+        );
+        assert!(err.is_transient());
+    }
+
+    #[test]
+    fn zero_on_release_wipes_slot_in_grown_chunk() {
+        let config = PoolConfig::builder()
+            .capacity(1)
+            .growth_strategy(GrowthStrategy::Linear { amount: 1 })
+            .zero_on_release(true)
+            .build()
+            .unwrap();
+        let pool = GrowingPool::<[u8; 32]>::with_config(config).unwrap();
+
+        let _first = pool.allocate([0x11; 32]).unwrap();
+        let second = pool.allocate([0x22; 32]).unwrap();
+        let index = second.index();
+        drop(second);
+
+        // Read the raw bytes of the released slot in the second chunk
+        let (chunk_idx, offset) = pool.compute_chunk_location(index);
+        let bytes = unsafe {
+            let storage = pool.storage.borrow();
+            let ptr = storage[chunk_idx][offset].as_ptr().cast::<u8>();
+            core::slice::from_raw_parts(ptr, mem::size_of::<[u8; 32]>()).to_vec()
+        };
+        assert_eq!(bytes, vec![0u8; 32]);
+    }
+
+    fn contiguous_config(capacity: usize, amount: usize) -> PoolConfig<u64> {
+        let builder = PoolConfig::builder()
+            .capacity(capacity)
+            .growth_strategy(GrowthStrategy::Linear { amount });
+        // Safety: tests don't hold references across growth
+        unsafe { builder.contiguous(true) }.build().unwrap()
+    }
+
+    #[test]
+    fn contiguous_growth_keeps_slots_adjacent() {
+        let pool = GrowingPool::with_config(contiguous_config(2, 3)).unwrap();
+
+        let handles: Vec<_> = (0..8u64).map(|i| pool.allocate(i * 10).unwrap()).collect();
+        assert_eq!(pool.capacity(), 8);
+        assert_eq!(pool.storage.borrow().len(), 1);
+
+        // Every slot address follows the previous one without gaps
+        let base = pool.get(0) as *const u64;
+        for index in 0..8 {
+            let addr = pool.get(index) as *const u64;
+            assert_eq!(addr, base.wrapping_add(index));
+        }
+
+        // Objects written before growth were moved intact
+        for (i, handle) in handles.iter().enumerate() {
+            assert_eq!(**handle, i as u64 * 10);
+        }
+    }
+
+    #[test]
+    fn chunked_growth_is_default() {
+        let config = PoolConfig::builder()
+            .capacity(2)
+            .growth_strategy(GrowthStrategy::Linear { amount: 2 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let _handles: Vec<_> = (0..4u64).map(|i| pool.allocate(i).unwrap()).collect();
+        assert_eq!(pool.storage.borrow().len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn observer_reports_growth() {
+        use std::sync::{Arc, Mutex};
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let config = PoolConfig::builder()
+            .capacity(1)
+            .growth_strategy(GrowthStrategy::Linear { amount: 1 })
+            .observer(move |event| sink.lock().unwrap().push(event))
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let a = pool.allocate(1).unwrap();
+        let b = pool.allocate(2).unwrap();
+        drop(b);
+        drop(a);
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                PoolEvent::Allocated { index: 0, usage: 1 },
+                PoolEvent::Grew { new_capacity: 2 },
+                PoolEvent::Allocated { index: 1, usage: 2 },
+                PoolEvent::Freed { index: 1, usage: 1 },
+                PoolEvent::Freed { index: 0, usage: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn growth_aligned_to_words() {
+        let config = PoolConfig::builder()
+            .capacity(10)
+            .growth_strategy(GrowthStrategy::Linear { amount: 5 })
+            .align_growth_to_words(true)
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let handles: Vec<_> = (0..11).map(|i| pool.allocate(i).unwrap()).collect();
+        assert_eq!(pool.capacity(), 64);
+
+        let more: Vec<_> = (0..54).map(|i| pool.allocate(i).unwrap()).collect();
+        assert_eq!(pool.capacity(), 128);
+        assert_eq!(handles.len() + more.len(), 65);
+    }
+
+    #[test]
+    fn aligned_growth_respects_max_capacity() {
+        let config = PoolConfig::builder()
+            .capacity(10)
+            .max_capacity(Some(40))
+            .growth_strategy(GrowthStrategy::Linear { amount: 5 })
+            .align_growth_to_words(true)
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let _handles: Vec<_> = (0..11).map(|i| pool.allocate(i).unwrap()).collect();
+        assert_eq!(pool.capacity(), 40);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn on_grow_reports_each_growth() {
+        use std::sync::{Arc, Mutex};
+
+        let growths = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&growths);
+        let config = PoolConfig::builder()
+            .capacity(10)
+            .growth_strategy(GrowthStrategy::Exponential { factor: 2.0 })
+            .on_grow(move |old, new| sink.lock().unwrap().push((old, new)))
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let _handles: Vec<_> = (0..41).map(|i| pool.allocate(i).unwrap()).collect();
+
+        assert_eq!(*growths.lock().unwrap(), [(10, 20), (20, 40), (40, 80)]);
+    }
+
+    #[test]
+    fn growth_overflow_is_an_error() {
+        let config = PoolConfig::builder()
+            .capacity(10)
+            .growth_strategy(GrowthStrategy::Custom {
+                compute: alloc::boxed::Box::new(|_| usize::MAX),
+            })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let _handles: Vec<_> = (0..10).map(|i| pool.allocate(i).unwrap()).collect();
+        let err = pool.allocate(10).unwrap_err();
+        assert_eq!(
+            err,
+            Error::CapacityOverflow {
+                current: 10,
+                additional: usize::MAX,
+            }
+        );
+        assert_eq!(pool.capacity(), 10);
+    }
+
+    #[test]
+    fn predict_capacity_exponential_and_linear() {
+        let config = PoolConfig::builder()
+            .capacity(10)
+            .growth_strategy(GrowthStrategy::Exponential { factor: 2.0 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+        let _held: Vec<_> = (0..5).map(|i| pool.allocate(i).unwrap()).collect();
+
+        assert_eq!(pool.predict_capacity_for(5), Some(10));
+        assert_eq!(pool.predict_capacity_for(6), Some(20));
+        assert_eq!(pool.predict_capacity_for(70), Some(80));
+        assert_eq!(pool.capacity(), 10);
+
+        let config = PoolConfig::builder()
+            .capacity(10)
+            .growth_strategy(GrowthStrategy::Linear { amount: 4 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::<i32>::with_config(config).unwrap();
+        assert_eq!(pool.predict_capacity_for(11), Some(14));
+        assert_eq!(pool.predict_capacity_for(19), Some(22));
+    }
+
+    #[test]
+    fn predict_capacity_capped_by_max() {
+        let config = PoolConfig::builder()
+            .capacity(10)
+            .max_capacity(Some(30))
+            .growth_strategy(GrowthStrategy::Linear { amount: 10 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::<i32>::with_config(config).unwrap();
+
+        assert_eq!(pool.predict_capacity_for(30), Some(30));
+        assert_eq!(pool.predict_capacity_for(31), None);
+
+        let fixed =
+            GrowingPool::<i32>::with_config(PoolConfig::builder().capacity(4).build().unwrap())
+                .unwrap();
+        assert_eq!(fixed.predict_capacity_for(5), None);
+    }
+
+    #[test]
+    fn chunk_layout_reports_live_objects_per_chunk() {
+        let config = PoolConfig::builder()
+            .capacity(4)
+            .growth_strategy(GrowthStrategy::Linear { amount: 4 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        // Fills the initial chunk and grows twice
+        let mut handles: Vec<_> = (0..12).map(|i| Some(pool.allocate(i).unwrap())).collect();
+        assert_eq!(pool.chunk_layout().len(), 3);
+
+        // Free one slot in the first chunk and three in the last
+        for index in [1, 8, 9, 11] {
+            handles[index] = None;
+        }
+
+        let layout = pool.chunk_layout();
+        let summary: Vec<_> = layout
+            .iter()
+            .map(|chunk| (chunk.start_index, chunk.len, chunk.live_count))
+            .collect();
+        assert_eq!(summary, [(0, 4, 3), (4, 4, 4), (8, 4, 1)]);
+    }
+
+    #[test]
+    fn fragmentation_ratio_counts_partial_chunks() {
+        let config = PoolConfig::builder()
+            .capacity(4)
+            .growth_strategy(GrowthStrategy::Linear { amount: 4 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+        assert_eq!(pool.fragmentation_ratio(), 0.0);
+
+        // Four full chunks
+        let mut handles: Vec<_> = (0..16).map(|i| Some(pool.allocate(i).unwrap())).collect();
+        assert_eq!(pool.fragmentation_ratio(), 0.0);
+
+        // Empty the last chunk entirely: still unfragmented
+        for handle in &mut handles[12..16] {
+            *handle = None;
+        }
+        assert_eq!(pool.fragmentation_ratio(), 0.0);
+
+        // One hole in the first chunk and one in the third: 2 of 4 chunks
+        handles[0] = None;
+        handles[9] = None;
+        assert_eq!(pool.fragmentation_ratio(), 0.5);
+
+        // Leave a single object in the second chunk: 3 of 4 chunks
+        for handle in &mut handles[5..8] {
+            *handle = None;
+        }
+        assert_eq!(pool.fragmentation_ratio(), 0.75);
+
+        #[cfg(feature = "stats")]
+        {
+            let stats = pool.statistics();
+            assert_eq!((stats.fragmented_chunks, stats.chunk_count), (3, 4));
+            assert_eq!(stats.fragmentation_ratio(), 0.75);
+        }
+    }
+
+    #[test]
+    fn pinned_object_does_not_move_on_growth() {
+        let config = PoolConfig::builder()
+            .capacity(1)
+            .growth_strategy(GrowthStrategy::Linear { amount: 1 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let pinned = pool.allocate_pinned(42u64).unwrap();
+        let before = &*pinned as *const u64;
+
+        let others: Vec<_> = (0..16u64).map(|i| pool.allocate(i).unwrap()).collect();
+        assert!(pool.storage.borrow().len() > 1);
+
+        assert_eq!(&*pinned as *const u64, before);
+        assert_eq!(*pinned, 42);
+        drop(others);
+    }
+
+    #[test]
+    fn pinned_allocation_rejects_contiguous_pools() {
+        let pool = GrowingPool::with_config(contiguous_config(2, 2)).unwrap();
+        assert!(matches!(
+            pool.allocate_pinned(1),
+            Err(Error::InvalidConfiguration { .. })
+        ));
+        assert_eq!(pool.allocated(), 0);
+    }
+
+    #[test]
+    fn default_pool_has_capacity_100_without_growth() {
+        let pool = GrowingPool::<i32>::default();
+        assert_eq!(pool.capacity(), 100);
+        assert!(!pool.can_grow());
+
+        let handle = pool.allocate(3).unwrap();
+        assert_eq!(*handle, 3);
+        assert_eq!(pool.allocated(), 1);
+    }
+
+    #[test]
+    fn pressure_accounts_for_remaining_growth() {
+        let config = PoolConfig::builder()
+            .capacity(2)
+            .max_capacity(Some(4))
+            .growth_strategy(GrowthStrategy::Linear { amount: 2 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let mut handles: Vec<_> = (0..2).map(|i| pool.allocate(i).unwrap()).collect();
+        assert!(pool.can_grow());
+        assert!(!pool.is_under_pressure(0.5)); // 2 of 4, exactly at the threshold
+        assert!(pool.is_under_pressure(0.49));
+
+        handles.push(pool.allocate(2).unwrap());
+        assert!(!pool.can_grow());
+        assert!(pool.is_under_pressure(0.7));
+        assert!(!pool.is_under_pressure(0.75));
+
+        // Without a maximum a growable pool always has room
+        let config = PoolConfig::builder()
+            .capacity(1)
+            .growth_strategy(GrowthStrategy::Linear { amount: 1 })
+            .build()
+            .unwrap();
+        let unbounded = GrowingPool::with_config(config).unwrap();
+        let _handle = unbounded.allocate(0).unwrap();
+        assert!(!unbounded.is_under_pressure(0.0));
+
+        // Without growth only the current capacity counts
+        let config = PoolConfig::builder()
+            .capacity(2)
+            .growth_strategy(GrowthStrategy::None)
+            .build()
+            .unwrap();
+        let fixed = GrowingPool::with_config(config).unwrap();
+        let _handle = fixed.allocate(0).unwrap();
+        assert!(fixed.is_under_pressure(0.49));
+        assert!(!fixed.is_under_pressure(0.5));
+    }
+
+    #[test]
+    fn allocations_land_in_the_right_chunk() {
+        let config = PoolConfig::builder()
+            .capacity(3)
+            .growth_strategy(GrowthStrategy::Linear { amount: 5 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let handles: Vec<_> = (0..200usize).map(|i| pool.allocate(i).unwrap()).collect();
+        assert!(pool.chunk_layout().len() > 30);
+
+        let storage = pool.storage.borrow();
+        for (value, handle) in handles.iter().enumerate() {
+            // Locate the slot by walking the chunks, independent of the lookup table
+            let mut offset = handle.index();
+            let chunk = storage
+                .iter()
+                .find(|chunk| {
+                    let inside = offset < chunk.len();
+                    if !inside {
+                        offset -= chunk.len();
+                    }
+                    inside
+                })
+                .unwrap();
+
+            // Safety: the slot is allocated, so it holds an initialized value
+            assert_eq!(unsafe { chunk[offset].assume_init_read() }, value);
+            assert_eq!(**handle, value);
+        }
+    }
+
+    #[test]
+    fn live_indices_in_chunk_lists_occupied_slots() {
+        let config = PoolConfig::builder()
+            .capacity(4)
+            .growth_strategy(GrowthStrategy::Linear { amount: 4 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let mut handles: Vec<_> = (0..12).map(|i| pool.allocate(i).unwrap()).collect();
+        handles.retain(|handle| ![1, 5, 6, 8, 9, 11].contains(&handle.index()));
+
+        assert_eq!(pool.live_indices_in_chunk(0), [0, 2, 3]);
+        assert_eq!(pool.live_indices_in_chunk(1), [4, 7]);
+        assert_eq!(pool.live_indices_in_chunk(2), [10]);
+
+        handles.retain(|handle| handle.index() != 10);
+        assert!(pool.live_indices_in_chunk(2).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk index out of bounds")]
+    fn live_indices_in_chunk_rejects_missing_chunk() {
+        let pool = GrowingPool::<u32>::with_config(PoolConfig::default()).unwrap();
+        pool.live_indices_in_chunk(1);
+    }
+
+    #[test]
+    fn into_pinned_follows_storage_layout() {
+        let chunked =
+            GrowingPool::with_config(PoolConfig::builder().capacity(2).build().unwrap()).unwrap();
+        let handle = chunked.allocate(1).unwrap();
+        let index = handle.index();
+        let pinned = handle.into_pinned().unwrap();
+        assert!(chunked.pinned.borrow()[index]);
+        drop(pinned);
+        assert!(!chunked.pinned.borrow()[index]);
+
+        let contiguous = GrowingPool::with_config(contiguous_config(2, 2)).unwrap();
+        let handle = contiguous.allocate(7).unwrap().into_pinned().unwrap_err();
+        assert_eq!(*handle, 7);
+    }
+
+    #[test]
+    fn leaked_pinned_objects_are_dropped_in_place() {
+        use core::cell::Cell;
+        use core::marker::PhantomPinned;
+
+        #[derive(Debug)]
+        struct Anchor<'a> {
+            dropped_at: &'a Cell<usize>,
+            _pin: PhantomPinned,
+        }
+        impl Drop for Anchor<'_> {
+            fn drop(&mut self) {
+                self.dropped_at.set(self as *mut Self as usize);
+            }
+        }
+        impl Poolable for Anchor<'_> {}
+
+        let dropped_at = Cell::new(0);
+        let anchor = || Anchor {
+            dropped_at: &dropped_at,
+            _pin: PhantomPinned,
+        };
+        let config = PoolConfig::builder()
+            .capacity(1)
+            .growth_strategy(GrowthStrategy::Linear { amount: 1 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        // The pinned object lives in the chunk added by growth
+        let first = pool.allocate(anchor()).unwrap();
+        let pinned = pool.allocate_pinned(anchor()).unwrap();
+        let address = &*pinned as *const Anchor<'_> as usize;
+        mem::forget(pinned);
+        drop(first);
+        assert_ne!(dropped_at.get(), address);
+
+        drop(pool);
+        assert_eq!(dropped_at.get(), address);
+    }
+
+    #[test]
+    fn memory_footprint_grows_by_a_chunk() {
+        let config = PoolConfig::builder()
+            .capacity(64)
+            .growth_strategy(GrowthStrategy::Linear { amount: 64 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::<[u8; 256]>::with_config(config).unwrap();
+        let chunk_bytes = 64 * 256;
+
+        let before = pool.memory_footprint();
+        assert!(before >= chunk_bytes);
+
+        let handles: Vec<_> = (0..65).map(|_| pool.allocate([0; 256]).unwrap()).collect();
+        assert_eq!(pool.capacity(), 128);
+
+        // The new chunk, plus a little more bookkeeping for its slots
+        let growth = pool.memory_footprint() - before;
+        assert!(growth >= chunk_bytes);
+        assert!(growth < chunk_bytes + 64 * 32);
+        drop(handles);
+    }
+
+    #[test]
+    fn growth_near_max_is_clamped_to_max() {
+        let config = PoolConfig::builder()
+            .capacity(90)
+            .max_capacity(Some(100))
+            .growth_strategy(GrowthStrategy::Exponential { factor: 2.0 })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+
+        let handles: Vec<_> = (0..100).map(|i| pool.allocate(i).unwrap()).collect();
+        assert_eq!(pool.capacity(), 100);
+        assert!(!pool.can_grow());
+
+        assert!(matches!(
+            pool.allocate(100),
+            Err(Error::PoolExhausted {
+                capacity: 100,
+                can_grow: false,
+                ..
+            })
+        ));
+        drop(handles);
+    }
+
+    #[test]
+    fn failed_growth_reports_allocation_failure() {
+        #[allow(dead_code)]
+        struct Page([u8; 4096]);
+        impl Poolable for Page {}
+
+        // Large enough that the reservation is rejected before reaching the
+        // system allocator, so the test never actually reserves the memory
+        let amount = usize::MAX / 8192;
+        for contiguous in [false, true] {
+            let builder = PoolConfig::builder()
+                .capacity(1)
+                .growth_strategy(GrowthStrategy::Linear { amount });
+            // Safety: the test doesn't hold references across growth
+            let config = unsafe { builder.contiguous(contiguous) }.build().unwrap();
+            let pool = GrowingPool::<Page>::with_config(config).unwrap();
+            let first = pool.allocate(Page([1; 4096])).unwrap();
+
+            match pool.allocate(Page([2; 4096])) {
+                Err(Error::AllocationFailed { bytes }) => assert_eq!(bytes, amount * 4096),
+                other => panic!("expected AllocationFailed, got {:?}", other.err()),
+            }
+
+            // The pool is untouched and still usable
+            assert_eq!(pool.capacity(), 1);
+            assert_eq!(first.0[0], 1);
+            drop(first);
+            assert!(pool.allocate(Page([3; 4096])).is_ok());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn allocate_batch_grows_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let growths = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&growths);
+        let config = PoolConfig::builder()
+            .capacity(10)
+            .growth_strategy(GrowthStrategy::Exponential { factor: 2.0 })
+            .on_grow(move |_, _| {
+                counter.fetch_add(1, Ordering::Relaxed);
+            })
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+        let expected = pool.predict_capacity_for(1000).unwrap();
+
+        let handles = pool.allocate_batch((0..1000).collect()).unwrap();
+
+        // One-at-a-time allocation would have grown seven times
+        assert_eq!(growths.load(Ordering::Relaxed), 1);
+        assert_eq!(pool.capacity(), expected);
+        assert_eq!(pool.chunk_layout().len(), 2);
+        assert!(handles.iter().enumerate().all(|(i, h)| **h == i));
+
+        // A batch that fits grows nothing
+        drop(handles);
+        let _handles = pool.allocate_batch((0..1000).collect()).unwrap();
+        assert_eq!(growths.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn allocate_batch_respects_max_capacity() {
+        let config = PoolConfig::builder()
+            .capacity(10)
+            .growth_strategy(GrowthStrategy::Linear { amount: 10 })
+            .max_capacity(Some(50))
+            .build()
+            .unwrap();
+        let pool = GrowingPool::with_config(config).unwrap();
+        let _first = pool.allocate(0).unwrap();
+
+        let result = pool.allocate_batch((0..50).collect());
         assert!(matches!(result, Err(Error::MaxCapacityExceeded { .. })));
+        assert_eq!(pool.capacity(), 10);
+        assert_eq!(pool.allocated(), 1);
+
+        assert_eq!(pool.allocate_batch((0..49).collect()).unwrap().len(), 49);
+        assert_eq!(pool.capacity(), 50);
+    }
+
+    fn shrinking_config(idle_threshold: usize) -> PoolConfig<u64> {
+        PoolConfig::builder()
+            .capacity(4)
+            .growth_strategy(GrowthStrategy::Linear { amount: 4 })
+            .auto_shrink(ShrinkPolicy {
+                idle_threshold,
+                min_free_ratio: 0.5,
+            })
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn single_release_after_growth_does_not_shrink() {
+        let pool = GrowingPool::with_config(shrinking_config(4)).unwrap();
+        let mut handles: Vec<_> = (0..12).map(|i| pool.allocate(i).unwrap()).collect();
+        assert_eq!(pool.capacity(), 12);
+
+        // Releases that leave at most half of the pool free do not count
+        handles.truncate(6);
+        assert_eq!(pool.capacity(), 12);
+
+        // One release past the free ratio is not sustained low occupancy
+        handles.pop();
+        assert_eq!(pool.available(), 7);
+        assert_eq!(pool.capacity(), 12);
+        assert_eq!(pool.chunk_layout().len(), 3);
+
+        // Going back above half full resets the count
+        handles.push(pool.allocate(5).unwrap());
+        handles.push(pool.allocate(6).unwrap());
+        for _ in 0..3 {
+            handles.pop();
+            handles.push(pool.allocate(7).unwrap());
+        }
+        assert_eq!(pool.capacity(), 12);
+    }
+
+    #[test]
+    fn sustained_low_occupancy_frees_trailing_chunks() {
+        let pool = GrowingPool::with_config(shrinking_config(4)).unwrap();
+        let handles: Vec<_> = (0..12).map(|i| pool.allocate(i).unwrap()).collect();
+        let (low, high) = {
+            let mut handles = handles;
+            let high = handles.split_off(4);
+            (handles, high)
+        };
+        // The last two of these releases leave more than half the pool free
+        drop(high);
+        assert_eq!(pool.capacity(), 12);
+
+        // Short-lived allocations released while the pool stays mostly free
+        // keep counting, until the fourth such release shrinks it
+        drop(pool.allocate(100).unwrap());
+        assert_eq!(pool.capacity(), 12);
+        drop(pool.allocate(101).unwrap());
+        assert_eq!(pool.capacity(), 4);
+        assert_eq!(pool.chunk_layout().len(), 1);
+        assert!(low.iter().enumerate().all(|(i, h)| **h == i as u64));
+
+        // The pool grows again on demand
+        let more: Vec<_> = (0..4).map(|i| pool.allocate(i).unwrap()).collect();
+        assert_eq!(pool.capacity(), 8);
+        drop((low, more));
+    }
+
+    #[test]
+    fn busy_trailing_chunk_is_kept() {
+        let pool = GrowingPool::with_config(shrinking_config(1)).unwrap();
+        let mut handles: Vec<_> = (0..12).map(|i| pool.allocate(i).unwrap()).collect();
+
+        // Only the last slot stays live, pinning the last chunk
+        let last = handles.pop().unwrap();
+        handles.clear();
+        assert_eq!(pool.capacity(), 12);
+
+        drop(last);
+        assert_eq!(pool.capacity(), 4);
+    }
+
+    #[test]
+    fn auto_shrink_rejects_invalid_ratio() {
+        let result = PoolConfig::<u64>::builder()
+            .capacity(4)
+            .auto_shrink(ShrinkPolicy {
+                idle_threshold: 1,
+                min_free_ratio: 1.5,
+            })
+            .build();
+        assert!(result.is_err());
     }
 }
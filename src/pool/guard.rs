@@ -0,0 +1,108 @@
+//! Leak-checking guard for fixed pools.
+
+use super::FixedPool;
+use crate::traits::Poolable;
+
+/// Guard that checks a pool has no live allocations when it goes out of scope.
+///
+/// Created by [`FixedPool::guard`]. When the guard is dropped while objects
+/// are still allocated, a warning is emitted (with the `tracing` feature) and
+/// debug builds panic, so handle leaks surface in tests instead of silently
+/// shrinking the pool. The check is skipped if the thread is already
+/// panicking. Use [`assert_empty`](Self::assert_empty) to check explicitly at
+/// any point, in any build.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::FixedPool;
+///
+/// let pool = FixedPool::new(4).unwrap();
+/// let guard = pool.guard();
+///
+/// let handle = pool.allocate(1).unwrap();
+/// drop(handle);
+///
+/// guard.assert_empty();
+/// ```
+pub struct PoolGuard<'pool, T: Poolable> {
+    pool: &'pool FixedPool<T>,
+}
+
+impl<'pool, T: Poolable> PoolGuard<'pool, T> {
+    pub(crate) fn new(pool: &'pool FixedPool<T>) -> Self {
+        Self { pool }
+    }
+
+    /// Panics if the pool has any live allocations.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the number of leaked objects if `allocated()` is not zero.
+    #[track_caller]
+    pub fn assert_empty(&self) {
+        let allocated = self.pool.allocated();
+        assert!(
+            allocated == 0,
+            "pool still has {} live allocation(s)",
+            allocated
+        );
+    }
+}
+
+impl<T: Poolable> Drop for PoolGuard<'_, T> {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        let panicking = std::thread::panicking();
+        #[cfg(not(feature = "std"))]
+        let panicking = false;
+
+        let allocated = self.pool.allocated();
+        if allocated == 0 || panicking {
+            return;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(allocated, "pool guard dropped with live allocations");
+
+        debug_assert!(
+            allocated == 0,
+            "pool guard dropped with {} live allocation(s)",
+            allocated
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guard_passes_when_all_returned() {
+        let pool = FixedPool::new(4).unwrap();
+        let guard = pool.guard();
+        let handles: alloc::vec::Vec<_> = (0..4).map(|i| pool.allocate(i).unwrap()).collect();
+        drop(handles);
+        guard.assert_empty();
+    }
+
+    #[test]
+    #[should_panic(expected = "1 live allocation")]
+    fn assert_empty_reports_leak() {
+        let pool = FixedPool::new(4).unwrap();
+        let guard = pool.guard();
+        let _held = pool.allocate(1).unwrap();
+        guard.assert_empty();
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", debug_assertions))]
+    fn guard_drop_catches_leaked_handle() {
+        let result = std::panic::catch_unwind(|| {
+            let pool = FixedPool::new(4).unwrap();
+            let _guard = pool.guard();
+            core::mem::forget(pool.allocate(1).unwrap());
+        });
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,240 @@
+//! Memory pool backed by an anonymous memory mapping.
+
+use crate::allocator::{Allocator, BitmapAllocator};
+use crate::error::{Error, Result};
+use crate::handle::{OwnedHandle, PoolInterface, PoolUsage};
+use crate::traits::Poolable;
+use core::cell::RefCell;
+use core::mem;
+use core::ptr;
+use memmap2::MmapMut;
+
+/// A fixed-capacity pool whose storage is an anonymous memory mapping.
+///
+/// The slot storage is reserved with a single anonymous `mmap` instead of a
+/// heap allocation, so the operating system only commits physical pages as
+/// slots are first written. This makes very large pools cheap to create:
+/// slots that are never touched are never backed by memory, and nothing is
+/// zeroed up front. Requires the `mmap` feature.
+///
+/// Slot bookkeeping uses a bitmap allocator, which needs one bit per slot,
+/// so the metadata stays small next to the mapping.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::MmapPool;
+///
+/// // Reserves 128 MiB of address space, but only touched pages use memory
+/// let pool = MmapPool::<u64>::new(1 << 24).unwrap();
+///
+/// let handle = pool.allocate(42).unwrap();
+/// assert_eq!(*handle, 42);
+/// assert_eq!(pool.capacity(), 1 << 24);
+/// ```
+pub struct MmapPool<T> {
+    /// Start of the slot array inside the mapping
+    base: *mut T,
+    allocator: RefCell<BitmapAllocator>,
+    capacity: usize,
+    /// Keeps the mapping alive; unmapped on drop
+    _map: MmapMut,
+}
+
+impl<T: Poolable> MmapPool<T> {
+    /// Creates a pool with `capacity` slots backed by an anonymous mapping.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `capacity` is 0,
+    /// `Error::InvalidAlignment` if `T` needs more than page alignment, and
+    /// `Error::AllocationFailed` if the size overflows or the mapping fails.
+    pub fn new(capacity: usize) -> Result<Self> {
+        if capacity == 0 {
+            return Err(Error::invalid_config("capacity must be at least 1"));
+        }
+
+        let bytes = capacity
+            .checked_mul(mem::size_of::<T>())
+            .ok_or(Error::AllocationFailed { bytes: usize::MAX })?;
+
+        // Mappings are page-aligned, which satisfies any ordinary alignment
+        if mem::align_of::<T>() > 4096 {
+            return Err(Error::InvalidAlignment {
+                alignment: mem::align_of::<T>(),
+            });
+        }
+
+        // Zero-sized types still need a non-empty mapping for a valid base
+        let mut map =
+            MmapMut::map_anon(bytes.max(1)).map_err(|_| Error::AllocationFailed { bytes })?;
+        let base = map.as_mut_ptr().cast::<T>();
+
+        Ok(Self {
+            base,
+            allocator: RefCell::new(BitmapAllocator::new(capacity)),
+            capacity,
+            _map: map,
+        })
+    }
+
+    /// Allocates an object from the pool with the given initial value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if every slot is in use.
+    pub fn allocate(&self, mut value: T) -> Result<OwnedHandle<'_, T>> {
+        let index = self
+            .allocator
+            .borrow_mut()
+            .allocate()
+            .ok_or(Error::PoolExhausted {
+                capacity: self.capacity,
+                allocated: self.capacity,
+                can_grow: false,
+                name: None,
+            })?;
+
+        value.on_acquire();
+        // Safety: index is in bounds and the slot is free
+        unsafe { self.base.add(index).write(value) };
+
+        Ok(OwnedHandle::new(self, index))
+    }
+
+    /// Returns the total capacity of the pool.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of available (free) slots in the pool.
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.allocator.borrow().available()
+    }
+
+    /// Returns the number of currently allocated objects.
+    #[inline]
+    pub fn allocated(&self) -> usize {
+        self.capacity - self.available()
+    }
+}
+
+impl<T: Poolable> PoolUsage for MmapPool<T> {
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[inline]
+    fn available(&self) -> usize {
+        self.available()
+    }
+}
+
+impl<T: Poolable> PoolInterface<T> for MmapPool<T> {
+    #[inline]
+    fn get(&self, index: usize) -> &T {
+        // Safety: handles only exist for allocated, initialized slots
+        unsafe { &*self.base.add(index) }
+    }
+
+    #[inline]
+    fn get_mut(&self, index: usize) -> &mut T {
+        // Safety: the owning handle has exclusive access to its slot
+        unsafe { &mut *self.base.add(index) }
+    }
+
+    fn return_to_pool(&self, index: usize) {
+        // Safety: the slot was initialized by allocate and is released once
+        unsafe {
+            let value_ptr = self.base.add(index);
+            (*value_ptr).on_release();
+            ptr::drop_in_place(value_ptr);
+        }
+
+        self.allocator.borrow_mut().free(index);
+    }
+
+    #[inline]
+    fn as_usage(&self) -> &dyn PoolUsage {
+        self
+    }
+
+    #[inline]
+    fn is_valid_index(&self, index: usize) -> bool {
+        index < self.capacity && self.allocator.borrow().is_allocated(index)
+    }
+}
+
+impl<T> Drop for MmapPool<T> {
+    fn drop(&mut self) {
+        if !mem::needs_drop::<T>() {
+            return;
+        }
+
+        // Drop objects whose handles were leaked before unmapping
+        let allocator = self.allocator.get_mut();
+        for index in 0..self.capacity {
+            if allocator.is_allocated(index) {
+                // Safety: allocated slots hold an initialized object
+                unsafe { ptr::drop_in_place(self.base.add(index)) };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn large_pool_allocates_and_frees() {
+        let capacity = 1 << 24;
+        let pool = MmapPool::<u64>::new(capacity).unwrap();
+        assert_eq!(pool.capacity(), capacity);
+        assert_eq!(pool.available(), capacity);
+
+        let a = pool.allocate(1).unwrap();
+        let mut b = pool.allocate(2).unwrap();
+        *b += 40;
+        assert_eq!(*a + *b, 43);
+        assert_eq!(pool.allocated(), 2);
+
+        drop(a);
+        assert_eq!(pool.allocated(), 1);
+        let c = pool.allocate(3).unwrap();
+        assert_eq!(*c, 3);
+        drop((b, c));
+        assert_eq!(pool.available(), capacity);
+    }
+
+    #[test]
+    fn exhaustion_and_zero_capacity() {
+        assert!(MmapPool::<u64>::new(0).is_err());
+
+        let pool = MmapPool::new(1).unwrap();
+        let _held = pool.allocate(String::from("only")).unwrap();
+        assert!(matches!(
+            pool.allocate(String::new()),
+            Err(Error::PoolExhausted { .. })
+        ));
+    }
+
+    #[test]
+    fn drop_releases_leaked_objects() {
+        use std::rc::Rc;
+
+        struct Tracked(#[allow(dead_code)] Rc<()>);
+        impl Poolable for Tracked {}
+
+        let tracker = Rc::new(());
+        let pool = MmapPool::new(4).unwrap();
+        mem::forget(pool.allocate(Tracked(Rc::clone(&tracker))).unwrap());
+        assert_eq!(Rc::strong_count(&tracker), 2);
+
+        drop(pool);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+}
@@ -1,10 +1,31 @@
 //! Memory pool implementations.
 
+mod box_pool;
 mod fixed;
 mod growing;
+mod guard;
+mod ring;
+mod static_pool;
+mod storage;
+mod string_pool;
+mod tagged;
+mod vec_pool_set;
 
-pub use fixed::FixedPool;
-pub use growing::GrowingPool;
+pub use box_pool::{BoxHandle, BoxPool};
+pub use fixed::{FixedPool, FixedPoolIntoIter};
+pub use growing::{ChunkInfo, GrowingPool};
+pub use guard::PoolGuard;
+pub use ring::RingBufferPool;
+pub use static_pool::StaticPool;
+pub use string_pool::{PooledString, StringPool};
+pub use tagged::{TaggedHandle, TaggedPool};
+pub use vec_pool_set::VecPoolSet;
+
+#[cfg(feature = "std")]
+mod cache;
+
+#[cfg(feature = "std")]
+mod pool_set;
 
 #[cfg(feature = "std")]
 mod thread_local;
@@ -12,11 +33,41 @@ mod thread_local;
 #[cfg(feature = "std")]
 mod thread_safe;
 
+#[cfg(feature = "std")]
+pub use cache::PoolCache;
+
+#[cfg(feature = "std")]
+pub use pool_set::PoolSet;
+
 #[cfg(feature = "std")]
 pub use thread_local::ThreadLocalPool;
 
 #[cfg(feature = "std")]
-pub use thread_safe::ThreadSafePool;
+pub use thread_safe::{ArcSharedHandle, ScopedPool, ThreadSafePool};
 
 #[cfg(all(feature = "std", feature = "lock-free"))]
 pub use thread_safe::LockFreePool;
+
+#[cfg(all(feature = "std", feature = "lock-free"))]
+mod spsc;
+
+#[cfg(all(feature = "std", feature = "lock-free"))]
+pub use spsc::{SpscHandle, SpscPool, SpscReleaser};
+
+#[cfg(feature = "parking_lot")]
+mod rwlock;
+
+#[cfg(feature = "parking_lot")]
+pub use rwlock::{RwLockHandle, RwLockPool};
+
+#[cfg(feature = "mmap")]
+mod mmap;
+
+#[cfg(feature = "mmap")]
+pub use mmap::MmapPool;
+
+#[cfg(feature = "async")]
+mod async_pool;
+
+#[cfg(feature = "async")]
+pub use async_pool::{AsyncHandle, AsyncPool};
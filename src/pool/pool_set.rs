@@ -0,0 +1,283 @@
+//! A collection of fixed pools keyed by object type.
+
+use super::vec_pool_set::DEFAULT_CAPACITY;
+use crate::error::{Error, Result};
+use crate::pool::FixedPool;
+use crate::traits::Poolable;
+use alloc::boxed::Box;
+use core::any::{Any, TypeId};
+use core::cell::RefCell;
+use core::fmt;
+use core::hash::BuildHasher;
+use core::num::NonZeroUsize;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+
+/// Owns one [`FixedPool`] per object type, created on first use.
+///
+/// Systems that pool many different types, such as an ECS with one pool per
+/// component, would otherwise need a separate pool variable for each type.
+/// `PoolSet` keeps them all in one place: [`pool`](Self::pool) returns the
+/// pool for `T`, creating it with the set's default capacity the first time
+/// it is requested. Every pool lives as long as the set, so handles from
+/// different pools all share the set's lifetime.
+///
+/// Pools are never removed while the set is borrowed, which is what allows
+/// several of them to be used at the same time through `&self`.
+///
+/// Pools are looked up in a `HashMap` keyed by `TypeId`, hashed with `S`.
+/// The keys are few and come from the program itself, so the DoS
+/// resistance of the default SipHash buys nothing; a faster hasher can be
+/// supplied with [`with_hasher`](Self::with_hasher). Without `std`, use
+/// [`VecPoolSet`](crate::VecPoolSet).
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::PoolSet;
+///
+/// struct Position(f32, f32);
+/// struct Velocity(f32, f32);
+/// impl fastalloc::Poolable for Position {}
+/// impl fastalloc::Poolable for Velocity {}
+///
+/// let set = PoolSet::new().with_capacity_for::<Position>(1024).unwrap();
+///
+/// let position = set.pool::<Position>().allocate(Position(0.0, 0.0)).unwrap();
+/// let velocity = set.pool::<Velocity>().allocate(Velocity(1.0, 0.0)).unwrap();
+///
+/// assert_eq!(set.pool::<Position>().capacity(), 1024);
+/// assert_eq!(set.pool::<Velocity>().capacity(), 100);
+/// # drop((position, velocity));
+/// ```
+pub struct PoolSet<S = RandomState> {
+    pools: RefCell<HashMap<TypeId, Box<dyn Any>, S>>,
+    default_capacity: NonZeroUsize,
+}
+
+impl PoolSet {
+    /// Creates an empty set whose pools are created with a capacity of 100.
+    pub fn new() -> Self {
+        Self {
+            pools: RefCell::new(HashMap::new()),
+            default_capacity: NonZeroUsize::new(DEFAULT_CAPACITY).expect("non-zero"),
+        }
+    }
+
+    /// Creates an empty set whose pools are created with `capacity` slots.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `capacity` is 0.
+    pub fn with_default_capacity(capacity: usize) -> Result<Self> {
+        let default_capacity = NonZeroUsize::new(capacity)
+            .ok_or_else(|| Error::invalid_config("capacity must be at least 1"))?;
+
+        Ok(Self {
+            pools: RefCell::new(HashMap::new()),
+            default_capacity,
+        })
+    }
+}
+
+impl<S: BuildHasher> PoolSet<S> {
+    /// Creates an empty set that hashes `TypeId`s with `hash_builder`, whose
+    /// pools are created with a capacity of 100.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::PoolSet;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// let set = PoolSet::with_hasher(BuildHasherDefault::<DefaultHasher>::default());
+    /// let handle = set.pool::<u64>().allocate(7).unwrap();
+    /// assert_eq!(*handle, 7);
+    /// ```
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            pools: RefCell::new(HashMap::with_hasher(hash_builder)),
+            default_capacity: NonZeroUsize::new(DEFAULT_CAPACITY).expect("non-zero"),
+        }
+    }
+
+    /// Creates an empty set that hashes `TypeId`s with `hash_builder`, whose
+    /// pools are created with `capacity` slots.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `capacity` is 0.
+    pub fn with_default_capacity_and_hasher(capacity: usize, hash_builder: S) -> Result<Self> {
+        let default_capacity = NonZeroUsize::new(capacity)
+            .ok_or_else(|| Error::invalid_config("capacity must be at least 1"))?;
+
+        Ok(Self {
+            pools: RefCell::new(HashMap::with_hasher(hash_builder)),
+            default_capacity,
+        })
+    }
+
+    /// Registers a pool for `T` with its own capacity, replacing any pool
+    /// already registered for `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `capacity` is 0.
+    pub fn with_capacity_for<T: Poolable + 'static>(self, capacity: usize) -> Result<Self> {
+        let pool = FixedPool::<T>::new(capacity)?;
+        self.pools
+            .borrow_mut()
+            .insert(TypeId::of::<T>(), Box::new(pool));
+        Ok(self)
+    }
+
+    /// Returns the pool for `T`, creating it with the default capacity if needed.
+    pub fn pool<T: Poolable + 'static>(&self) -> &FixedPool<T> {
+        let pool: *const FixedPool<T> = {
+            let mut pools = self.pools.borrow_mut();
+            let pool = pools
+                .entry(TypeId::of::<T>())
+                .or_insert_with(|| Box::new(FixedPool::<T>::with_nonzero(self.default_capacity)));
+            pool.downcast_ref::<FixedPool<T>>()
+                .expect("pool is stored under its own TypeId")
+        };
+
+        // Safety: the pool is boxed, so rehashing the map does not move it,
+        // and entries are only replaced or removed through `self` by value
+        // or `&mut self`, which cannot happen while this borrow is alive
+        unsafe { &*pool }
+    }
+
+    /// Returns whether a pool for `T` has been created or registered.
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.pools.borrow().contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the number of pools in the set.
+    pub fn len(&self) -> usize {
+        self.pools.borrow().len()
+    }
+
+    /// Returns whether the set holds no pools yet.
+    pub fn is_empty(&self) -> bool {
+        self.pools.borrow().is_empty()
+    }
+
+    /// Returns the capacity used for pools created on first use.
+    pub fn default_capacity(&self) -> usize {
+        self.default_capacity.get()
+    }
+}
+
+impl<S: BuildHasher + Default> Default for PoolSet<S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<S> fmt::Debug for PoolSet<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoolSet")
+            .field("pools", &self.pools.borrow().len())
+            .field("default_capacity", &self.default_capacity)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Position(i32, i32);
+    struct Velocity(i32);
+
+    impl Poolable for Position {}
+    impl Poolable for Velocity {}
+
+    #[test]
+    fn pools_are_independent_per_type() {
+        let set = PoolSet::with_default_capacity(8)
+            .unwrap()
+            .with_capacity_for::<Position>(2)
+            .unwrap();
+        assert_eq!(set.len(), 1);
+        assert!(!set.contains::<Velocity>());
+
+        let positions = set.pool::<Position>();
+        let velocities = set.pool::<Velocity>();
+        assert_eq!(set.len(), 2);
+
+        let a = positions.allocate(Position(1, 2)).unwrap();
+        let b = positions.allocate(Position(3, 4)).unwrap();
+        assert!(positions.allocate(Position(5, 6)).is_err());
+
+        let v = velocities.allocate(Velocity(7)).unwrap();
+        assert_eq!(velocities.capacity(), 8);
+        assert_eq!(velocities.available(), 7);
+
+        assert_eq!(a.0 + a.1 + b.0 + b.1 + v.0, 17);
+    }
+
+    #[test]
+    fn pool_is_created_once() {
+        let set = PoolSet::new();
+        let handle = set.pool::<u32>().allocate(1).unwrap();
+
+        // Later lookups return the same pool even after other pools were added
+        set.pool::<u64>();
+        set.pool::<i8>();
+        assert!(core::ptr::eq(set.pool::<u32>(), set.pool::<u32>()));
+        assert_eq!(set.pool::<u32>().allocated(), 1);
+        assert_eq!(set.pool::<u32>().capacity(), 100);
+        drop(handle);
+    }
+
+    #[test]
+    fn zero_capacity_is_rejected() {
+        assert!(PoolSet::with_default_capacity(0).is_err());
+        assert!(PoolSet::new().with_capacity_for::<u8>(0).is_err());
+    }
+
+    #[test]
+    fn custom_hasher_is_used_for_lookups() {
+        use core::hash::Hasher;
+
+        /// Hashes a `TypeId` by folding its bytes, counting every hash
+        #[derive(Default)]
+        struct CountingHasher(u64);
+
+        impl Hasher for CountingHasher {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for &byte in bytes {
+                    self.0 = self.0.rotate_left(8) ^ u64::from(byte);
+                }
+            }
+        }
+
+        #[derive(Default)]
+        struct CountingState(core::cell::Cell<usize>);
+
+        impl BuildHasher for CountingState {
+            type Hasher = CountingHasher;
+
+            fn build_hasher(&self) -> CountingHasher {
+                self.0.set(self.0.get() + 1);
+                CountingHasher::default()
+            }
+        }
+
+        let set = PoolSet::with_default_capacity_and_hasher(4, CountingState::default()).unwrap();
+        let a = set.pool::<Position>().allocate(Position(1, 2)).unwrap();
+        let v = set.pool::<Velocity>().allocate(Velocity(3)).unwrap();
+
+        assert_eq!(set.pool::<Position>().allocated(), 1);
+        assert_eq!(set.pool::<Velocity>().capacity(), 4);
+        assert!(set.pools.borrow().hasher().0.get() >= 4);
+        assert_eq!(a.0 + a.1 + v.0, 6);
+    }
+}
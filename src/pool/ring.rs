@@ -0,0 +1,334 @@
+//! Ring buffer pool for objects released in allocation order.
+
+use crate::error::{Error, Result};
+use crate::handle::{OwnedHandle, PoolInterface, PoolUsage};
+use crate::pool::storage::SlotStorage;
+use crate::traits::Poolable;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::mem;
+use core::ptr;
+
+/// Position of the live region within the ring.
+struct RingState {
+    /// Slot the next allocation takes
+    head: usize,
+    /// Number of slots between the oldest live slot and `head`
+    len: usize,
+    /// Slots released ahead of older ones, reclaimed once the tail reaches them
+    released: Vec<bool>,
+}
+
+/// A fixed-size pool for objects that are freed in the order they were allocated.
+///
+/// Streaming workloads, such as decoders that hand out frames and retire
+/// them in sequence, don't need a general free list. `RingBufferPool`
+/// allocates by bumping a head index around a ring of slots and reclaims
+/// them by advancing the tail, so both operations are O(1) with no free
+/// list to maintain. Allocation hands out the same [`OwnedHandle`] as
+/// [`FixedPool`](crate::FixedPool).
+///
+/// Releasing a slot out of order is a logic error. Debug builds panic; in
+/// release builds the slot is reclaimed lazily, once every older slot has
+/// been released, and until then it still counts as allocated.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::RingBufferPool;
+///
+/// let pool = RingBufferPool::new(2).unwrap();
+///
+/// let first = pool.allocate(1u32).unwrap();
+/// let second = pool.allocate(2u32).unwrap();
+/// assert!(pool.allocate(3).is_err());
+///
+/// drop(first);
+/// let third = pool.allocate(3).unwrap();
+/// assert_eq!(third.index(), 0); // wrapped around
+/// # drop((second, third));
+/// ```
+pub struct RingBufferPool<T> {
+    storage: SlotStorage<T>,
+    state: RefCell<RingState>,
+}
+
+impl<T: Poolable> RingBufferPool<T> {
+    /// Creates a new ring buffer pool with the specified capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if capacity is 0.
+    pub fn new(capacity: usize) -> Result<Self> {
+        if capacity == 0 {
+            return Err(Error::invalid_config("capacity must be at least 1"));
+        }
+
+        Ok(Self {
+            storage: SlotStorage::new(capacity, mem::align_of::<T>()),
+            state: RefCell::new(RingState {
+                head: 0,
+                len: 0,
+                released: alloc::vec![false; capacity],
+            }),
+        })
+    }
+
+    /// Allocates an object in the slot after the most recently allocated one.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if the ring is full, including slots
+    /// released out of order that have not been reclaimed yet.
+    pub fn allocate(&self, mut value: T) -> Result<OwnedHandle<'_, T>> {
+        let capacity = self.capacity();
+        let index = {
+            let mut state = self.state.borrow_mut();
+            if state.len == capacity {
+                return Err(Error::PoolExhausted {
+                    capacity,
+                    allocated: capacity,
+                    can_grow: false,
+                    name: None,
+                });
+            }
+
+            let index = state.head;
+            state.head = (index + 1) % capacity;
+            state.len += 1;
+            index
+        };
+
+        value.on_acquire();
+        // Safety: the slot is outside the live region, so it holds no object
+        unsafe { self.slot(index).write(value) };
+
+        Ok(OwnedHandle::new(self, index))
+    }
+}
+
+impl<T> RingBufferPool<T> {
+    /// Returns the total capacity of the pool.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// Returns the number of slots available for allocation.
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.capacity() - self.allocated()
+    }
+
+    /// Returns the number of slots between the oldest live slot and the head.
+    #[inline]
+    pub fn allocated(&self) -> usize {
+        self.state.borrow().len
+    }
+
+    /// Returns whether the pool is full (no available slots).
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.available() == 0
+    }
+
+    /// Returns whether the pool is empty (all slots available).
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.allocated() == 0
+    }
+
+    /// Returns a pointer to the slot at `index`.
+    #[inline]
+    fn slot(&self, index: usize) -> *mut T {
+        debug_assert!(index < self.capacity(), "index out of bounds");
+        // Safety: index is in bounds of the storage
+        unsafe { self.storage.as_mut_ptr().add(index).cast::<T>() }
+    }
+}
+
+impl<T: Poolable> PoolUsage for RingBufferPool<T> {
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[inline]
+    fn available(&self) -> usize {
+        self.available()
+    }
+}
+
+impl<T: Poolable> PoolInterface<T> for RingBufferPool<T> {
+    #[inline]
+    fn get(&self, index: usize) -> &T {
+        // Safety: handles only exist for allocated, initialized slots
+        unsafe { &*self.slot(index) }
+    }
+
+    #[inline]
+    fn get_mut(&self, index: usize) -> &mut T {
+        // Safety: the owning handle has exclusive access to its slot
+        unsafe { &mut *self.slot(index) }
+    }
+
+    fn return_to_pool(&self, index: usize) {
+        // Safety: the slot was initialized by allocate and is released once
+        unsafe {
+            let value_ptr = self.slot(index);
+            (*value_ptr).on_release();
+            ptr::drop_in_place(value_ptr);
+        }
+
+        let capacity = self.capacity();
+        let in_order = {
+            let mut state = self.state.borrow_mut();
+            let tail = (state.head + capacity - state.len) % capacity;
+            if index == tail {
+                // Reclaim this slot and any released right behind it
+                state.len -= 1;
+                loop {
+                    let tail = (state.head + capacity - state.len) % capacity;
+                    if state.len == 0 || !state.released[tail] {
+                        break;
+                    }
+                    state.released[tail] = false;
+                    state.len -= 1;
+                }
+                true
+            } else {
+                state.released[index] = true;
+                false
+            }
+        };
+
+        debug_assert!(
+            in_order,
+            "RingBufferPool slots must be released in allocation order (released slot {})",
+            index
+        );
+    }
+
+    #[inline]
+    fn as_usage(&self) -> &dyn PoolUsage {
+        self
+    }
+
+    fn is_valid_index(&self, index: usize) -> bool {
+        let capacity = self.capacity();
+        if index >= capacity {
+            return false;
+        }
+
+        // Live slots are the `len` slots behind `head`, minus those
+        // released out of order
+        let state = self.state.borrow();
+        let tail = (state.head + capacity - state.len) % capacity;
+        (index + capacity - tail) % capacity < state.len && !state.released[index]
+    }
+}
+
+impl<T> Drop for RingBufferPool<T> {
+    fn drop(&mut self) {
+        if !mem::needs_drop::<T>() {
+            return;
+        }
+
+        // Drop objects whose handles were leaked
+        let capacity = self.capacity();
+        let state = self.state.get_mut();
+        for offset in 0..state.len {
+            let index = (state.head + capacity - state.len + offset) % capacity;
+            if !state.released[index] {
+                // Safety: unreleased slots in the live region hold an initialized object
+                unsafe { ptr::drop_in_place(self.storage.as_mut_ptr().add(index).cast::<T>()) };
+            }
+        }
+    }
+}
+
+// Safety: RingBufferPool is Send if T is Send (slot state is behind RefCell)
+unsafe impl<T: Send> Send for RingBufferPool<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fifo_allocate_and_release() {
+        let pool = RingBufferPool::new(4).unwrap();
+        let handles: Vec<_> = (0..4u32).map(|i| pool.allocate(i * 10).unwrap()).collect();
+        assert!(pool.is_full());
+        assert!(matches!(
+            pool.allocate(99),
+            Err(Error::PoolExhausted { capacity: 4, .. })
+        ));
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.index(), i);
+            assert_eq!(*handle, i as u32 * 10);
+        }
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn allocation_wraps_around() {
+        let pool = RingBufferPool::new(3).unwrap();
+        let mut live: alloc::collections::VecDeque<_> =
+            (0..3u32).map(|i| pool.allocate(i).unwrap()).collect();
+
+        // Keep the ring full while cycling through it several times
+        for value in 3..10u32 {
+            drop(live.pop_front());
+            let handle = pool.allocate(value).unwrap();
+            assert_eq!(handle.index(), value as usize % 3);
+            live.push_back(handle);
+        }
+
+        let values: Vec<u32> = live.iter().map(|handle| **handle).collect();
+        assert_eq!(values, [7, 8, 9]);
+        drop(live);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "released in allocation order")]
+    fn out_of_order_release_panics_in_debug() {
+        let pool = RingBufferPool::new(4).unwrap();
+        let _first = pool.allocate(1).unwrap();
+        let second = pool.allocate(2).unwrap();
+        drop(second);
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn out_of_order_release_is_reclaimed_lazily() {
+        let pool = RingBufferPool::new(2).unwrap();
+        let first = pool.allocate(1).unwrap();
+        let second = pool.allocate(2).unwrap();
+
+        drop(second);
+        assert_eq!(pool.available(), 0);
+        drop(first);
+        assert_eq!(pool.available(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn drop_releases_leaked_objects() {
+        use std::rc::Rc;
+
+        struct Tracked(#[allow(dead_code)] Rc<()>);
+        impl Poolable for Tracked {}
+
+        let tracker = Rc::new(());
+        let pool = RingBufferPool::new(3).unwrap();
+        drop(pool.allocate(Tracked(Rc::clone(&tracker))).unwrap());
+        core::mem::forget(pool.allocate(Tracked(Rc::clone(&tracker))).unwrap());
+        assert_eq!(Rc::strong_count(&tracker), 2);
+
+        drop(pool);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+}
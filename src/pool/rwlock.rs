@@ -0,0 +1,257 @@
+//! Read-mostly pool guarded by a `parking_lot::RwLock`.
+
+use crate::allocator::{Allocator, StackAllocator};
+use crate::error::{Error, Result};
+use alloc::vec::Vec;
+use core::fmt;
+use parking_lot::{MappedRwLockReadGuard, MappedRwLockWriteGuard, RwLock};
+use parking_lot::{RwLockReadGuard, RwLockWriteGuard};
+use std::sync::Arc;
+
+/// Slots and free list, kept behind the pool's lock.
+struct Slots<T> {
+    values: Vec<Option<T>>,
+    allocator: StackAllocator,
+}
+
+/// A thread-safe pool where reading objects only takes a shared lock.
+///
+/// [`ThreadSafePool`](crate::ThreadSafePool) serializes every operation
+/// through a mutex. `RwLockPool` keeps its slots behind a
+/// `parking_lot::RwLock` instead: [`RwLockHandle::read`] takes the lock in
+/// shared mode, so any number of threads can read pooled objects at the
+/// same time, while allocating, freeing and [`RwLockHandle::write`] take
+/// it exclusively. It suits pools whose objects are read far more often
+/// than they are allocated or changed.
+///
+/// Handles own a reference to the pool, are `Send` and `Sync` when `T` is,
+/// and return their slot when dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::RwLockPool;
+/// use std::thread;
+///
+/// let pool = RwLockPool::new(16).unwrap();
+/// let handle = pool.allocate(String::from("shared")).unwrap();
+///
+/// thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| assert_eq!(*handle.read(), "shared"));
+///     }
+/// });
+/// ```
+pub struct RwLockPool<T> {
+    slots: Arc<RwLock<Slots<T>>>,
+    capacity: usize,
+}
+
+impl<T> RwLockPool<T> {
+    /// Creates a pool with room for `capacity` objects.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `capacity` is 0.
+    pub fn new(capacity: usize) -> Result<Self> {
+        if capacity == 0 {
+            return Err(Error::invalid_config("capacity must be at least 1"));
+        }
+
+        let mut values = Vec::with_capacity(capacity);
+        values.resize_with(capacity, || None);
+
+        Ok(Self {
+            slots: Arc::new(RwLock::new(Slots {
+                values,
+                allocator: StackAllocator::new(capacity),
+            })),
+            capacity,
+        })
+    }
+
+    /// Moves `value` into a free slot, taking the lock exclusively.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if every slot is in use.
+    pub fn allocate(&self, value: T) -> Result<RwLockHandle<T>> {
+        let mut slots = self.slots.write();
+        let index = slots.allocator.allocate().ok_or(Error::PoolExhausted {
+            capacity: self.capacity,
+            allocated: self.capacity,
+            can_grow: false,
+            name: None,
+        })?;
+        slots.values[index] = Some(value);
+
+        Ok(RwLockHandle {
+            slots: Arc::clone(&self.slots),
+            index,
+        })
+    }
+
+    /// Returns the number of slots in the pool.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of free slots.
+    pub fn available(&self) -> usize {
+        self.slots.read().allocator.available()
+    }
+
+    /// Returns the number of allocated objects.
+    pub fn allocated(&self) -> usize {
+        self.capacity - self.available()
+    }
+}
+
+impl<T> fmt::Debug for RwLockPool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RwLockPool")
+            .field("capacity", &self.capacity)
+            .field("available", &self.available())
+            .finish()
+    }
+}
+
+/// A handle to an object in a [`RwLockPool`].
+///
+/// The object is reached through [`read`](Self::read) and
+/// [`write`](Self::write) guards rather than `Deref`, since each access
+/// needs the pool's lock. The slot is returned to the pool when the handle
+/// is dropped.
+pub struct RwLockHandle<T> {
+    slots: Arc<RwLock<Slots<T>>>,
+    index: usize,
+}
+
+impl<T> RwLockHandle<T> {
+    /// Locks the pool for reading and returns a guard for the object.
+    ///
+    /// Other readers are not blocked; writers wait until the guard is
+    /// dropped.
+    pub fn read(&self) -> MappedRwLockReadGuard<'_, T> {
+        let index = self.index;
+        RwLockReadGuard::map(self.slots.read(), |slots| {
+            slots.values[index]
+                .as_ref()
+                .expect("handle refers to an allocated slot")
+        })
+    }
+
+    /// Locks the pool exclusively and returns a mutable guard for the object.
+    pub fn write(&mut self) -> MappedRwLockWriteGuard<'_, T> {
+        let index = self.index;
+        RwLockWriteGuard::map(self.slots.write(), |slots| {
+            slots.values[index]
+                .as_mut()
+                .expect("handle refers to an allocated slot")
+        })
+    }
+
+    /// Returns the index of this handle's slot in the pool.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<T> Drop for RwLockHandle<T> {
+    fn drop(&mut self) {
+        // Drop the object after releasing the lock, so its destructor can
+        // use the pool.
+        let value = {
+            let mut slots = self.slots.write();
+            let value = slots.values[self.index].take();
+            slots.allocator.free(self.index);
+            value
+        };
+        drop(value);
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RwLockHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RwLockHandle")
+            .field("index", &self.index)
+            .field("value", &*self.read())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::thread;
+
+    #[test]
+    fn allocate_read_write_and_release() {
+        let pool = RwLockPool::new(2).unwrap();
+        let mut a = pool.allocate(1).unwrap();
+        let b = pool.allocate(2).unwrap();
+
+        assert!(matches!(pool.allocate(3), Err(Error::PoolExhausted { .. })));
+
+        *a.write() += 10;
+        assert_eq!(*a.read(), 11);
+        assert_eq!(*b.read(), 2);
+        assert_eq!(pool.allocated(), 2);
+
+        drop(a);
+        assert_eq!(pool.available(), 1);
+        let c = pool.allocate(3).unwrap();
+        assert_eq!(*c.read(), 3);
+    }
+
+    #[test]
+    fn concurrent_readers_with_occasional_writers() {
+        const READERS: usize = 8;
+        const PAIRS: usize = 16;
+
+        let pool = RwLockPool::new(PAIRS + 4).unwrap();
+        // Each pair always sums to 100, so a torn write would show up as a
+        // different sum in a reader.
+        let pairs: Vec<_> = (0..PAIRS)
+            .map(|i| pool.allocate((i as u64, 100 - i as u64)).unwrap())
+            .collect();
+        let pairs = RwLock::new(pairs);
+        let done = AtomicBool::new(false);
+
+        thread::scope(|s| {
+            for _ in 0..READERS {
+                s.spawn(|| {
+                    while !done.load(Ordering::Relaxed) {
+                        for handle in pairs.read().iter() {
+                            let pair = handle.read();
+                            assert_eq!(pair.0 + pair.1, 100);
+                        }
+                    }
+                });
+            }
+
+            s.spawn(|| {
+                for round in 0..200u64 {
+                    for handle in pairs.write().iter_mut() {
+                        let mut pair = handle.write();
+                        pair.0 = round % 100;
+                        pair.1 = 100 - round % 100;
+                    }
+
+                    // Allocation and release also take the lock exclusively.
+                    let extra = pool.allocate((50, 50)).unwrap();
+                    assert_eq!(extra.read().0 + extra.read().1, 100);
+                    drop(extra);
+                }
+                done.store(true, Ordering::Relaxed);
+            });
+        });
+
+        assert_eq!(pool.allocated(), PAIRS);
+        drop(pairs);
+        assert_eq!(pool.available(), PAIRS + 4);
+    }
+}
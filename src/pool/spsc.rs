@@ -0,0 +1,347 @@
+//! Lock-free pool for one allocating thread and one freeing thread.
+
+use crate::error::{Error, Result};
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Slots and the ring of free slot indices, shared by both halves.
+///
+/// `head` and `tail` count pops and pushes since creation; the free indices
+/// are in ring positions `head..tail` (modulo the capacity). Only the
+/// allocating half advances `head` and only the releasing half advances
+/// `tail`.
+struct Shared<T> {
+    slots: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    ring: Box<[AtomicUsize]>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+// Safety: each slot is accessed by the single handle that owns it, and the
+// ring positions are handed between the two halves through `head` and `tail`
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    #[inline]
+    fn available(&self) -> usize {
+        let head = self.head.load(Ordering::Acquire);
+        self.tail.load(Ordering::Acquire).wrapping_sub(head)
+    }
+}
+
+/// A pool for handing objects from one thread to another without locks.
+///
+/// The pool is split into two halves: `SpscPool` allocates and an
+/// [`SpscReleaser`] frees. Each half takes `&mut self`, so there is exactly
+/// one allocating thread and one freeing thread at a time, which lets the
+/// free slots live in a single-producer single-consumer ring indexed by two
+/// atomic counters: allocation pops from the ring and freeing pushes back,
+/// with no locks or compare-and-swap loops.
+///
+/// Handles are sent to the releasing thread, typically over a channel, and
+/// given back with [`SpscReleaser::free`]. A handle dropped anywhere else
+/// drops its object, but its slot is not returned to the pool.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::SpscPool;
+/// use std::sync::mpsc;
+/// use std::thread;
+///
+/// let (mut pool, mut releaser) = SpscPool::new(4).unwrap();
+/// let (tx, rx) = mpsc::channel();
+///
+/// let consumer = thread::spawn(move || {
+///     for buffer in rx {
+///         releaser.free(buffer);
+///     }
+/// });
+///
+/// for i in 0..100 {
+///     let buffer = loop {
+///         match pool.allocate([i as f32; 64]) {
+///             Ok(buffer) => break buffer,
+///             Err(_) => thread::yield_now(),
+///         }
+///     };
+///     tx.send(buffer).unwrap();
+/// }
+/// drop(tx);
+/// consumer.join().unwrap();
+/// assert_eq!(pool.available(), 4);
+/// ```
+pub struct SpscPool<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> SpscPool<T> {
+    /// Creates a pool with room for `capacity` objects, returning its
+    /// allocating and releasing halves.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `capacity` is 0.
+    pub fn new(capacity: usize) -> Result<(Self, SpscReleaser<T>)> {
+        if capacity == 0 {
+            return Err(Error::invalid_config("capacity must be at least 1"));
+        }
+
+        let shared = Arc::new(Shared {
+            slots: (0..capacity)
+                .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+                .collect(),
+            ring: (0..capacity).map(AtomicUsize::new).collect(),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(capacity),
+        });
+
+        Ok((
+            Self {
+                shared: Arc::clone(&shared),
+            },
+            SpscReleaser { shared },
+        ))
+    }
+
+    /// Moves `value` into a free slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if every slot is in use. Slots freed
+    /// concurrently become available to later calls.
+    pub fn allocate(&mut self, value: T) -> Result<SpscHandle<T>> {
+        let shared = &*self.shared;
+        let head = shared.head.load(Ordering::Relaxed);
+        // Acquire pairs with the release in `free`, so the freed object has
+        // been dropped before its slot is reused
+        if shared.tail.load(Ordering::Acquire) == head {
+            return Err(Error::PoolExhausted {
+                capacity: shared.capacity(),
+                allocated: shared.capacity(),
+                can_grow: false,
+                name: None,
+            });
+        }
+
+        let index = shared.ring[head % shared.capacity()].load(Ordering::Relaxed);
+        // Safety: the index was just popped from the free ring, so no handle
+        // owns the slot
+        unsafe { (*shared.slots[index].get()).write(value) };
+        shared.head.store(head.wrapping_add(1), Ordering::Release);
+
+        Ok(SpscHandle {
+            shared: Arc::clone(&self.shared),
+            index,
+        })
+    }
+
+    /// Returns the number of slots in the pool.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity()
+    }
+
+    /// Returns the number of free slots.
+    ///
+    /// Slots freed concurrently may not be counted yet.
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.shared.available()
+    }
+}
+
+impl<T> fmt::Debug for SpscPool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpscPool")
+            .field("capacity", &self.capacity())
+            .field("available", &self.available())
+            .finish()
+    }
+}
+
+/// The freeing half of an [`SpscPool`].
+pub struct SpscReleaser<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> SpscReleaser<T> {
+    /// Drops the handle's object and returns its slot to the pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle was allocated from a different pool.
+    pub fn free(&mut self, handle: SpscHandle<T>) {
+        assert!(
+            Arc::ptr_eq(&self.shared, &handle.shared),
+            "handle belongs to a different pool"
+        );
+        let index = handle.index;
+        drop(handle);
+
+        let shared = &*self.shared;
+        let tail = shared.tail.load(Ordering::Relaxed);
+        // Every free index came from a distinct allocation, so the ring never
+        // holds more than `capacity` entries and this position was popped
+        // by an allocation that happened before the handle reached us
+        shared.ring[tail % shared.capacity()].store(index, Ordering::Relaxed);
+        shared.tail.store(tail.wrapping_add(1), Ordering::Release);
+    }
+
+    /// Returns the number of slots in the pool.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.shared.capacity()
+    }
+}
+
+impl<T> fmt::Debug for SpscReleaser<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpscReleaser")
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+/// A handle to an object in an [`SpscPool`].
+///
+/// Give it back with [`SpscReleaser::free`]. Dropping it drops the object
+/// but leaves its slot allocated.
+pub struct SpscHandle<T> {
+    shared: Arc<Shared<T>>,
+    index: usize,
+}
+
+impl<T> SpscHandle<T> {
+    /// Returns the index of this handle's slot in the pool.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<T> Deref for SpscHandle<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // Safety: the handle owns the slot, which holds an initialized object
+        unsafe { (*self.shared.slots[self.index].get()).assume_init_ref() }
+    }
+}
+
+impl<T> DerefMut for SpscHandle<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        // Safety: as above, and `&mut self` makes the access exclusive
+        unsafe { (*self.shared.slots[self.index].get()).assume_init_mut() }
+    }
+}
+
+impl<T> Drop for SpscHandle<T> {
+    fn drop(&mut self) {
+        // Safety: the handle owns the initialized object and drops it once
+        unsafe { ptr::drop_in_place((*self.shared.slots[self.index].get()).as_mut_ptr()) };
+    }
+}
+
+// Safety: a handle gives access to its own object only, like a `Box<T>`
+unsafe impl<T: Send> Send for SpscHandle<T> {}
+unsafe impl<T: Sync> Sync for SpscHandle<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for SpscHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpscHandle")
+            .field("index", &self.index)
+            .field("value", &**self)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::vec::Vec;
+
+    #[test]
+    fn allocate_until_exhausted_then_free() {
+        let (mut pool, mut releaser) = SpscPool::new(2).unwrap();
+        let a = pool.allocate(1).unwrap();
+        let mut b = pool.allocate(2).unwrap();
+
+        assert!(matches!(pool.allocate(3), Err(Error::PoolExhausted { .. })));
+
+        *b += 10;
+        assert_eq!((*a, *b), (1, 12));
+
+        releaser.free(a);
+        assert_eq!(pool.available(), 1);
+        let c = pool.allocate(3).unwrap();
+        assert_eq!(*c, 3);
+
+        releaser.free(b);
+        releaser.free(c);
+        assert_eq!(pool.available(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "handle belongs to a different pool")]
+    fn free_rejects_foreign_handles() {
+        let (mut pool, _releaser) = SpscPool::new(1).unwrap();
+        let (_other, mut other_releaser) = SpscPool::<u32>::new(1).unwrap();
+        other_releaser.free(pool.allocate(1).unwrap());
+    }
+
+    #[test]
+    fn two_threads_never_lose_or_share_slots() {
+        const CAPACITY: usize = 64;
+        const ITERATIONS: usize = 1_000_000;
+
+        let (mut pool, mut releaser) = SpscPool::new(CAPACITY).unwrap();
+        let in_use: Vec<AtomicBool> = (0..CAPACITY).map(|_| AtomicBool::new(false)).collect();
+        let (tx, rx) = mpsc::sync_channel::<SpscHandle<usize>>(CAPACITY);
+
+        thread::scope(|s| {
+            s.spawn(|| {
+                let mut expected = 0;
+                for handle in rx {
+                    assert_eq!(*handle, expected);
+                    expected += 1;
+                    assert!(in_use[handle.index()].swap(false, Ordering::Relaxed));
+                    releaser.free(handle);
+                }
+                assert_eq!(expected, ITERATIONS);
+            });
+
+            for i in 0..ITERATIONS {
+                let handle = loop {
+                    match pool.allocate(i) {
+                        Ok(handle) => break handle,
+                        Err(_) => thread::yield_now(),
+                    }
+                };
+                assert!(!in_use[handle.index()].swap(true, Ordering::Relaxed));
+                tx.send(handle).unwrap();
+            }
+            drop(tx);
+        });
+
+        assert_eq!(pool.available(), CAPACITY);
+        assert!(in_use.iter().all(|used| !used.load(Ordering::Relaxed)));
+    }
+}
@@ -0,0 +1,287 @@
+//! Heap-free memory pool with inline storage.
+
+use crate::error::{Error, Result};
+use crate::handle::{OwnedHandle, PoolInterface, PoolUsage};
+use crate::traits::Poolable;
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::ptr;
+
+/// Slot bookkeeping for a [`StaticPool`].
+struct SlotState<const N: usize> {
+    /// Stack of free indices; the top is `free[free_len - 1]`
+    free: [usize; N],
+    /// Number of valid entries in `free`
+    free_len: usize,
+    /// Whether each slot holds a live object
+    occupied: [bool; N],
+}
+
+/// A fixed-capacity pool that stores its `N` slots inline, without any heap allocation.
+///
+/// Every other pool allocates its storage with `alloc`, which rules them out
+/// on targets that have no allocator at all. `StaticPool` keeps the slots and
+/// the free list in arrays sized by the const parameter `N`, and
+/// [`new`](Self::new) is a `const fn`, so a pool can live on the stack, inside
+/// another struct, or in a `static` behind whatever lock the platform
+/// provides. Allocation hands out the same [`OwnedHandle`] as
+/// [`FixedPool`](crate::FixedPool).
+///
+/// Like `FixedPool`, the pool is not `Sync`.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::StaticPool;
+///
+/// let pool = StaticPool::<u32, 16>::new();
+///
+/// let mut handle = pool.allocate(7).unwrap();
+/// *handle += 1;
+/// assert_eq!(*handle, 8);
+/// assert_eq!(pool.available(), 15);
+/// ```
+pub struct StaticPool<T, const N: usize> {
+    storage: UnsafeCell<[MaybeUninit<T>; N]>,
+    state: UnsafeCell<SlotState<N>>,
+}
+
+impl<T, const N: usize> StaticPool<T, N> {
+    /// Creates an empty pool. Usable in `const` and `static` initializers.
+    pub const fn new() -> Self {
+        // Slot 0 ends up on top so indices are handed out in ascending order
+        let mut free = [0; N];
+        let mut i = 0;
+        while i < N {
+            free[i] = N - 1 - i;
+            i += 1;
+        }
+
+        Self {
+            // Safety: an array of `MaybeUninit` needs no initialization
+            storage: UnsafeCell::new(unsafe { MaybeUninit::uninit().assume_init() }),
+            state: UnsafeCell::new(SlotState {
+                free,
+                free_len: N,
+                occupied: [false; N],
+            }),
+        }
+    }
+
+    /// Returns the total capacity of the pool, which is always `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of available (free) slots in the pool.
+    #[inline]
+    pub fn available(&self) -> usize {
+        // Safety: the state is only accessed briefly and never across user code
+        unsafe { (*self.state.get()).free_len }
+    }
+
+    /// Returns the number of currently allocated objects.
+    #[inline]
+    pub fn allocated(&self) -> usize {
+        N - self.available()
+    }
+
+    /// Returns whether the pool is full (no available slots).
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.available() == 0
+    }
+
+    /// Returns whether the pool is empty (all slots available).
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.available() == N
+    }
+
+    /// Returns a pointer to the slot at `index`.
+    #[inline]
+    fn slot(&self, index: usize) -> *mut T {
+        debug_assert!(index < N, "index out of bounds");
+        // Safety: index is in bounds; no reference to the array is created
+        unsafe {
+            self.storage
+                .get()
+                .cast::<MaybeUninit<T>>()
+                .add(index)
+                .cast::<T>()
+        }
+    }
+}
+
+impl<T: Poolable, const N: usize> StaticPool<T, N> {
+    /// Allocates an object from the pool with the given initial value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if every slot is in use.
+    pub fn allocate(&self, mut value: T) -> Result<OwnedHandle<'_, T>> {
+        let index = {
+            // Safety: no other reference to the state is live
+            let state = unsafe { &mut *self.state.get() };
+            if state.free_len == 0 {
+                return Err(Error::PoolExhausted {
+                    capacity: N,
+                    allocated: N,
+                    can_grow: false,
+                    name: None,
+                });
+            }
+            state.free_len -= 1;
+            let index = state.free[state.free_len];
+            state.occupied[index] = true;
+            index
+        };
+
+        value.on_acquire();
+        // Safety: the slot was free, so nothing is overwritten
+        unsafe { self.slot(index).write(value) };
+
+        Ok(OwnedHandle::new(self, index))
+    }
+}
+
+impl<T: Poolable, const N: usize> PoolUsage for StaticPool<T, N> {
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
+
+    #[inline]
+    fn available(&self) -> usize {
+        self.available()
+    }
+}
+
+impl<T: Poolable, const N: usize> PoolInterface<T> for StaticPool<T, N> {
+    #[inline]
+    fn get(&self, index: usize) -> &T {
+        // Safety: handles only exist for allocated, initialized slots
+        unsafe { &*self.slot(index) }
+    }
+
+    #[inline]
+    fn get_mut(&self, index: usize) -> &mut T {
+        // Safety: the owning handle has exclusive access to its slot
+        unsafe { &mut *self.slot(index) }
+    }
+
+    fn return_to_pool(&self, index: usize) {
+        // Safety: the slot was initialized by allocate and is released once
+        unsafe {
+            let value_ptr = self.slot(index);
+            (*value_ptr).on_release();
+            ptr::drop_in_place(value_ptr);
+        }
+
+        // Safety: no other reference to the state is live
+        let state = unsafe { &mut *self.state.get() };
+        debug_assert!(state.occupied[index], "double free detected");
+        state.occupied[index] = false;
+        state.free[state.free_len] = index;
+        state.free_len += 1;
+    }
+
+    #[inline]
+    fn as_usage(&self) -> &dyn PoolUsage {
+        self
+    }
+
+    #[inline]
+    fn is_valid_index(&self, index: usize) -> bool {
+        // Safety: no other reference to the state is live
+        let state = unsafe { &*self.state.get() };
+        index < N && state.occupied[index]
+    }
+}
+
+impl<T, const N: usize> Default for StaticPool<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for StaticPool<T, N> {
+    fn drop(&mut self) {
+        if !core::mem::needs_drop::<T>() {
+            return;
+        }
+
+        // Drop objects whose handles were leaked
+        for index in 0..N {
+            if self.state.get_mut().occupied[index] {
+                // Safety: occupied slots hold an initialized object
+                unsafe { ptr::drop_in_place(self.slot(index)) };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocates_to_capacity_without_heap() {
+        let pool = StaticPool::<u32, 16>::new();
+        assert_eq!(pool.capacity(), 16);
+        assert!(pool.is_empty());
+
+        let handles: [OwnedHandle<'_, u32>; 16] =
+            core::array::from_fn(|i| pool.allocate(i as u32 * 10).unwrap());
+        assert!(pool.is_full());
+        assert!(matches!(
+            pool.allocate(99),
+            Err(Error::PoolExhausted { capacity: 16, .. })
+        ));
+        assert_eq!(*handles[3], 30);
+        assert_eq!(handles[15].index(), 15);
+
+        drop(handles);
+        assert!(pool.is_empty());
+        assert_eq!(*pool.allocate(5).unwrap(), 5);
+    }
+
+    #[test]
+    fn freed_slot_is_reused() {
+        let pool = StaticPool::<u32, 2>::new();
+        let a = pool.allocate(1).unwrap();
+        let b = pool.allocate(2).unwrap();
+        let index = a.index();
+        drop(a);
+
+        let c = pool.allocate(3).unwrap();
+        assert_eq!(c.index(), index);
+        assert_eq!(*b + *c, 5);
+    }
+
+    #[test]
+    fn new_is_usable_in_const_context() {
+        #[allow(clippy::declare_interior_mutable_const)]
+        const EMPTY: StaticPool<u8, 4> = StaticPool::new();
+        let pool = EMPTY;
+        assert_eq!(pool.available(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn drop_releases_leaked_objects() {
+        use std::rc::Rc;
+
+        struct Tracked(#[allow(dead_code)] Rc<()>);
+        impl Poolable for Tracked {}
+
+        let tracker = Rc::new(());
+        let pool = StaticPool::<Tracked, 4>::new();
+        core::mem::forget(pool.allocate(Tracked(Rc::clone(&tracker))).unwrap());
+        assert_eq!(Rc::strong_count(&tracker), 2);
+
+        drop(pool);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+}
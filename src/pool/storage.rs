@@ -0,0 +1,123 @@
+//! Slot storage that is either owned by the pool or borrowed from the caller.
+
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use core::mem::MaybeUninit;
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+/// A fixed-length slice of slots.
+///
+/// Storage created with [`new`](Self::new) or [`try_new`](Self::try_new) is
+/// owned, aligned to the requested alignment and freed on drop. Storage
+/// created with [`borrowed`](Self::borrowed) points into a caller-provided
+/// buffer and is never freed by the pool.
+pub(crate) struct SlotStorage<T> {
+    ptr: NonNull<MaybeUninit<T>>,
+    len: usize,
+    /// Layout to free on drop; `None` for borrowed or zero-sized storage
+    layout: Option<Layout>,
+}
+
+impl<T> SlotStorage<T> {
+    /// Allocates `len` uninitialized slots whose first slot is aligned to
+    /// `align` (or `T`'s alignment, if larger).
+    ///
+    /// Aborts on out-of-memory like `Vec::with_capacity`, and panics if the
+    /// size overflows.
+    pub(crate) fn new(len: usize, align: usize) -> Self {
+        let layout = Self::layout(len, align).expect("capacity overflow");
+        Self::allocate(layout, len).unwrap_or_else(|| handle_alloc_error(layout))
+    }
+
+    /// Like [`new`](Self::new), but returns `None` if the size overflows or
+    /// the allocation fails.
+    pub(crate) fn try_new(len: usize, align: usize) -> Option<Self> {
+        Self::allocate(Self::layout(len, align)?, len)
+    }
+
+    /// Wraps a caller-owned buffer of `len` slots.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non-null, aligned for `T` and valid for reads and writes
+    /// of `len` slots for as long as the storage exists, and nothing else may
+    /// access the buffer in that time.
+    pub(crate) unsafe fn borrowed(ptr: NonNull<MaybeUninit<T>>, len: usize) -> Self {
+        Self {
+            ptr,
+            len,
+            layout: None,
+        }
+    }
+
+    /// Returns a pointer to the first slot that may be written through a shared reference.
+    #[inline]
+    pub(crate) fn as_mut_ptr(&self) -> *mut MaybeUninit<T> {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns the number of bytes allocated for the slots.
+    ///
+    /// Borrowed and zero-sized storage allocate nothing, so they report 0.
+    #[inline]
+    pub(crate) fn heap_bytes(&self) -> usize {
+        self.layout.map_or(0, |layout| layout.size())
+    }
+
+    /// Returns the layout of `len` slots aligned to at least `align`.
+    fn layout(len: usize, align: usize) -> Option<Layout> {
+        Layout::array::<MaybeUninit<T>>(len)
+            .ok()?
+            .align_to(align)
+            .ok()
+    }
+
+    /// Allocates `len` slots described by `layout`.
+    fn allocate(layout: Layout, len: usize) -> Option<Self> {
+        if layout.size() == 0 {
+            // Nothing to allocate; any well-aligned non-null pointer will do
+            let dangling = layout.align() as *mut MaybeUninit<T>;
+            return Some(Self {
+                // Safety: alignments are never zero
+                ptr: unsafe { NonNull::new_unchecked(dangling) },
+                len,
+                layout: None,
+            });
+        }
+
+        // Safety: the layout has a non-zero size
+        let raw = unsafe { alloc(layout) };
+        Some(Self {
+            ptr: NonNull::new(raw.cast::<MaybeUninit<T>>())?,
+            len,
+            layout: Some(layout),
+        })
+    }
+}
+
+impl<T> Deref for SlotStorage<T> {
+    type Target = [MaybeUninit<T>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // Safety: `ptr` is valid for `len` slots for the storage's lifetime
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for SlotStorage<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safety: as in `deref`, and `&mut self` guarantees exclusive access
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> Drop for SlotStorage<T> {
+    fn drop(&mut self) {
+        if let Some(layout) = self.layout {
+            // Safety: owned storage was allocated with exactly this layout
+            unsafe { dealloc(self.ptr.as_ptr().cast::<u8>(), layout) };
+        }
+    }
+}
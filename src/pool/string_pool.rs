@@ -0,0 +1,216 @@
+//! Pool of reusable string buffers.
+
+use super::FixedPool;
+use crate::config::PoolConfig;
+use crate::error::Result;
+use crate::handle::OwnedHandle;
+use alloc::string::String;
+use core::fmt;
+use core::ops::Deref;
+
+/// A pool of `String` buffers that keep their allocation across uses.
+///
+/// Building many short-lived strings allocates and frees a buffer each
+/// time. `StringPool` wraps a [`FixedPool<String>`] whose strings are
+/// cleared, not dropped, when released, so a [`PooledString`] acquired
+/// later starts empty but reuses the capacity a previous user grew it to.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::fmt::Write;
+/// use fastalloc::StringPool;
+///
+/// let pool = StringPool::new(8).unwrap();
+///
+/// let mut line = pool.acquire().unwrap();
+/// write!(line, "{} requests in {}ms", 42, 7).unwrap();
+/// assert_eq!(&*line, "42 requests in 7ms");
+/// ```
+pub struct StringPool {
+    pool: FixedPool<String>,
+}
+
+impl StringPool {
+    /// Creates a pool of `capacity` strings, each allocated on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `capacity` is 0.
+    pub fn new(capacity: usize) -> Result<Self> {
+        Self::with_string_capacity(capacity, 0)
+    }
+
+    /// Creates a pool of `capacity` strings that each start with room for
+    /// `bytes` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `capacity` is 0.
+    pub fn with_string_capacity(capacity: usize, bytes: usize) -> Result<Self> {
+        let config = PoolConfig::builder()
+            .capacity(capacity)
+            .reset_fn(move || String::with_capacity(bytes), String::clear)
+            .build()?;
+        Ok(Self {
+            pool: FixedPool::with_config(config)?,
+        })
+    }
+
+    /// Acquires an empty string, reusing a released buffer when one is
+    /// available.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if every string is in use.
+    #[track_caller]
+    pub fn acquire(&self) -> Result<PooledString<'_>> {
+        let mut handle = self.pool.allocate_recycled()?;
+        handle.clear();
+        Ok(PooledString { handle })
+    }
+
+    /// Returns the number of strings in the pool.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.pool.capacity()
+    }
+
+    /// Returns the number of strings available to acquire.
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.pool.available()
+    }
+
+    /// Returns the underlying pool.
+    #[inline]
+    pub fn as_pool(&self) -> &FixedPool<String> {
+        &self.pool
+    }
+}
+
+impl fmt::Debug for StringPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StringPool")
+            .field("capacity", &self.capacity())
+            .field("available", &self.available())
+            .finish()
+    }
+}
+
+/// A string acquired from a [`StringPool`].
+///
+/// Dereferences to `str` and implements [`fmt::Write`], so it can be the
+/// target of `write!`. [`as_mut_string`](Self::as_mut_string) gives access
+/// to the full `String` API. Dropping it clears the string, keeping its
+/// capacity, and returns it to the pool.
+pub struct PooledString<'pool> {
+    handle: OwnedHandle<'pool, String>,
+}
+
+impl PooledString<'_> {
+    /// Returns the contents as a string slice.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.handle.as_str()
+    }
+
+    /// Returns the underlying `String`.
+    #[inline]
+    pub fn as_mut_string(&mut self) -> &mut String {
+        &mut self.handle
+    }
+
+    /// Returns the capacity of the underlying buffer in bytes.
+    #[inline]
+    pub fn buffer_capacity(&self) -> usize {
+        self.handle.capacity()
+    }
+}
+
+impl Deref for PooledString<'_> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Write for PooledString<'_> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.handle.push_str(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.handle.push(c);
+        Ok(())
+    }
+}
+
+impl fmt::Display for PooledString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for PooledString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    #[test]
+    fn write_formatted_content() {
+        let pool = StringPool::new(2).unwrap();
+        let mut s = pool.acquire().unwrap();
+
+        let id = 7;
+        write!(s, "id-{:03}", id).unwrap();
+        s.write_char('!').unwrap();
+        s.as_mut_string().push_str("?#");
+
+        assert_eq!(&*s, "id-007!?#");
+        assert_eq!(s.len(), 9);
+        assert_eq!(pool.available(), 1);
+    }
+
+    #[test]
+    fn reacquired_string_is_empty_and_keeps_capacity() {
+        let pool = StringPool::new(1).unwrap();
+
+        let mut s = pool.acquire().unwrap();
+        for i in 0..100 {
+            write!(s, "{},", i).unwrap();
+        }
+        let grown = s.buffer_capacity();
+        assert!(grown >= s.len());
+        drop(s);
+
+        let s = pool.acquire().unwrap();
+        assert!(s.is_empty());
+        assert_eq!(s.buffer_capacity(), grown);
+    }
+
+    #[test]
+    fn with_string_capacity_presizes_buffers() {
+        let pool = StringPool::with_string_capacity(2, 256).unwrap();
+        let s = pool.acquire().unwrap();
+        assert!(s.buffer_capacity() >= 256);
+    }
+
+    #[test]
+    fn acquire_fails_when_exhausted() {
+        let pool = StringPool::new(1).unwrap();
+        let _s = pool.acquire().unwrap();
+        assert!(pool.acquire().is_err());
+    }
+}
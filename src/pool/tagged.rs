@@ -0,0 +1,201 @@
+//! Fixed pool that stores a small tag alongside each slot.
+
+use crate::config::PoolConfig;
+use crate::error::Result;
+use crate::handle::OwnedHandle;
+use crate::pool::FixedPool;
+use crate::traits::Poolable;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+/// A fixed-size pool that keeps a `Copy` tag next to every allocated object.
+///
+/// Sideband data such as a priority or owner id often has to travel with a
+/// pooled object without being part of `T`. `TaggedPool` stores it in a
+/// parallel array indexed by slot: [`allocate`](Self::allocate) sets the
+/// tag, the handle reads or updates it, and it is cleared when the handle
+/// is dropped so a reused slot never exposes the previous owner's tag.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::TaggedPool;
+///
+/// let pool = TaggedPool::<String, u32>::new(16).unwrap();
+///
+/// let mut job = pool.allocate(String::from("render"), 3).unwrap();
+/// assert_eq!(job.tag(), 3);
+///
+/// job.set_tag(1);
+/// assert_eq!(job.tag(), 1);
+/// assert_eq!(*job, "render");
+/// ```
+pub struct TaggedPool<T, Tag: Copy> {
+    pool: FixedPool<T>,
+    /// Tag of each slot; `None` while the slot is free
+    tags: Box<[Cell<Option<Tag>>]>,
+}
+
+impl<T: Poolable, Tag: Copy> TaggedPool<T, Tag> {
+    /// Creates a new tagged pool with the specified capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if capacity is 0.
+    pub fn new(capacity: usize) -> Result<Self> {
+        Ok(Self::from_pool(FixedPool::new(capacity)?))
+    }
+
+    /// Creates a new tagged pool with the specified configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error [`FixedPool::with_config`] would return.
+    pub fn with_config(config: PoolConfig<T>) -> Result<Self> {
+        Ok(Self::from_pool(FixedPool::with_config(config)?))
+    }
+
+    /// Adds an empty tag array sized to `pool`.
+    fn from_pool(pool: FixedPool<T>) -> Self {
+        let tags: Vec<_> = (0..pool.capacity()).map(|_| Cell::new(None)).collect();
+        Self {
+            pool,
+            tags: tags.into_boxed_slice(),
+        }
+    }
+
+    /// Allocates an object and tags its slot with `tag`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if the pool is at capacity.
+    pub fn allocate(&self, value: T, tag: Tag) -> Result<TaggedHandle<'_, T, Tag>> {
+        let handle = self.pool.allocate(value)?;
+        self.tags[handle.index()].set(Some(tag));
+
+        Ok(TaggedHandle {
+            handle,
+            tags: &self.tags,
+        })
+    }
+
+    /// Returns the total capacity of the pool.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.pool.capacity()
+    }
+
+    /// Returns the number of available (free) slots in the pool.
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.pool.available()
+    }
+
+    /// Returns the number of currently allocated objects.
+    #[inline]
+    pub fn allocated(&self) -> usize {
+        self.pool.allocated()
+    }
+}
+
+/// Handle to an object allocated from a [`TaggedPool`].
+///
+/// Dereferences to the pooled object like an [`OwnedHandle`] and gives
+/// access to the slot's tag.
+pub struct TaggedHandle<'pool, T, Tag: Copy> {
+    handle: OwnedHandle<'pool, T>,
+    tags: &'pool [Cell<Option<Tag>>],
+}
+
+impl<T, Tag: Copy> TaggedHandle<'_, T, Tag> {
+    /// Returns the internal index of this handle.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.handle.index()
+    }
+
+    /// Returns the tag of this handle's slot.
+    #[inline]
+    pub fn tag(&self) -> Tag {
+        self.tags[self.index()]
+            .get()
+            .expect("allocated slots are always tagged")
+    }
+
+    /// Replaces the tag of this handle's slot.
+    #[inline]
+    pub fn set_tag(&mut self, tag: Tag) {
+        self.tags[self.index()].set(Some(tag));
+    }
+}
+
+impl<T, Tag: Copy> Deref for TaggedHandle<'_, T, Tag> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl<T, Tag: Copy> DerefMut for TaggedHandle<'_, T, Tag> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.handle
+    }
+}
+
+impl<T, Tag: Copy> Drop for TaggedHandle<'_, T, Tag> {
+    fn drop(&mut self) {
+        // The inner handle frees the slot right after this
+        self.tags[self.index()].set(None);
+    }
+}
+
+impl<T: fmt::Debug, Tag: Copy + fmt::Debug> fmt::Debug for TaggedHandle<'_, T, Tag> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaggedHandle")
+            .field("index", &self.index())
+            .field("tag", &self.tag())
+            .field("value", &&**self)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tags_are_stored_per_slot() {
+        let pool = TaggedPool::<u64, u32>::new(4).unwrap();
+        let a = pool.allocate(10, 1).unwrap();
+        let mut b = pool.allocate(20, 2).unwrap();
+
+        assert_eq!((a.tag(), *a), (1, 10));
+        assert_eq!((b.tag(), *b), (2, 20));
+
+        b.set_tag(7);
+        *b += 1;
+        assert_eq!((b.tag(), *b), (7, 21));
+        assert_eq!(a.tag(), 1);
+        assert_eq!(pool.allocated(), 2);
+    }
+
+    #[test]
+    fn tags_do_not_leak_across_reuse() {
+        let pool = TaggedPool::<u64, u32>::new(1).unwrap();
+        let first = pool.allocate(1, 42).unwrap();
+        let index = first.index();
+        drop(first);
+        assert_eq!(pool.tags[index].get(), None);
+
+        let second = pool.allocate(2, 5).unwrap();
+        assert_eq!(second.index(), index);
+        assert_eq!(second.tag(), 5);
+        assert!(pool.allocate(3, 0).is_err());
+    }
+}
@@ -1,17 +1,100 @@
 //! Thread-safe memory pool implementations.
 
 use crate::config::PoolConfig;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use core::ops::{Deref, DerefMut};
+use core::time::Duration;
+use std::time::Instant;
+
+#[cfg(feature = "stats")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "stats")]
+use crate::stats::PoolStatistics;
 
 #[cfg(not(feature = "parking_lot"))]
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Condvar, Mutex};
 
 #[cfg(feature = "parking_lot")]
-use parking_lot::Mutex;
+use parking_lot::{Condvar, Mutex};
 #[cfg(feature = "parking_lot")]
 use std::sync::Arc;
 
+/// State shared between a thread-safe pool and its handles.
+struct Shared<T> {
+    pool: Mutex<crate::pool::GrowingPool<T>>,
+    /// Signaled whenever a handle returns its slot to the pool
+    slot_freed: Condvar,
+    /// Number of blocking lock acquisitions
+    #[cfg(feature = "stats")]
+    lock_attempts: AtomicUsize,
+    /// Number of blocking lock acquisitions that found the lock held
+    #[cfg(feature = "stats")]
+    contended_locks: AtomicUsize,
+}
+
+#[cfg(not(feature = "parking_lot"))]
+type LockedPool<'a, T> = std::sync::MutexGuard<'a, crate::pool::GrowingPool<T>>;
+
+#[cfg(feature = "parking_lot")]
+type LockedPool<'a, T> = parking_lot::MutexGuard<'a, crate::pool::GrowingPool<T>>;
+
+impl<T> Shared<T> {
+    fn new(pool: crate::pool::GrowingPool<T>) -> Self {
+        Self {
+            pool: Mutex::new(pool),
+            slot_freed: Condvar::new(),
+            #[cfg(feature = "stats")]
+            lock_attempts: AtomicUsize::new(0),
+            #[cfg(feature = "stats")]
+            contended_locks: AtomicUsize::new(0),
+        }
+    }
+
+    /// Locks the pool, blocking until it is available.
+    ///
+    /// With `stats` enabled the lock is tried first, so acquisitions that
+    /// had to wait can be counted.
+    fn lock(&self) -> LockedPool<'_, T> {
+        #[cfg(feature = "stats")]
+        {
+            self.lock_attempts.fetch_add(1, Ordering::Relaxed);
+            if let Some(pool) = self.try_lock() {
+                return pool;
+            }
+            self.contended_locks.fetch_add(1, Ordering::Relaxed);
+        }
+
+        self.lock_blocking()
+    }
+
+    #[cfg(not(feature = "parking_lot"))]
+    fn lock_blocking(&self) -> LockedPool<'_, T> {
+        self.pool.lock().unwrap()
+    }
+
+    #[cfg(feature = "parking_lot")]
+    fn lock_blocking(&self) -> LockedPool<'_, T> {
+        self.pool.lock()
+    }
+
+    /// Locks the pool if no other thread holds the lock.
+    #[cfg(not(feature = "parking_lot"))]
+    fn try_lock(&self) -> Option<LockedPool<'_, T>> {
+        match self.pool.try_lock() {
+            Ok(pool) => Some(pool),
+            Err(std::sync::TryLockError::WouldBlock) => None,
+            Err(std::sync::TryLockError::Poisoned(err)) => panic!("{}", err),
+        }
+    }
+
+    /// Locks the pool if no other thread holds the lock.
+    #[cfg(feature = "parking_lot")]
+    fn try_lock(&self) -> Option<LockedPool<'_, T>> {
+        self.pool.try_lock()
+    }
+}
+
 /// Handle for thread-safe pool allocations.
 ///
 /// This handle holds a reference to the pool and automatically returns
@@ -19,11 +102,88 @@ use std::sync::Arc;
 ///
 /// Performance note: This handle caches the pointer to avoid locking
 /// on every dereference operation, only locking during allocation and deallocation.
+#[must_use = "dropping the handle immediately returns the object to the pool"]
 pub struct ThreadSafeHandle<T: crate::traits::Poolable> {
-    pool: Arc<Mutex<crate::pool::GrowingPool<T>>>,
+    shared: Arc<Shared<T>>,
     index: usize,
     /// Cached pointer to the value for lock-free deref
     cached_ptr: *mut T,
+    /// Slot generation captured at allocation, checked in debug builds
+    #[cfg(debug_assertions)]
+    generation: u32,
+    /// The slot's generation counter, readable without the pool lock
+    #[cfg(debug_assertions)]
+    slot_generation: crate::pool::growing::SlotGeneration,
+}
+
+impl<T: crate::traits::Poolable> ThreadSafeHandle<T> {
+    /// Creates the handle for a slot just allocated from the locked pool.
+    fn new(shared: &Arc<Shared<T>>, pool: &crate::pool::GrowingPool<T>, index: usize) -> Self {
+        // Cache the pointer for lock-free deref
+        let cached_ptr = pool.get_mut(index) as *mut T;
+        #[cfg(debug_assertions)]
+        let slot_generation = pool.generation(index);
+
+        Self {
+            shared: Arc::clone(shared),
+            index,
+            cached_ptr,
+            #[cfg(debug_assertions)]
+            generation: slot_generation.load(),
+            #[cfg(debug_assertions)]
+            slot_generation,
+        }
+    }
+
+    /// Allocates another object from the pool this handle's object lives in.
+    ///
+    /// Behaves like [`ThreadSafePool::allocate`], so code that only holds
+    /// object handles does not need the pool passed alongside them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::ThreadSafePool;
+    ///
+    /// let pool = ThreadSafePool::new(4).unwrap();
+    /// let first = pool.allocate(1).unwrap();
+    ///
+    /// let second = first.allocate_sibling(2).unwrap();
+    /// assert_eq!((*first, *second), (1, 2));
+    /// assert_eq!(pool.allocated(), 2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error [`ThreadSafePool::allocate`] would return.
+    pub fn allocate_sibling(&self, value: T) -> Result<ThreadSafeHandle<T>> {
+        let mut pool = self.shared.lock();
+
+        let index = pool.allocate_internal(value)?;
+        Ok(ThreadSafeHandle::new(&self.shared, &pool, index))
+    }
+}
+
+#[cfg(debug_assertions)]
+impl<T: crate::traits::Poolable> ThreadSafeHandle<T> {
+    /// Reads the slot's current generation without locking the pool.
+    fn current_generation(&self) -> u32 {
+        self.slot_generation.load()
+    }
+
+    /// Asserts that the slot has not been released since this handle was created.
+    ///
+    /// Must be called with the pool lock released, so a failure does not
+    /// poison it. The check is skipped while the thread is panicking.
+    fn check_generation(&self, current: u32) {
+        debug_assert!(
+            current == self.generation || std::thread::panicking(),
+            "stale ThreadSafeHandle: slot {} was released and reused (generation {} != {})",
+            self.index,
+            current,
+            self.generation
+        );
+    }
 }
 
 impl<T: crate::traits::Poolable> Deref for ThreadSafeHandle<T> {
@@ -31,6 +191,9 @@ impl<T: crate::traits::Poolable> Deref for ThreadSafeHandle<T> {
 
     #[inline]
     fn deref(&self) -> &Self::Target {
+        #[cfg(debug_assertions)]
+        self.check_generation(self.current_generation());
+
         // Safety: The cached pointer is valid for the lifetime of this handle.
         // The pool storage is stable (won't move) and this handle has exclusive
         // ownership of the slot via allocator tracking.
@@ -41,6 +204,9 @@ impl<T: crate::traits::Poolable> Deref for ThreadSafeHandle<T> {
 impl<T: crate::traits::Poolable> DerefMut for ThreadSafeHandle<T> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
+        #[cfg(debug_assertions)]
+        self.check_generation(self.current_generation());
+
         // Safety: The cached pointer is valid for the lifetime of this handle.
         // We have &mut self so we have exclusive access to the handle.
         unsafe { &mut *self.cached_ptr }
@@ -49,12 +215,25 @@ impl<T: crate::traits::Poolable> DerefMut for ThreadSafeHandle<T> {
 
 impl<T: crate::traits::Poolable> Drop for ThreadSafeHandle<T> {
     fn drop(&mut self) {
-        #[cfg(not(feature = "parking_lot"))]
-        let pool = self.pool.lock().unwrap();
-        #[cfg(feature = "parking_lot")]
-        let pool = self.pool.lock();
+        {
+            let pool = self.shared.lock();
+
+            // A stale handle must not free a slot that now belongs to another
+            #[cfg(debug_assertions)]
+            {
+                let current = self.current_generation();
+                if current != self.generation {
+                    drop(pool);
+                    self.check_generation(current);
+                    return;
+                }
+            }
+
+            pool.return_to_pool(self.index);
+        }
 
-        pool.return_to_pool(self.index);
+        // Wake one caller blocked in `allocate_timeout`
+        self.shared.slot_freed.notify_one();
     }
 }
 
@@ -65,6 +244,82 @@ unsafe impl<T: crate::traits::Poolable + Send> Send for ThreadSafeHandle<T> {}
 // Note: ThreadSafeHandle is intentionally NOT Sync because it contains a raw pointer
 // and provides mutable access through DerefMut. Each handle should be owned by a single thread.
 
+/// Reference-counted handle to an object in a [`ThreadSafePool`].
+///
+/// The thread-safe counterpart of [`SharedHandle`](crate::SharedHandle):
+/// clones can be sent to other threads and all point at the same object,
+/// which is returned to the pool, under the pool lock, when the last clone
+/// is dropped. The object is only reachable through shared references.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::ThreadSafePool;
+/// use std::thread;
+///
+/// let pool = ThreadSafePool::new(4).unwrap();
+/// let config = pool.allocate_shared(String::from("settings")).unwrap();
+///
+/// let worker = {
+///     let config = config.clone();
+///     thread::spawn(move || config.len())
+/// };
+/// assert_eq!(worker.join().unwrap(), 8);
+///
+/// drop(config);
+/// assert_eq!(pool.allocated(), 0);
+/// ```
+pub struct ArcSharedHandle<T: crate::traits::Poolable> {
+    handle: Arc<ThreadSafeHandle<T>>,
+}
+
+impl<T: crate::traits::Poolable> ArcSharedHandle<T> {
+    /// Returns the number of handles pointing to this object.
+    #[inline]
+    pub fn strong_count(&self) -> usize {
+        Arc::strong_count(&self.handle)
+    }
+
+    /// Returns the internal index of this handle.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.handle.index
+    }
+}
+
+impl<T: crate::traits::Poolable> Clone for ArcSharedHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            handle: Arc::clone(&self.handle),
+        }
+    }
+}
+
+impl<T: crate::traits::Poolable> Deref for ArcSharedHandle<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl<T: crate::traits::Poolable + core::fmt::Debug> core::fmt::Debug for ArcSharedHandle<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ArcSharedHandle")
+            .field("index", &self.index())
+            .field("strong_count", &self.strong_count())
+            .field("value", &**self)
+            .finish()
+    }
+}
+
+// Safety: clones on several threads read the object concurrently, which
+// requires T: Sync, and the last clone to drop releases it on whichever
+// thread that is, which requires T: Send. The release itself locks the pool.
+unsafe impl<T: crate::traits::Poolable + Send + Sync> Send for ArcSharedHandle<T> {}
+unsafe impl<T: crate::traits::Poolable + Send + Sync> Sync for ArcSharedHandle<T> {}
+
 /// A thread-safe memory pool using locks for synchronization.
 ///
 /// This pool can be safely shared across threads and used concurrently.
@@ -96,7 +351,7 @@ unsafe impl<T: crate::traits::Poolable + Send> Send for ThreadSafeHandle<T> {}
 /// - Higher latency under heavy contention
 /// - Use `ThreadLocalPool` for single-threaded performance
 pub struct ThreadSafePool<T> {
-    inner: Arc<Mutex<crate::pool::GrowingPool<T>>>,
+    inner: Arc<Shared<T>>,
 }
 
 impl<T: crate::traits::Poolable> ThreadSafePool<T> {
@@ -107,10 +362,22 @@ impl<T: crate::traits::Poolable> ThreadSafePool<T> {
     }
 
     /// Creates a new thread-safe pool with the specified configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if the configuration enables
+    /// contiguous storage, since handles cache pointers into the pool that
+    /// growth would invalidate.
     pub fn with_config(config: PoolConfig<T>) -> Result<Self> {
+        if config.contiguous() {
+            return Err(Error::invalid_config(
+                "ThreadSafePool does not support contiguous storage",
+            ));
+        }
+
         let pool = crate::pool::GrowingPool::with_config(config)?;
         Ok(Self {
-            inner: Arc::new(Mutex::new(pool)),
+            inner: Arc::new(Shared::new(pool)),
         })
     }
 
@@ -118,32 +385,122 @@ impl<T: crate::traits::Poolable> ThreadSafePool<T> {
     ///
     /// This method acquires a lock and may block if another thread is
     /// currently using the pool.
+    #[must_use = "dropping the handle immediately returns the object to the pool"]
     pub fn allocate(&self, value: T) -> Result<ThreadSafeHandle<T>> {
-        #[cfg(not(feature = "parking_lot"))]
-        let mut pool = self.inner.lock().unwrap();
-
-        #[cfg(feature = "parking_lot")]
         let mut pool = self.inner.lock();
 
         // Allocate using the internal pool API
         let index = pool.allocate_internal(value)?;
+        Ok(ThreadSafeHandle::new(&self.inner, &pool, index))
+    }
 
-        // Cache the pointer for lock-free deref
-        let cached_ptr = pool.get_mut(index) as *mut T;
-
-        Ok(ThreadSafeHandle {
-            pool: Arc::clone(&self.inner),
-            index,
-            cached_ptr,
+    /// Allocates an object and returns a handle that can be cloned across threads.
+    ///
+    /// The slot is returned to the pool exactly once, when the last
+    /// [`ArcSharedHandle`] pointing at it is dropped.
+    pub fn allocate_shared(&self, value: T) -> Result<ArcSharedHandle<T>> {
+        Ok(ArcSharedHandle {
+            handle: Arc::new(self.allocate(value)?),
         })
     }
 
+    /// Allocates an object without blocking on the pool lock.
+    ///
+    /// Returns `Ok(None)` immediately if another thread holds the lock,
+    /// instead of waiting for it. Real-time code, such as an audio
+    /// callback, can use this to fail fast rather than risk priority
+    /// inversion; the value is dropped in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::ThreadSafePool;
+    ///
+    /// let pool = ThreadSafePool::new(4).unwrap();
+    ///
+    /// if let Some(handle) = pool.try_allocate(7).unwrap() {
+    ///     assert_eq!(*handle, 7);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error [`allocate`](Self::allocate) would return once the
+    /// lock is acquired, such as `Error::PoolExhausted`.
+    #[must_use = "dropping the handle immediately returns the object to the pool"]
+    pub fn try_allocate(&self, value: T) -> Result<Option<ThreadSafeHandle<T>>> {
+        let mut pool = match self.inner.try_lock() {
+            Some(pool) => pool,
+            None => return Ok(None),
+        };
+
+        let index = pool.allocate_internal(value)?;
+        Ok(Some(ThreadSafeHandle::new(&self.inner, &pool, index)))
+    }
+
+    /// Allocates an object, waiting up to `timeout` for a slot to be freed.
+    ///
+    /// If the pool has no free slot and cannot grow, the caller is parked
+    /// until another thread drops a handle or the timeout elapses. Pools that
+    /// can still grow allocate immediately, just like [`allocate`](Self::allocate).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::ThreadSafePool;
+    /// use std::time::Duration;
+    ///
+    /// let pool = ThreadSafePool::new(1).unwrap();
+    /// let held = pool.allocate(1).unwrap();
+    ///
+    /// // Nobody frees the slot in time
+    /// assert!(pool.allocate_timeout(2, Duration::from_millis(10)).is_err());
+    ///
+    /// drop(held);
+    /// let handle = pool.allocate_timeout(3, Duration::from_millis(10)).unwrap();
+    /// assert_eq!(*handle, 3);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PoolExhausted` if no slot became available before the
+    /// timeout, or any error [`allocate`](Self::allocate) would return.
+    pub fn allocate_timeout(&self, value: T, timeout: Duration) -> Result<ThreadSafeHandle<T>> {
+        let deadline = Instant::now() + timeout;
+
+        let mut pool = self.inner.lock();
+
+        while pool.available() == 0 && !pool.can_grow() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(Error::PoolExhausted {
+                    capacity: pool.capacity(),
+                    allocated: pool.allocated(),
+                    can_grow: false,
+                    name: pool.name(),
+                });
+            }
+
+            #[cfg(not(feature = "parking_lot"))]
+            {
+                pool = self
+                    .inner
+                    .slot_freed
+                    .wait_timeout(pool, remaining)
+                    .unwrap()
+                    .0;
+            }
+
+            #[cfg(feature = "parking_lot")]
+            self.inner.slot_freed.wait_for(&mut pool, remaining);
+        }
+
+        let index = pool.allocate_internal(value)?;
+        Ok(ThreadSafeHandle::new(&self.inner, &pool, index))
+    }
+
     /// Returns the current capacity of the pool.
     pub fn capacity(&self) -> usize {
-        #[cfg(not(feature = "parking_lot"))]
-        let pool = self.inner.lock().unwrap();
-
-        #[cfg(feature = "parking_lot")]
         let pool = self.inner.lock();
 
         pool.capacity()
@@ -151,10 +508,6 @@ impl<T: crate::traits::Poolable> ThreadSafePool<T> {
 
     /// Returns the number of available slots.
     pub fn available(&self) -> usize {
-        #[cfg(not(feature = "parking_lot"))]
-        let pool = self.inner.lock().unwrap();
-
-        #[cfg(feature = "parking_lot")]
         let pool = self.inner.lock();
 
         pool.available()
@@ -162,14 +515,128 @@ impl<T: crate::traits::Poolable> ThreadSafePool<T> {
 
     /// Returns the number of currently allocated objects.
     pub fn allocated(&self) -> usize {
-        #[cfg(not(feature = "parking_lot"))]
-        let pool = self.inner.lock().unwrap();
-
-        #[cfg(feature = "parking_lot")]
         let pool = self.inner.lock();
 
         pool.allocated()
     }
+
+    /// Returns a snapshot of the pool statistics, taken under a single lock.
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    pub fn statistics(&self) -> PoolStatistics {
+        let pool = self.inner.lock();
+
+        pool.statistics()
+    }
+
+    /// Resets the statistics counters.
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    pub fn reset_statistics(&self) {
+        let pool = self.inner.lock();
+
+        pool.reset_statistics();
+        self.inner.lock_attempts.store(0, Ordering::Relaxed);
+        self.inner.contended_locks.store(0, Ordering::Relaxed);
+    }
+
+    /// Returns how many lock acquisitions had to wait for another thread.
+    ///
+    /// Every operation that takes the pool lock, including handle drops,
+    /// first tries to acquire it without blocking; the count increases each
+    /// time that fails. Together with
+    /// [`contention_ratio`](Self::contention_ratio) this shows whether the
+    /// single mutex is a bottleneck worth sharding.
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    pub fn contention_count(&self) -> usize {
+        self.inner.contended_locks.load(Ordering::Relaxed)
+    }
+
+    /// Returns the fraction of lock acquisitions that had to wait (0.0 to 1.0).
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+    pub fn contention_ratio(&self) -> f64 {
+        let attempts = self.inner.lock_attempts.load(Ordering::Relaxed);
+        if attempts == 0 {
+            0.0
+        } else {
+            self.contention_count() as f64 / attempts as f64
+        }
+    }
+
+    /// Runs `f` with a [`ScopedPool`] that spawns scoped threads sharing this pool.
+    ///
+    /// Built on [`std::thread::scope`]: every thread spawned through the
+    /// scoped pool borrows `self` directly, so fork-join sections need no
+    /// `Arc` or clone of the pool. All spawned threads are joined before
+    /// `scope` returns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::ThreadSafePool;
+    ///
+    /// let pool = ThreadSafePool::new(64).unwrap();
+    ///
+    /// let total: i32 = pool.scope(|scoped| {
+    ///     let workers: Vec<_> = (0..4)
+    ///         .map(|i| scoped.spawn(move |pool| *pool.allocate(i).unwrap()))
+    ///         .collect();
+    ///     workers.into_iter().map(|w| w.join().unwrap()).sum()
+    /// });
+    ///
+    /// assert_eq!(total, 6);
+    /// assert_eq!(pool.allocated(), 0);
+    /// ```
+    pub fn scope<F, R>(&self, f: F) -> R
+    where
+        F: for<'scope> FnOnce(&ScopedPool<'scope, '_, T>) -> R,
+    {
+        std::thread::scope(|scope| f(&ScopedPool { pool: self, scope }))
+    }
+}
+
+/// Borrowed access to a [`ThreadSafePool`] inside [`ThreadSafePool::scope`].
+///
+/// Dereferences to the pool, so it allocates like the pool itself, and
+/// [`spawn`](Self::spawn) starts scoped threads that receive their own copy.
+pub struct ScopedPool<'scope, 'env, T> {
+    pool: &'env ThreadSafePool<T>,
+    scope: &'scope std::thread::Scope<'scope, 'env>,
+}
+
+impl<'scope, 'env, T: crate::traits::Poolable + Send> ScopedPool<'scope, 'env, T> {
+    /// Spawns a scoped thread that is handed the pool.
+    ///
+    /// The thread is joined automatically when the enclosing
+    /// [`scope`](ThreadSafePool::scope) call ends, or earlier through the
+    /// returned handle.
+    pub fn spawn<F, R>(&self, f: F) -> std::thread::ScopedJoinHandle<'scope, R>
+    where
+        F: FnOnce(&ScopedPool<'scope, 'env, T>) -> R + Send + 'scope,
+        R: Send + 'scope,
+    {
+        let scoped = *self;
+        self.scope.spawn(move || f(&scoped))
+    }
+}
+
+impl<T> Clone for ScopedPool<'_, '_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ScopedPool<'_, '_, T> {}
+
+impl<T> Deref for ScopedPool<'_, '_, T> {
+    type Target = ThreadSafePool<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.pool
+    }
 }
 
 impl<T> Clone for ThreadSafePool<T> {
@@ -323,4 +790,297 @@ mod tests {
 
         pool.return_object(obj.unwrap());
     }
+
+    #[test]
+    fn rejects_contiguous_config() {
+        let builder = PoolConfig::<i32>::builder().capacity(4);
+        // Safety: the pool is never created
+        let config = unsafe { builder.contiguous(true) }.build().unwrap();
+
+        let result = ThreadSafePool::with_config(config);
+        assert!(matches!(result, Err(Error::InvalidConfiguration { .. })));
+    }
+
+    #[test]
+    fn allocate_timeout_waits_for_free_slot() {
+        use std::sync::mpsc;
+        use std::thread;
+
+        let pool = Arc::new(ThreadSafePool::<i32>::new(2).unwrap());
+        let held: Vec<_> = (0..2).map(|i| pool.allocate(i).unwrap()).collect();
+        assert_eq!(pool.available(), 0);
+
+        let (started_tx, started_rx) = mpsc::channel();
+        let waiter = {
+            let pool = Arc::clone(&pool);
+            thread::spawn(move || {
+                started_tx.send(()).unwrap();
+                let handle = pool
+                    .allocate_timeout(99, Duration::from_secs(10))
+                    .expect("slot should be freed before the timeout");
+                *handle
+            })
+        };
+
+        // Release the slots once the waiter is running
+        started_rx.recv().unwrap();
+        thread::sleep(Duration::from_millis(20));
+        drop(held);
+
+        assert_eq!(waiter.join().unwrap(), 99);
+        assert_eq!(pool.allocated(), 0);
+    }
+
+    #[test]
+    fn allocate_timeout_expires() {
+        let pool = ThreadSafePool::<i32>::new(1).unwrap();
+        let _held = pool.allocate(1).unwrap();
+
+        let result = pool.allocate_timeout(2, Duration::from_millis(10));
+        assert!(matches!(
+            result,
+            Err(Error::PoolExhausted {
+                capacity: 1,
+                allocated: 1,
+                can_grow: false,
+                name: None,
+            })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn statistics_track_usage() {
+        let pool = ThreadSafePool::new(8).unwrap();
+        let handles: Vec<_> = (0..5).map(|i| pool.allocate(i).unwrap()).collect();
+        drop(handles.into_iter().take(2).collect::<Vec<_>>());
+
+        let stats = pool.statistics();
+        assert_eq!(stats.current_usage, pool.allocated());
+        assert_eq!(stats.total_allocations, 5);
+
+        pool.reset_statistics();
+        assert_eq!(pool.statistics().total_allocations, 0);
+    }
+
+    #[test]
+    fn try_allocate_returns_none_while_locked() {
+        use std::sync::mpsc;
+        use std::thread;
+
+        let pool = ThreadSafePool::<i32>::new(2).unwrap();
+        let (locked_tx, locked_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let shared = &pool;
+
+        thread::scope(|scope| {
+            // Hold the pool lock in a long critical section
+            scope.spawn(move || {
+                let _guard = shared.inner.lock();
+
+                locked_tx.send(()).unwrap();
+                release_rx.recv().unwrap();
+            });
+
+            locked_rx.recv().unwrap();
+            let contended = scope.spawn(|| shared.try_allocate(1)).join().unwrap();
+            assert!(matches!(contended, Ok(None)));
+            release_tx.send(()).unwrap();
+        });
+
+        let handle = pool.try_allocate(2).unwrap().unwrap();
+        assert_eq!(*handle, 2);
+        let _other = pool.allocate(3).unwrap();
+        assert!(matches!(
+            pool.try_allocate(4),
+            Err(Error::PoolExhausted { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn contention_is_counted_under_load() {
+        use std::thread;
+
+        let pool = ThreadSafePool::<u64>::new(64).unwrap();
+        thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for i in 0..5_000 {
+                        let mut handle = pool.allocate(i).unwrap();
+                        *handle += 1;
+                    }
+                });
+            }
+        });
+
+        assert!(pool.contention_count() > 0);
+        let ratio = pool.contention_ratio();
+        assert!(ratio > 0.0 && ratio <= 1.0, "ratio = {}", ratio);
+
+        pool.reset_statistics();
+        assert_eq!(pool.contention_count(), 0);
+        assert_eq!(pool.contention_ratio(), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "stats")]
+    fn uncontended_pool_reports_no_contention() {
+        let pool = ThreadSafePool::<u64>::new(8).unwrap();
+        for i in 0..1_000 {
+            drop(pool.allocate(i).unwrap());
+        }
+
+        assert_eq!(pool.contention_count(), 0);
+        assert_eq!(pool.contention_ratio(), 0.0);
+    }
+
+    /// Simulates the slot behind `handle` being released and reused.
+    #[cfg(debug_assertions)]
+    fn make_stale(pool: &ThreadSafePool<i32>, handle: &ThreadSafeHandle<i32>) {
+        let inner = pool.inner.lock();
+
+        inner.bump_generation(handle.index);
+    }
+
+    #[test]
+    fn handles_deref_while_the_pool_is_locked() {
+        use crate::config::PoolEvent;
+        use core::sync::atomic::{AtomicI32, Ordering};
+
+        let watched = Arc::new(std::sync::Mutex::new(None::<ThreadSafeHandle<i32>>));
+        let seen = Arc::new(AtomicI32::new(0));
+        let config = PoolConfig::builder()
+            .capacity(4)
+            .observer({
+                let watched = Arc::clone(&watched);
+                let seen = Arc::clone(&seen);
+                move |event| {
+                    if let PoolEvent::Freed { .. } = event {
+                        if let Some(handle) = &*watched.lock().unwrap() {
+                            seen.store(**handle, Ordering::Relaxed);
+                        }
+                    }
+                }
+            })
+            .build()
+            .unwrap();
+        let pool = ThreadSafePool::with_config(config).unwrap();
+
+        *watched.lock().unwrap() = Some(pool.allocate(7).unwrap());
+        // The observer runs under the pool lock
+        drop(pool.allocate(1).unwrap());
+        assert_eq!(seen.load(Ordering::Relaxed), 7);
+
+        let handle = watched.lock().unwrap().take();
+        drop(handle);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "stale ThreadSafeHandle")]
+    fn stale_handle_deref_is_caught() {
+        let pool = ThreadSafePool::new(4).unwrap();
+        let handle = pool.allocate(1).unwrap();
+        assert_eq!(*handle, 1);
+
+        make_stale(&pool, &handle);
+        let _ = *handle;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn stale_handle_drop_is_caught_without_freeing() {
+        let pool = ThreadSafePool::new(4).unwrap();
+        let handle = pool.allocate(1).unwrap();
+        make_stale(&pool, &handle);
+
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| drop(handle)));
+        assert!(result.is_err());
+        // The slot was not released on behalf of the stale handle
+        assert_eq!(pool.allocated(), 1);
+    }
+
+    #[test]
+    fn scope_allocates_concurrently_without_arc() {
+        let pool = ThreadSafePool::new(32).unwrap();
+
+        let sums: Vec<i32> = pool.scope(|scoped| {
+            let workers: Vec<_> = (0..4)
+                .map(|t| {
+                    scoped.spawn(move |pool| {
+                        let handles: Vec<_> = (0..8)
+                            .map(|i| pool.allocate(t * 100 + i).unwrap())
+                            .collect();
+                        handles.iter().map(|h| **h).sum()
+                    })
+                })
+                .collect();
+
+            // The scope body can use the pool too
+            let _local = scoped.allocate(-1);
+
+            workers.into_iter().map(|w| w.join().unwrap()).collect()
+        });
+
+        assert_eq!(sums, [28, 828, 1628, 2428]);
+        assert_eq!(pool.allocated(), 0);
+        assert_eq!(pool.available(), pool.capacity());
+    }
+
+    #[test]
+    fn arc_shared_handle_frees_once_after_last_clone() {
+        use std::sync::atomic::AtomicUsize;
+        use std::thread;
+
+        static RELEASES: AtomicUsize = AtomicUsize::new(0);
+
+        struct Counted(usize);
+        impl crate::traits::Poolable for Counted {
+            fn on_release(&mut self) {
+                RELEASES.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            }
+        }
+
+        let pool = ThreadSafePool::new(2).unwrap();
+        let shared = pool.allocate_shared(Counted(7)).unwrap();
+
+        let workers: Vec<_> = (0..8)
+            .map(|i| {
+                let clone = shared.clone();
+                thread::spawn(move || {
+                    // Stagger the drops so clones are released in varying order
+                    thread::sleep(Duration::from_millis((i * 7 % 5) as u64));
+                    clone.0
+                })
+            })
+            .collect();
+        drop(shared);
+
+        for worker in workers {
+            assert_eq!(worker.join().unwrap(), 7);
+        }
+
+        assert_eq!(RELEASES.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(pool.allocated(), 0);
+    }
+
+    #[test]
+    fn sibling_allocations_are_independent() {
+        let pool = ThreadSafePool::new(2).unwrap();
+        let mut first = pool.allocate(String::from("first")).unwrap();
+        let mut second = first.allocate_sibling(String::from("second")).unwrap();
+        assert_eq!(pool.allocated(), 2);
+        assert!(first.allocate_sibling(String::new()).is_err());
+
+        first.push('!');
+        second.push('?');
+        assert_eq!((first.as_str(), second.as_str()), ("first!", "second?"));
+
+        drop(first);
+        assert_eq!(pool.allocated(), 1);
+        assert_eq!(*second, "second?");
+        drop(second);
+        assert_eq!(pool.allocated(), 0);
+    }
 }
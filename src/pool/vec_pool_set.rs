@@ -0,0 +1,224 @@
+//! A collection of fixed pools keyed by object type, without hashing.
+
+use crate::error::{Error, Result};
+use crate::pool::FixedPool;
+use crate::traits::Poolable;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
+use core::cell::RefCell;
+use core::fmt;
+use core::num::NonZeroUsize;
+
+/// Capacity of pools created on first use when no other default is configured.
+pub(super) const DEFAULT_CAPACITY: usize = 100;
+
+/// Owns one [`FixedPool`] per object type, created on first use, without `std`.
+///
+/// The same type map as [`PoolSet`](crate::PoolSet), but the pools are kept
+/// in a `Vec` sorted by `TypeId` and found by binary search, so it only
+/// needs `alloc`. With the handful of types a program pools, this is as
+/// fast as hashing, which makes it the type map for embedded targets.
+///
+/// # Examples
+///
+/// ```rust
+/// use fastalloc::VecPoolSet;
+///
+/// struct Sample(u16);
+/// impl fastalloc::Poolable for Sample {}
+///
+/// let set = VecPoolSet::new().with_capacity_for::<Sample>(64).unwrap();
+///
+/// let sample = set.pool::<Sample>().allocate(Sample(512)).unwrap();
+/// let count = set.pool::<u32>().allocate(1).unwrap();
+///
+/// assert_eq!(set.pool::<Sample>().capacity(), 64);
+/// assert_eq!(set.len(), 2);
+/// # drop((sample, count));
+/// ```
+pub struct VecPoolSet {
+    /// Pools sorted by the `TypeId` of their object type
+    pools: RefCell<Vec<(TypeId, Box<dyn Any>)>>,
+    default_capacity: NonZeroUsize,
+}
+
+impl VecPoolSet {
+    /// Creates an empty set whose pools are created with a capacity of 100.
+    pub fn new() -> Self {
+        Self {
+            pools: RefCell::new(Vec::new()),
+            default_capacity: NonZeroUsize::new(DEFAULT_CAPACITY).expect("non-zero"),
+        }
+    }
+
+    /// Creates an empty set whose pools are created with `capacity` slots.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `capacity` is 0.
+    pub fn with_default_capacity(capacity: usize) -> Result<Self> {
+        let default_capacity = NonZeroUsize::new(capacity)
+            .ok_or_else(|| Error::invalid_config("capacity must be at least 1"))?;
+
+        Ok(Self {
+            pools: RefCell::new(Vec::new()),
+            default_capacity,
+        })
+    }
+
+    /// Registers a pool for `T` with its own capacity, replacing any pool
+    /// already registered for `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidConfiguration` if `capacity` is 0.
+    pub fn with_capacity_for<T: Poolable + 'static>(self, capacity: usize) -> Result<Self> {
+        let pool: Box<dyn Any> = Box::new(FixedPool::<T>::new(capacity)?);
+        {
+            let mut pools = self.pools.borrow_mut();
+            let key = TypeId::of::<T>();
+            match pools.binary_search_by_key(&key, |(id, _)| *id) {
+                Ok(position) => pools[position].1 = pool,
+                Err(position) => pools.insert(position, (key, pool)),
+            }
+        }
+        Ok(self)
+    }
+
+    /// Returns the pool for `T`, creating it with the default capacity if needed.
+    pub fn pool<T: Poolable + 'static>(&self) -> &FixedPool<T> {
+        let pool: *const FixedPool<T> = {
+            let mut pools = self.pools.borrow_mut();
+            let key = TypeId::of::<T>();
+            let position = match pools.binary_search_by_key(&key, |(id, _)| *id) {
+                Ok(position) => position,
+                Err(position) => {
+                    let pool = FixedPool::<T>::with_nonzero(self.default_capacity);
+                    pools.insert(position, (key, Box::new(pool)));
+                    position
+                }
+            };
+            pools[position]
+                .1
+                .downcast_ref::<FixedPool<T>>()
+                .expect("pool is stored under its own TypeId")
+        };
+
+        // Safety: the pool is boxed, so shifting or reallocating the vector
+        // does not move it, and entries are only replaced or removed through
+        // `self` by value or `&mut self`, which cannot happen while this
+        // borrow is alive
+        unsafe { &*pool }
+    }
+
+    /// Returns whether a pool for `T` has been created or registered.
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.pools
+            .borrow()
+            .binary_search_by_key(&TypeId::of::<T>(), |(id, _)| *id)
+            .is_ok()
+    }
+
+    /// Returns the number of pools in the set.
+    pub fn len(&self) -> usize {
+        self.pools.borrow().len()
+    }
+
+    /// Returns whether the set holds no pools yet.
+    pub fn is_empty(&self) -> bool {
+        self.pools.borrow().is_empty()
+    }
+
+    /// Returns the capacity used for pools created on first use.
+    pub fn default_capacity(&self) -> usize {
+        self.default_capacity.get()
+    }
+}
+
+impl Default for VecPoolSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for VecPoolSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VecPoolSet")
+            .field("pools", &self.len())
+            .field("default_capacity", &self.default_capacity)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Position(i32, i32);
+    struct Velocity(i32);
+
+    impl Poolable for Position {}
+    impl Poolable for Velocity {}
+
+    #[test]
+    fn registers_and_retrieves_pools() {
+        let set = VecPoolSet::with_default_capacity(8)
+            .unwrap()
+            .with_capacity_for::<Position>(2)
+            .unwrap();
+        assert_eq!(set.len(), 1);
+        assert!(set.contains::<Position>());
+        assert!(!set.contains::<Velocity>());
+
+        let positions = set.pool::<Position>();
+        let velocities = set.pool::<Velocity>();
+        assert_eq!(set.len(), 2);
+
+        let a = positions.allocate(Position(1, 2)).unwrap();
+        let b = positions.allocate(Position(3, 4)).unwrap();
+        assert!(positions.allocate(Position(5, 6)).is_err());
+
+        let v = velocities.allocate(Velocity(7)).unwrap();
+        assert_eq!(velocities.capacity(), 8);
+
+        assert_eq!(a.0 + a.1 + b.0 + b.1 + v.0, 17);
+    }
+
+    #[test]
+    fn pools_stay_in_place_as_the_set_grows() {
+        let set = VecPoolSet::new();
+        let handle = set.pool::<u32>().allocate(1).unwrap();
+        let first: *const FixedPool<u32> = set.pool::<u32>();
+
+        // Inserting other types shifts and reallocates the sorted vector
+        set.pool::<u64>();
+        set.pool::<i8>();
+        set.pool::<i16>();
+        set.pool::<Position>();
+        assert_eq!(set.len(), 5);
+        assert!(core::ptr::eq(first, set.pool::<u32>()));
+        assert_eq!(*handle, 1);
+        assert_eq!(set.pool::<u32>().allocated(), 1);
+
+        let pools = set.pools.borrow();
+        assert!(pools.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn registering_twice_replaces_the_pool() {
+        let set = VecPoolSet::new()
+            .with_capacity_for::<u8>(4)
+            .unwrap()
+            .with_capacity_for::<u8>(16)
+            .unwrap();
+        assert_eq!(set.len(), 1);
+        assert_eq!(set.pool::<u8>().capacity(), 16);
+    }
+
+    #[test]
+    fn zero_capacity_is_rejected() {
+        assert!(VecPoolSet::with_default_capacity(0).is_err());
+        assert!(VecPoolSet::new().with_capacity_for::<u8>(0).is_err());
+    }
+}
@@ -2,12 +2,30 @@
 
 use super::PoolStatistics;
 
+#[cfg(feature = "std")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+/// Number of `(timestamp, total_allocations)` samples kept for the recent rate.
+#[cfg(feature = "std")]
+const RATE_SAMPLES: usize = 8;
+
 /// Collects statistics about pool operations.
 ///
 /// This is used internally by pool implementations to track metrics
-/// when the `stats` feature is enabled.
+/// when the `stats` feature is enabled. With `std` it also timestamps the
+/// last reset and keeps a small ring of samples taken by
+/// [`sample`](Self::sample), from which allocation rates are derived.
+#[derive(Clone)]
 pub struct StatisticsCollector {
     stats: PoolStatistics,
+    /// When the collector was created or last reset
+    #[cfg(feature = "std")]
+    started: Instant,
+    /// Recent `(timestamp, total_allocations)` samples, oldest first
+    #[cfg(feature = "std")]
+    samples: VecDeque<(Instant, usize)>,
 }
 
 impl StatisticsCollector {
@@ -15,6 +33,10 @@ impl StatisticsCollector {
     pub fn new(capacity: usize) -> Self {
         Self {
             stats: PoolStatistics::new(capacity),
+            #[cfg(feature = "std")]
+            started: Instant::now(),
+            #[cfg(feature = "std")]
+            samples: VecDeque::with_capacity(RATE_SAMPLES),
         }
     }
 
@@ -27,6 +49,7 @@ impl StatisticsCollector {
         if self.stats.current_usage > self.stats.peak_usage {
             self.stats.peak_usage = self.stats.current_usage;
         }
+        self.record_occupancy();
     }
 
     /// Records a deallocation.
@@ -34,6 +57,18 @@ impl StatisticsCollector {
     pub fn record_deallocation(&mut self) {
         self.stats.total_deallocations += 1;
         self.stats.current_usage = self.stats.current_usage.saturating_sub(1);
+        self.record_occupancy();
+    }
+
+    /// Counts the current utilization in its decile of the occupancy histogram.
+    #[inline]
+    fn record_occupancy(&mut self) {
+        let capacity = self.stats.capacity;
+        if capacity == 0 {
+            return;
+        }
+        let decile = (self.stats.current_usage.saturating_mul(10) / capacity).min(9);
+        self.stats.occupancy_counts[decile] += 1;
     }
 
     /// Records an allocation failure.
@@ -52,13 +87,50 @@ impl StatisticsCollector {
     /// Returns a snapshot of the current statistics.
     #[inline]
     pub fn snapshot(&self) -> PoolStatistics {
-        self.stats
+        #[allow(unused_mut)]
+        let mut stats = self.stats;
+        #[cfg(feature = "std")]
+        {
+            stats.elapsed = self.started.elapsed();
+        }
+        stats
     }
 
-    /// Resets all statistics counters.
+    /// Returns a snapshot and records it as a sample for the recent allocation rate.
+    ///
+    /// The snapshot's window runs from the oldest retained sample (or the
+    /// last reset) to now. Without `std` this is the same as
+    /// [`snapshot`](Self::snapshot).
+    pub fn sample(&mut self) -> PoolStatistics {
+        #[allow(unused_mut)]
+        let mut stats = self.snapshot();
+
+        #[cfg(feature = "std")]
+        {
+            let now = Instant::now();
+            let (since, count) = self.samples.front().copied().unwrap_or((self.started, 0));
+            stats.window = now.saturating_duration_since(since);
+            stats.window_allocations = stats.total_allocations.saturating_sub(count);
+
+            if self.samples.len() == RATE_SAMPLES {
+                self.samples.pop_front();
+            }
+            self.samples.push_back((now, stats.total_allocations));
+        }
+
+        stats
+    }
+
+    /// Resets all statistics counters and restarts the rate clock.
     pub fn reset(&mut self) {
         let capacity = self.stats.capacity;
         self.stats = PoolStatistics::new(capacity);
+
+        #[cfg(feature = "std")]
+        {
+            self.started = Instant::now();
+            self.samples.clear();
+        }
     }
 }
 
@@ -133,4 +205,69 @@ mod tests {
         assert_eq!(stats.allocation_failures, 0);
         assert_eq!(stats.capacity, 100); // Capacity is preserved
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn collector_reports_allocation_rate() {
+        use std::time::Duration;
+
+        let mut collector = StatisticsCollector::new(100);
+        for _ in 0..20 {
+            collector.record_allocation();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        // 20 allocations spread over at least 20ms
+        let stats = collector.snapshot();
+        assert!(stats.elapsed >= Duration::from_millis(20));
+        let rate = stats.allocations_per_second();
+        assert!(rate > 0.0 && rate <= 1000.0, "rate = {}", rate);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn collector_windowed_rate_tracks_recent_samples() {
+        let mut collector = StatisticsCollector::new(100);
+        for _ in 0..5 {
+            collector.record_allocation();
+        }
+        assert_eq!(collector.sample().window_allocations, 5);
+
+        // Only allocations since the oldest retained sample count
+        for round in 0..RATE_SAMPLES {
+            collector.record_allocation();
+            let stats = collector.sample();
+            assert_eq!(stats.window_allocations, round + 1);
+            assert!(stats.window <= stats.elapsed);
+        }
+
+        // The first sample has been evicted from the ring
+        collector.record_allocation();
+        assert_eq!(collector.sample().window_allocations, RATE_SAMPLES);
+
+        collector.reset();
+        assert_eq!(collector.sample().window_allocations, 0);
+    }
+
+    #[test]
+    fn collector_buckets_occupancy_by_decile() {
+        let mut collector = StatisticsCollector::new(4);
+
+        // Fill to 25%, 50%, 75%, 100%, then drain to 75%, 50%, 25%, 0%
+        for _ in 0..4 {
+            collector.record_allocation();
+        }
+        for _ in 0..4 {
+            collector.record_deallocation();
+        }
+        collector.record_allocation();
+
+        assert_eq!(
+            collector.snapshot().occupancy_histogram(),
+            [1, 0, 3, 0, 0, 2, 0, 2, 0, 1]
+        );
+
+        collector.reset();
+        assert_eq!(collector.snapshot().occupancy_histogram(), [0; 10]);
+    }
 }
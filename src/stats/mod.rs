@@ -7,6 +7,7 @@ pub use collector::StatisticsCollector;
 pub use reporter::StatisticsReporter;
 
 use core::fmt;
+use core::time::Duration;
 
 /// Statistics about pool usage and performance.
 ///
@@ -29,6 +30,7 @@ use core::fmt;
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub struct PoolStatistics {
     /// Total number of allocations ever made
     pub total_allocations: usize,
@@ -50,6 +52,38 @@ pub struct PoolStatistics {
 
     /// Number of allocation failures
     pub allocation_failures: usize,
+
+    /// Number of storage chunks (for growing pools)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub chunk_count: usize,
+
+    /// Number of chunks that are partially but not fully used (for growing pools)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fragmented_chunks: usize,
+
+    /// Time since the statistics were created or last reset (zero without `std`)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub elapsed: Duration,
+
+    /// Length of the recent sampling window (zero without `std`)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub window: Duration,
+
+    /// Number of allocations made within `window`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub window_allocations: usize,
+
+    /// Number of allocations and frees that left the pool in each
+    /// utilization decile; see [`occupancy_histogram`](Self::occupancy_histogram)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub occupancy_counts: [u64; 10],
+
+    /// Name of the pool, if one was configured
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip_deserializing, skip_serializing_if = "Option::is_none")
+    )]
+    pub name: Option<&'static str>,
 }
 
 impl PoolStatistics {
@@ -63,6 +97,13 @@ impl PoolStatistics {
             capacity,
             growth_count: 0,
             allocation_failures: 0,
+            chunk_count: 0,
+            fragmented_chunks: 0,
+            elapsed: Duration::ZERO,
+            window: Duration::ZERO,
+            window_allocations: 0,
+            occupancy_counts: [0; 10],
+            name: None,
         }
     }
 
@@ -97,16 +138,139 @@ impl PoolStatistics {
         }
     }
 
+    /// Returns the fraction of chunks that are partially used (0.0 to 1.0).
+    ///
+    /// See [`GrowingPool::fragmentation_ratio`](crate::GrowingPool::fragmentation_ratio).
+    /// Pools without chunk tracking report 0.0.
+    #[inline]
+    pub fn fragmentation_ratio(&self) -> f64 {
+        if self.chunk_count == 0 {
+            0.0
+        } else {
+            self.fragmented_chunks as f64 / self.chunk_count as f64
+        }
+    }
+
+    /// Returns the average number of allocations per second since the
+    /// statistics were created or last reset.
+    ///
+    /// Returns 0.0 if no time has been recorded, which is always the case
+    /// without the `std` feature.
+    #[inline]
+    pub fn allocations_per_second(&self) -> f64 {
+        rate(self.total_allocations, self.elapsed)
+    }
+
+    /// Returns the number of allocations per second over the recent sampling window.
+    ///
+    /// The window spans the last few calls to `statistics()`, so polling at
+    /// a fixed interval gives a rate that follows changes in load instead of
+    /// averaging over the pool's whole lifetime. Returns 0.0 until a window
+    /// has been recorded.
+    #[inline]
+    pub fn recent_allocations_per_second(&self) -> f64 {
+        rate(self.window_allocations, self.window)
+    }
+
+    /// Returns how often the pool sat at each fill level.
+    ///
+    /// Every allocation and free is counted in the bucket of the
+    /// utilization it left the pool at: bucket `n` covers `10n%` up to
+    /// `10(n + 1)%`, and a full pool is counted in the last bucket. A pool
+    /// whose counts stay in the low buckets is over-provisioned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::FixedPool;
+    ///
+    /// let pool = FixedPool::new(10).unwrap();
+    /// let handles: Vec<_> = (0..3).map(|i| pool.allocate(i).unwrap()).collect();
+    ///
+    /// // Three allocations took the pool to 10%, 20% and 30%
+    /// let histogram = pool.statistics().occupancy_histogram();
+    /// assert_eq!(histogram[..4], [0, 1, 1, 1]);
+    /// # drop(handles);
+    /// ```
+    #[inline]
+    pub fn occupancy_histogram(&self) -> [u64; 10] {
+        self.occupancy_counts
+    }
+
     /// Returns the number of currently available slots.
     #[inline]
     pub fn available(&self) -> usize {
         self.capacity.saturating_sub(self.current_usage)
     }
+
+    /// Combines the statistics of two pools into a single rolled-up view.
+    ///
+    /// Cumulative counters, `capacity` and `current_usage` are summed, so the
+    /// derived rates (such as [`utilization_rate`](Self::utilization_rate))
+    /// describe the pools as a whole. `peak_usage` is the larger of the two
+    /// peaks, since the individual peaks need not have happened at the same time.
+    /// The `name` is kept only if both pools share it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use fastalloc::stats::PoolStatistics;
+    ///
+    /// let mut a = PoolStatistics::new(100);
+    /// a.current_usage = 30;
+    /// let mut b = PoolStatistics::new(100);
+    /// b.current_usage = 10;
+    ///
+    /// let merged = a.merge(&b);
+    /// assert_eq!(merged.capacity, 200);
+    /// assert_eq!(merged.utilization_rate(), 20.0);
+    /// ```
+    pub fn merge(&self, other: &PoolStatistics) -> PoolStatistics {
+        PoolStatistics {
+            total_allocations: self.total_allocations + other.total_allocations,
+            total_deallocations: self.total_deallocations + other.total_deallocations,
+            current_usage: self.current_usage + other.current_usage,
+            peak_usage: self.peak_usage.max(other.peak_usage),
+            capacity: self.capacity + other.capacity,
+            growth_count: self.growth_count + other.growth_count,
+            allocation_failures: self.allocation_failures + other.allocation_failures,
+            chunk_count: self.chunk_count + other.chunk_count,
+            fragmented_chunks: self.fragmented_chunks + other.fragmented_chunks,
+            elapsed: self.elapsed.max(other.elapsed),
+            window: self.window.max(other.window),
+            window_allocations: self.window_allocations + other.window_allocations,
+            occupancy_counts: {
+                let mut counts = self.occupancy_counts;
+                for (count, other) in counts.iter_mut().zip(other.occupancy_counts) {
+                    *count += other;
+                }
+                counts
+            },
+            name: if self.name == other.name {
+                self.name
+            } else {
+                None
+            },
+        }
+    }
+}
+
+/// Returns `count` per second over `duration`, or 0.0 for an empty duration.
+fn rate(count: usize, duration: Duration) -> f64 {
+    let secs = duration.as_secs_f64();
+    if secs == 0.0 {
+        0.0
+    } else {
+        count as f64 / secs
+    }
 }
 
 impl fmt::Display for PoolStatistics {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "Pool Statistics:")?;
+        match self.name {
+            Some(name) => writeln!(f, "Pool Statistics ({}):", name)?,
+            None => writeln!(f, "Pool Statistics:")?,
+        }
         writeln!(f, "  Capacity:            {}", self.capacity)?;
         writeln!(
             f,
@@ -167,4 +331,77 @@ mod tests {
 
         assert_eq!(stats.available(), 70);
     }
+
+    #[test]
+    fn merge_aggregates_each_field() {
+        let a = PoolStatistics {
+            total_allocations: 10,
+            total_deallocations: 4,
+            current_usage: 6,
+            peak_usage: 8,
+            capacity: 10,
+            growth_count: 1,
+            allocation_failures: 2,
+            chunk_count: 2,
+            fragmented_chunks: 1,
+            elapsed: Duration::from_secs(2),
+            window: Duration::from_secs(1),
+            window_allocations: 3,
+            occupancy_counts: [1, 2, 3, 0, 0, 0, 0, 0, 0, 0],
+            name: Some("shard"),
+        };
+        let b = PoolStatistics {
+            total_allocations: 5,
+            total_deallocations: 5,
+            current_usage: 0,
+            peak_usage: 12,
+            capacity: 30,
+            growth_count: 2,
+            allocation_failures: 3,
+            chunk_count: 2,
+            fragmented_chunks: 0,
+            elapsed: Duration::from_secs(5),
+            window: Duration::from_secs(2),
+            window_allocations: 5,
+            occupancy_counts: [0, 0, 1, 0, 0, 0, 0, 0, 0, 4],
+            name: Some("shard"),
+        };
+
+        let merged = a.merge(&b);
+        assert_eq!(merged.total_allocations, 15);
+        assert_eq!(merged.total_deallocations, 9);
+        assert_eq!(merged.current_usage, 6);
+        assert_eq!(merged.peak_usage, 12);
+        assert_eq!(merged.capacity, 40);
+        assert_eq!(merged.growth_count, 3);
+        assert_eq!(merged.allocation_failures, 5);
+        assert_eq!(merged.chunk_count, 4);
+        assert_eq!(merged.fragmented_chunks, 1);
+        assert_eq!(merged.elapsed, Duration::from_secs(5));
+        assert_eq!(merged.window, Duration::from_secs(2));
+        assert_eq!(merged.window_allocations, 8);
+        assert_eq!(merged.occupancy_counts, [1, 2, 4, 0, 0, 0, 0, 0, 0, 4]);
+        assert_eq!(merged.name, Some("shard"));
+        assert_eq!(a.merge(&PoolStatistics::new(0)).name, None);
+
+        // Rates are computed over the combined capacity
+        assert_eq!(merged.utilization_rate(), 15.0);
+        assert_eq!(merged.peak_utilization_rate(), 30.0);
+        assert_eq!(merged.hit_rate(), 0.75);
+        assert_eq!(merged.fragmentation_ratio(), 0.25);
+        assert_eq!(merged.allocations_per_second(), 3.0);
+        assert_eq!(merged.recent_allocations_per_second(), 4.0);
+        assert_eq!(merged, b.merge(&a));
+    }
+
+    #[test]
+    fn rates_are_zero_without_elapsed_time() {
+        let stats = PoolStatistics {
+            total_allocations: 10,
+            window_allocations: 4,
+            ..PoolStatistics::new(10)
+        };
+        assert_eq!(stats.allocations_per_second(), 0.0);
+        assert_eq!(stats.recent_allocations_per_second(), 0.0);
+    }
 }
@@ -31,6 +31,29 @@ impl StatisticsReporter {
         Self { stats }
     }
 
+    /// Creates a reporter for the combined statistics of several pools.
+    ///
+    /// The snapshots are rolled up with [`PoolStatistics::merge`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #[cfg(feature = "stats")]
+    /// {
+    ///     use fastalloc::stats::{PoolStatistics, StatisticsReporter};
+    ///
+    ///     let shards = [PoolStatistics::new(64), PoolStatistics::new(64)];
+    ///     let reporter = StatisticsReporter::merged(&shards);
+    ///     assert!(reporter.compact_summary().contains("0/128"));
+    /// }
+    /// ```
+    pub fn merged<'a>(stats: impl IntoIterator<Item = &'a PoolStatistics>) -> Self {
+        let stats = stats
+            .into_iter()
+            .fold(PoolStatistics::new(0), |acc, s| acc.merge(s));
+        Self { stats }
+    }
+
     /// Returns a human-readable summary of the statistics.
     pub fn summary(&self) -> String {
         alloc::format!("{}", self.stats)
@@ -38,8 +61,13 @@ impl StatisticsReporter {
 
     /// Returns a compact one-line summary.
     pub fn compact_summary(&self) -> String {
+        let label = match self.stats.name {
+            Some(name) => alloc::format!("Pool '{}'", name),
+            None => String::from("Pool"),
+        };
         alloc::format!(
-            "Pool: {}/{} ({:.1}%) | Allocs: {} | Deallocs: {} | Failures: {}",
+            "{}: {}/{} ({:.1}%) | Allocs: {} | Deallocs: {} | Failures: {}",
+            label,
             self.stats.current_usage,
             self.stats.capacity,
             self.stats.utilization_rate(),
@@ -76,16 +104,143 @@ impl StatisticsReporter {
         ]
     }
 
+    /// Returns statistics in the Prometheus text exposition format.
+    ///
+    /// Every metric name is prefixed with `namespace` and preceded by its
+    /// `# HELP` and `# TYPE` lines, so the output can be served directly
+    /// from a scrape endpoint. Cumulative counts are typed `counter`, the
+    /// rest `gauge`:
+    ///
+    /// - `{namespace}_allocations_total` (counter)
+    /// - `{namespace}_deallocations_total` (counter)
+    /// - `{namespace}_allocation_failures_total` (counter)
+    /// - `{namespace}_growths_total` (counter)
+    /// - `{namespace}_capacity` (gauge)
+    /// - `{namespace}_current_usage` (gauge)
+    /// - `{namespace}_peak_usage` (gauge)
+    /// - `{namespace}_utilization_ratio` (gauge, 0.0 to 1.0)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #[cfg(feature = "stats")]
+    /// {
+    ///     use fastalloc::stats::{PoolStatistics, StatisticsReporter};
+    ///
+    ///     let mut stats = PoolStatistics::new(64);
+    ///     stats.total_allocations = 12;
+    ///     let text = StatisticsReporter::new(stats).as_prometheus("pool");
+    ///
+    ///     assert!(text.contains("# TYPE pool_allocations_total counter\n"));
+    ///     assert!(text.contains("pool_allocations_total 12\n"));
+    ///     assert!(text.contains("pool_capacity 64\n"));
+    /// }
+    /// ```
+    pub fn as_prometheus(&self, namespace: &str) -> String {
+        use core::fmt::Write;
+
+        let stats = &self.stats;
+        let metrics = [
+            (
+                "allocations_total",
+                "counter",
+                "Total number of allocations.",
+                stats.total_allocations as f64,
+            ),
+            (
+                "deallocations_total",
+                "counter",
+                "Total number of deallocations.",
+                stats.total_deallocations as f64,
+            ),
+            (
+                "allocation_failures_total",
+                "counter",
+                "Total number of failed allocations.",
+                stats.allocation_failures as f64,
+            ),
+            (
+                "growths_total",
+                "counter",
+                "Total number of times the pool has grown.",
+                stats.growth_count as f64,
+            ),
+            (
+                "capacity",
+                "gauge",
+                "Current capacity of the pool.",
+                stats.capacity as f64,
+            ),
+            (
+                "current_usage",
+                "gauge",
+                "Number of currently allocated objects.",
+                stats.current_usage as f64,
+            ),
+            (
+                "peak_usage",
+                "gauge",
+                "Peak number of simultaneously allocated objects.",
+                stats.peak_usage as f64,
+            ),
+            (
+                "utilization_ratio",
+                "gauge",
+                "Fraction of the capacity currently allocated.",
+                stats.utilization_rate() / 100.0,
+            ),
+        ];
+
+        let mut out = String::new();
+        for (name, kind, help, value) in metrics {
+            // Writing to a `String` cannot fail
+            let _ = write!(
+                out,
+                "# HELP {ns}_{name} {help}\n# TYPE {ns}_{name} {kind}\n{ns}_{name} {value}\n",
+                ns = namespace,
+                name = name,
+                help = help,
+                kind = kind,
+                value = value,
+            );
+        }
+        out
+    }
+
     /// Returns statistics in JSON format (requires alloc).
     #[cfg(feature = "serde")]
     pub fn as_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(&self.stats)
     }
 
+    /// Publishes statistics through the `metrics` facade (requires metrics feature).
+    ///
+    /// Updates the following metrics on the installed recorder:
+    ///
+    /// - `{prefix}_allocations_total` (counter)
+    /// - `{prefix}_current_usage` (gauge)
+    /// - `{prefix}_peak_usage` (gauge)
+    /// - `{prefix}_utilization_ratio` (gauge, 0.0 to 1.0)
+    ///
+    /// The counter is set to the absolute allocation total, so calling this
+    /// periodically with fresh snapshots keeps the exported values in sync.
+    #[cfg(feature = "metrics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+    pub fn emit_metrics(&self, prefix: &str) {
+        metrics::counter!(alloc::format!("{}_allocations_total", prefix))
+            .absolute(self.stats.total_allocations as u64);
+        metrics::gauge!(alloc::format!("{}_current_usage", prefix))
+            .set(self.stats.current_usage as f64);
+        metrics::gauge!(alloc::format!("{}_peak_usage", prefix)).set(self.stats.peak_usage as f64);
+        metrics::gauge!(alloc::format!("{}_utilization_ratio", prefix))
+            .set(self.stats.utilization_rate() / 100.0);
+    }
+
     /// Logs statistics using the tracing framework (requires tracing feature).
     #[cfg(feature = "tracing")]
     pub fn log(&self) {
         tracing::info!(
+            name = self.stats.name.unwrap_or(""),
             capacity = self.stats.capacity,
             current_usage = self.stats.current_usage,
             peak_usage = self.stats.peak_usage,
@@ -136,6 +291,23 @@ mod tests {
         assert!(compact.contains("Allocs: 50"));
     }
 
+    #[test]
+    fn reporter_includes_pool_name() {
+        let stats = PoolStatistics {
+            current_usage: 3,
+            name: Some("connection_pool"),
+            ..PoolStatistics::new(8)
+        };
+
+        let reporter = StatisticsReporter::new(stats);
+        assert!(reporter
+            .summary()
+            .starts_with("Pool Statistics (connection_pool):"));
+        assert!(reporter
+            .compact_summary()
+            .starts_with("Pool 'connection_pool': 3/8"));
+    }
+
     #[test]
     fn reporter_key_value_pairs() {
         let stats = PoolStatistics::new(100);
@@ -148,4 +320,98 @@ mod tests {
         let capacity_pair = pairs.iter().find(|(k, _)| *k == "capacity");
         assert!(capacity_pair.is_some());
     }
+
+    #[test]
+    fn reporter_merged() {
+        let a = PoolStatistics {
+            current_usage: 10,
+            total_allocations: 12,
+            ..PoolStatistics::new(50)
+        };
+        let b = PoolStatistics {
+            current_usage: 15,
+            total_allocations: 20,
+            ..PoolStatistics::new(50)
+        };
+
+        let reporter = StatisticsReporter::merged([&a, &b]);
+        let compact = reporter.compact_summary();
+
+        assert!(compact.contains("25/100"));
+        assert!(compact.contains("Allocs: 32"));
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn reporter_emit_metrics() {
+        use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+        let stats = PoolStatistics {
+            capacity: 200,
+            current_usage: 50,
+            peak_usage: 80,
+            total_allocations: 120,
+            ..PoolStatistics::new(200)
+        };
+        let reporter = StatisticsReporter::new(stats);
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        metrics::with_local_recorder(&recorder, || reporter.emit_metrics("pool"));
+
+        let mut emitted: Vec<(String, DebugValue)> = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .map(|(key, _, _, value)| (key.key().name().to_string(), value))
+            .collect();
+        emitted.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let find = |name: &str| {
+            emitted
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| v)
+                .unwrap_or_else(|| panic!("metric {} not emitted", name))
+        };
+
+        assert_eq!(emitted.len(), 4);
+        assert_eq!(find("pool_allocations_total"), &DebugValue::Counter(120));
+        assert!(matches!(find("pool_current_usage"), DebugValue::Gauge(v) if v.0 == 50.0));
+        assert!(matches!(find("pool_peak_usage"), DebugValue::Gauge(v) if v.0 == 80.0));
+        assert!(matches!(find("pool_utilization_ratio"), DebugValue::Gauge(v) if v.0 == 0.25));
+    }
+
+    #[test]
+    fn reporter_prometheus_format() {
+        let stats = PoolStatistics {
+            capacity: 200,
+            current_usage: 50,
+            peak_usage: 80,
+            total_allocations: 120,
+            total_deallocations: 70,
+            allocation_failures: 3,
+            growth_count: 1,
+            ..PoolStatistics::new(200)
+        };
+        let text = StatisticsReporter::new(stats).as_prometheus("pool");
+
+        assert!(text.contains("# HELP pool_allocations_total Total number of allocations.\n"));
+        assert!(text.contains("# TYPE pool_allocations_total counter\n"));
+        assert!(text.contains("\npool_allocations_total 120\n"));
+        assert!(text.contains("# TYPE pool_deallocations_total counter\n"));
+        assert!(text.contains("\npool_deallocations_total 70\n"));
+        assert!(text.contains("\npool_allocation_failures_total 3\n"));
+        assert!(text.contains("\npool_growths_total 1\n"));
+        assert!(text.contains("# TYPE pool_current_usage gauge\n"));
+        assert!(text.contains("\npool_current_usage 50\n"));
+        assert!(text.contains("\npool_peak_usage 80\n"));
+        assert!(text.contains("\npool_capacity 200\n"));
+        assert!(text.contains("\npool_utilization_ratio 0.25\n"));
+
+        // Every metric has exactly one HELP and one TYPE line
+        assert_eq!(text.matches("# HELP ").count(), 8);
+        assert_eq!(text.matches("# TYPE ").count(), 8);
+        assert_eq!(text.lines().count(), 24);
+    }
 }
@@ -40,7 +40,27 @@ pub const fn padding_needed(size: usize, alignment: usize) -> usize {
     aligned - size
 }
 
+/// Overwrites `len` bytes starting at `ptr` with zeros.
+///
+/// Uses volatile writes followed by a compiler fence so the wipe is not
+/// optimized away even though the memory is not read afterwards.
+///
+/// # Safety
+///
+/// `ptr` must be valid for writes of `len` bytes.
+#[inline]
+pub unsafe fn secure_zero(ptr: *mut u8, len: usize) {
+    for i in 0..len {
+        core::ptr::write_volatile(ptr.add(i), 0);
+    }
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
+
 /// Computes the growth amount based on current capacity and growth factor.
+///
+/// The float-to-integer cast saturates, so huge capacities or factors yield
+/// `usize::MAX` rather than wrapping; callers must still use checked
+/// arithmetic when adding the result to the current capacity.
 #[inline]
 pub fn compute_exponential_growth(current: usize, factor: f64) -> usize {
     let growth = (current as f64 * factor) as usize;
@@ -139,6 +159,15 @@ mod tests {
         assert_eq!(compute_exponential_growth(100, 2.0), 200);
         assert_eq!(compute_exponential_growth(100, 1.5), 150);
         assert_eq!(compute_exponential_growth(0, 2.0), 1); // Minimum growth
+        assert_eq!(compute_exponential_growth(usize::MAX, 4.0), usize::MAX);
+    }
+
+    #[test]
+    fn test_secure_zero() {
+        let mut buf = [0xAAu8; 16];
+        unsafe { secure_zero(buf.as_mut_ptr(), 8) };
+        assert_eq!(&buf[..8], &[0u8; 8]);
+        assert_eq!(&buf[8..], &[0xAAu8; 8]);
     }
 
     #[test]
@@ -0,0 +1,7 @@
+//! Checks that misuse of the typed APIs is rejected by the compiler.
+
+#[test]
+fn compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
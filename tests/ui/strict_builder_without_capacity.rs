@@ -0,0 +1,5 @@
+use fastalloc::PoolConfig;
+
+fn main() {
+    let _config = PoolConfig::<u32>::strict_builder().max_capacity(Some(8)).build();
+}